@@ -1,7 +1,11 @@
+use std::collections::HashMap;
 use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::time::Duration;
 
+use ed25519_dalek::SigningKey;
 use eframe::egui;
-use sha2::{Sha256, Digest};
+use uuid::Uuid;
 
 use crate::benchmarks::apps::{
     AppLaunchBenchmark, ArchiveOpsBenchmark, DefenderImpactBenchmark,
@@ -20,31 +24,25 @@ use crate::benchmarks::latency::{
     ProcessSpawnBenchmark, StorageLatencyBenchmark, StorageLatencyLiteBenchmark, ThreadWakeBenchmark,
 };
 use crate::benchmarks::memory::{MemoryBandwidthBenchmark, MemoryLatencyBenchmark};
-use crate::benchmarks::Benchmark;
-use crate::cloud::CloudClient;
+use crate::benchmarks::{Benchmark, BenchmarkConfig, LiveSample};
+use crate::cloud::{
+    estimate_percentile_ranks, CloudClient, CloudMessage, CloudResult, CloudTask, CloudWorker, CommunityRun,
+    HardwareClass, HostSalt, RedactionPolicy, RequestId,
+};
+use crate::core::quiet::DEFAULT_QUIET_PERIOD_SECS;
 use crate::core::{
-    BenchmarkMessage, BenchmarkRunner, RecommendationEngine, RecommendationsReport,
-    SystemCheckResult, SystemChecker, SystemInfoCollector,
+    BenchmarkMessage, BenchmarkRunner, EtaEstimator, PercentileRank, RecommendationEngine,
+    RecommendationsReport, ScoreCache, ScratchRequirement, SystemCheckResult, SystemChecker, SystemInfoCollector,
 };
-use crate::models::{BenchmarkRun, SystemInfo};
-use crate::storage::HistoryStorage;
+use crate::models::{BenchmarkRun, ComparisonReport, SignatureStatus, SystemInfo};
+use crate::storage::{load_or_create_signing_key, HistoryStorage, StatisticsCache, UploadQueue};
 use crate::ui::views::{
-    HistoryAction, HistoryView, HomeAction, HomeView, PreCheckAction, PreCheckView,
-    ResultsAction, ResultsView, RunningView,
+    CommunityAction, CommunityFilters, CommunityView, ComparisonView, HistoryAction, HistoryView,
+    HomeAction, HomeView, PreCheckAction, PreCheckView, ResultsAction, ResultsView, RunningView,
+    TrendsView,
 };
 use crate::ui::Theme;
 
-// SHA-256 hash of the admin password
-const ADMIN_PASSWORD_HASH: &str = "92f71e72f53a12f3851825f1caf01587679bc8333ecf07c9df745b0c4386eec0";
-
-fn verify_admin_password(password: &str) -> bool {
-    let mut hasher = Sha256::new();
-    hasher.update(password.as_bytes());
-    let result = hasher.finalize();
-    let hash_hex = format!("{:x}", result);
-    hash_hex == ADMIN_PASSWORD_HASH
-}
-
 /// Application state
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum AppState {
@@ -54,6 +52,10 @@ enum AppState {
     Results,
     History,
     ViewingHistoricRun(usize),       // Index of run to view
+    Trends,
+    Community,
+    CommunityRun,                    // Run fetched from the community database
+    Comparison,
 }
 
 /// Main application
@@ -68,11 +70,15 @@ pub struct WorkBenchProApp {
     current_test_progress: f32,
     current_test: String,
     current_message: String,
+    eta_secs: Option<f64>,
     completed_tests: Vec<String>,
+    live_samples: Vec<LiveSample>,
+    live_benchmark_id: String,
 
     // Pre-check
     system_check: Option<SystemCheckResult>,
     system_check_receiver: Option<Receiver<SystemCheckResult>>,
+    quiet_period_secs: u32, // Idle time required by "Start When Idle"
 
     // Results
     last_run: Option<BenchmarkRun>,
@@ -82,27 +88,59 @@ pub struct WorkBenchProApp {
     history_storage: HistoryStorage,
     history_runs: Vec<BenchmarkRun>,
 
+    // Trends
+    trend_test: Option<String>,
+    trend_show_bands: bool,
+
     // Cloud/Community
-    cloud_client: CloudClient,
+    cloud: CloudWorker,
+    cloud_status: Option<(RequestId, String)>, // Latest progress message and the request it belongs to
+
+    // Community statistics for distribution overlays and percentile ranks, cached on disk
+    community_stats: Option<StatisticsCache>,
+    community_stats_request: Option<RequestId>,
+
+    // Community browser
+    community_filters: CommunityFilters,
+    community_runs: Vec<CommunityRun>,
+    community_error: Option<String>,
+    community_browse_request: Option<RequestId>,
+    community_fetch_request: Option<RequestId>,
+    community_run: Option<BenchmarkRun>,
+    compare_local_idx: Option<usize>, // Index into history_runs of the run to compare against
+    comparison: Option<ComparisonReport>,
 
     // Upload dialog state
     show_upload_dialog: bool,
     upload_display_name: String,
     upload_user_name: String,
     upload_description: String, // Optional description (VDI, Desktop, Laptop, etc.)
+    upload_policy: RedactionPolicy,
+    saved_upload_policy: RedactionPolicy, // Policy on disk, to tell whether the dialog's one is saved
+    signing_key: SigningKey, // Signs completed runs so later edits are detectable
+    host_salt: HostSalt, // Keys the anonymous ID that replaces the computer name
     upload_in_progress: bool,
     upload_error: Option<String>,
     upload_success: bool,
     upload_run_index: Option<usize>, // Index of run being uploaded (None = last_run)
+    upload_dialog_run: Option<Uuid>, // Run whose upload attempt the dialog is waiting for
+
+    // Persistent upload queue, drained one attempt at a time in the background
+    upload_queue: UploadQueue,
+    upload_request: Option<(RequestId, Uuid)>,
+    // Uploads cancelled mid-request, by run; one that reached the server anyway is still recorded
+    cancelled_uploads: HashMap<RequestId, Uuid>,
 
     // Window resize flag (for deferred resize after benchmark completes)
     pending_window_resize: Option<egui::Vec2>,
 
     // Remove upload dialog state
     show_remove_upload_dialog: bool,
-    remove_upload_password: String,
     remove_upload_error: Option<String>,
+    // Set once only the local status could be cleared, to say the server copy stays
+    remove_upload_notice: Option<String>,
     remove_upload_run_index: Option<usize>,
+    remove_upload_request: Option<RequestId>,
 
     // Delete confirmation dialog state
     show_delete_dialog: bool,
@@ -111,12 +149,17 @@ pub struct WorkBenchProApp {
     delete_password: String,
     delete_error: Option<String>,
     delete_found_remote_id: Option<String>, // Remote ID found by matching, for runs without local remote_id
+    delete_match_request: Option<RequestId>,
+    delete_request: Option<RequestId>,
 
     // Save error (for debugging)
     last_save_error: Option<String>,
 
     // Safe mode - skip AV-sensitive tests
     safe_mode: bool,
+
+    // Score of the run shown in the results view
+    score_cache: ScoreCache,
 }
 
 impl WorkBenchProApp {
@@ -131,6 +174,7 @@ impl WorkBenchProApp {
         // Load history
         let mut history_storage = HistoryStorage::new();
         let history_runs = history_storage.load_all().unwrap_or_default();
+        let signing_key = load_or_create_signing_key();
 
         Self {
             state: AppState::Home,
@@ -141,33 +185,60 @@ impl WorkBenchProApp {
             current_test_progress: 0.0,
             current_test: String::new(),
             current_message: String::new(),
+            eta_secs: None,
             completed_tests: Vec::new(),
+            live_samples: Vec::new(),
+            live_benchmark_id: String::new(),
             system_check: None,
             system_check_receiver: None,
+            quiet_period_secs: DEFAULT_QUIET_PERIOD_SECS,
             last_run: None,
             recommendations: None,
             history_storage,
             history_runs,
+            trend_test: None,
+            trend_show_bands: true,
             // Cloud state
-            cloud_client: CloudClient::new(),
+            cloud: CloudWorker::new(Arc::new(CloudClient::new())),
+            cloud_status: None,
+            community_stats: StatisticsCache::load(),
+            community_stats_request: None,
+            // Community browser
+            community_filters: CommunityFilters::default(),
+            community_runs: Vec::new(),
+            community_error: None,
+            community_browse_request: None,
+            community_fetch_request: None,
+            community_run: None,
+            compare_local_idx: None,
+            comparison: None,
             // Upload dialog
             show_upload_dialog: false,
             upload_display_name: machine_name,
             upload_user_name: String::new(),
             upload_description: String::new(),
+            upload_policy: RedactionPolicy::load(),
+            saved_upload_policy: RedactionPolicy::load(),
+            host_salt: HostSalt::from_signing_key(&signing_key),
+            signing_key,
             upload_in_progress: false,
             upload_error: None,
             upload_success: false,
             upload_run_index: None,
+            upload_dialog_run: None,
+            upload_queue: UploadQueue::new(),
+            upload_request: None,
+            cancelled_uploads: HashMap::new(),
 
             // Window resize
             pending_window_resize: None,
 
             // Remove upload dialog
             show_remove_upload_dialog: false,
-            remove_upload_password: String::new(),
             remove_upload_error: None,
+            remove_upload_notice: None,
             remove_upload_run_index: None,
+            remove_upload_request: None,
 
             // Delete confirmation dialog
             show_delete_dialog: false,
@@ -176,12 +247,15 @@ impl WorkBenchProApp {
             delete_password: String::new(),
             delete_error: None,
             delete_found_remote_id: None,
+            delete_match_request: None,
+            delete_request: None,
 
             // Save error
             last_save_error: None,
 
             // Safe mode disabled by default
             safe_mode: false,
+            score_cache: ScoreCache::default(),
         }
     }
 
@@ -193,9 +267,18 @@ impl WorkBenchProApp {
         let (tx, rx) = mpsc::channel();
         self.system_check_receiver = Some(rx);
 
+        // Scratch files the queued tests will write, and what Safe Mode's would
+        let config = BenchmarkConfig::default();
+        let scratch_bytes =
+            |safe_mode| Self::create_benchmarks(safe_mode).iter().map(|b| b.scratch_bytes(&config)).sum();
+        let scratch = ScratchRequirement {
+            selected_bytes: scratch_bytes(self.safe_mode),
+            lite_bytes: scratch_bytes(true),
+        };
+
         // Run check in background thread
         std::thread::spawn(move || {
-            let result = SystemChecker::check();
+            let result = SystemChecker::check(scratch);
             let _ = tx.send(result);
         });
 
@@ -211,7 +294,166 @@ impl WorkBenchProApp {
         }
     }
 
-    fn start_benchmark(&mut self) {
+    /// Refresh community statistics in the background unless the cached ones are
+    /// recent and still describe this machine's hardware class
+    fn fetch_community_stats(&mut self) {
+        let class = HardwareClass::of(&self.system_info);
+        let fresh = self
+            .community_stats
+            .as_ref()
+            .is_some_and(|cache| !cache.is_stale(chrono::Utc::now()) && cache.community.class == class);
+        if fresh || self.community_stats_request.is_some() {
+            return;
+        }
+
+        self.community_stats_request = Some(self.cloud.submit(CloudTask::FetchStatistics { similar_to: class }));
+    }
+
+    /// Load the current page of community runs, replacing any load still in flight
+    fn browse_community(&mut self) {
+        if let Some(request) = self.community_browse_request.take() {
+            self.cloud.cancel(request);
+        }
+        self.community_error = None;
+        self.community_browse_request = Some(self.cloud.submit(CloudTask::Browse(self.community_filters.browse_filter())));
+    }
+
+    /// Fetch a community run's full results to view it
+    fn open_community_run(&mut self, remote_id: String) {
+        if let Some(request) = self.community_fetch_request.take() {
+            self.cloud.cancel(request);
+        }
+        self.community_error = None;
+        self.community_fetch_request = Some(self.cloud.submit(CloudTask::Fetch { remote_id }));
+    }
+
+    /// Dispatch messages from background cloud requests to whoever is waiting for them
+    fn process_cloud_messages(&mut self, ctx: &egui::Context) {
+        for message in self.cloud.poll() {
+            let id = message.id();
+            if let CloudMessage::Progress { message, .. } = message {
+                self.cloud_status = Some((id, message));
+                continue;
+            }
+            if self.cloud_status.as_ref().is_some_and(|(request, _)| *request == id) {
+                self.cloud_status = None;
+            }
+
+            if self.community_stats_request == Some(id) {
+                self.community_stats_request = None;
+                match message {
+                    CloudMessage::Complete { result: CloudResult::Statistics(stats), .. } => {
+                        let cache = StatisticsCache::new(stats);
+                        if let Err(e) = cache.save() {
+                            tracing::warn!("Failed to cache community statistics: {}", e);
+                        }
+                        self.community_stats = Some(cache);
+
+                        // Re-rank the latest run against the new statistics
+                        self.recommendations = self
+                            .last_run
+                            .as_ref()
+                            .map(|run| Self::analyze_run(run, self.community_stats.as_ref()));
+                    }
+                    CloudMessage::Error { error, .. } => {
+                        // Cached statistics, if any, keep working offline
+                        tracing::warn!("Failed to fetch community statistics: {}", error);
+                    }
+                    _ => {}
+                }
+            } else if self.community_browse_request == Some(id) {
+                self.community_browse_request = None;
+                match message {
+                    CloudMessage::Complete { result: CloudResult::Browsed(runs), .. } => {
+                        self.community_runs = runs;
+                    }
+                    CloudMessage::Error { error, .. } => {
+                        self.community_error = Some(format!("Failed to load community results: {}", error));
+                    }
+                    _ => {}
+                }
+            } else if self.community_fetch_request == Some(id) {
+                self.community_fetch_request = None;
+                match message {
+                    CloudMessage::Complete { result: CloudResult::Fetched(run), .. } => {
+                        self.community_run = Some(*run);
+                        self.state = AppState::CommunityRun;
+                        self.fetch_community_stats();
+                    }
+                    CloudMessage::Error { error, .. } => {
+                        self.community_error = Some(format!("Failed to load run: {}", error));
+                    }
+                    _ => {}
+                }
+            } else if let Some((_, run_id)) = self.upload_request.filter(|(request, _)| *request == id) {
+                self.upload_request = None;
+                self.finish_upload(run_id, message);
+            } else if let Some(run_id) = self.cancelled_uploads.remove(&id) {
+                // Cancelled too late: the run is public, so keep its remote ID and token
+                if let CloudMessage::Complete { result: CloudResult::Uploaded { remote_id, delete_token }, .. } = message {
+                    tracing::info!("Cancelled upload of run {} had already reached the server", run_id);
+                    self.mark_uploaded(run_id, remote_id, delete_token);
+                }
+            } else if self.delete_match_request == Some(id) {
+                self.delete_match_request = None;
+                if let CloudMessage::Complete { result: CloudResult::Matched { remote_id }, .. } = message {
+                    self.delete_found_remote_id = remote_id;
+                }
+            } else if self.delete_request == Some(id) {
+                self.delete_request = None;
+                match message {
+                    CloudMessage::Complete { .. } => self.delete_local_run(),
+                    CloudMessage::Error { error, .. } => {
+                        self.delete_error = Some(format!("Failed to delete from cloud: {}", error));
+                    }
+                    _ => {}
+                }
+            } else if self.remove_upload_request == Some(id) {
+                self.remove_upload_request = None;
+                match message {
+                    CloudMessage::Complete { .. } => {
+                        self.clear_upload_status();
+                        self.reset_remove_upload_dialog();
+                    }
+                    CloudMessage::Error { error, .. } => {
+                        self.remove_upload_error = Some(format!("Failed to remove: {}", error));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Keep polling while requests are in flight
+        if self.cloud.is_busy() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+    }
+
+    /// Recommendations for a run, with percentile ranks when community statistics are available
+    fn analyze_run(run: &BenchmarkRun, stats: Option<&StatisticsCache>) -> RecommendationsReport {
+        let ranks: Option<Vec<PercentileRank>> = stats.map(|cache| {
+            estimate_percentile_ranks(run, &cache.community.statistics)
+                .into_iter()
+                .map(|rank| PercentileRank {
+                    test_id: rank.test_id,
+                    percentile: rank.percentile_rank as f32,
+                })
+                .collect()
+        });
+        RecommendationEngine::analyze(run, ranks.as_deref())
+    }
+
+    /// Estimate run time from benchmark estimates and this machine's history
+    fn estimate_eta(&self, benchmarks: &[Box<dyn Benchmark>]) -> EtaEstimator {
+        let plan: Vec<(String, u32)> = benchmarks
+            .iter()
+            .map(|b| (b.id().to_string(), b.estimated_duration_secs()))
+            .collect();
+        EtaEstimator::new(&plan, &self.history_runs, &self.system_info.hostname)
+    }
+
+    /// The full suite in run order; Safe Mode swaps in Lite tests and skips AV-sensitive ones
+    fn create_benchmarks(safe_mode: bool) -> Vec<Box<dyn Benchmark>> {
         // Create benchmark instances
         // In safe mode, skip tests that may trigger antivirus behavioral detection:
         // - FileEnumeration (creates 30,000 files)
@@ -220,18 +462,18 @@ impl WorkBenchProApp {
         let mut benchmarks: Vec<Box<dyn Benchmark>> = vec![];
 
         // Project Operations (disk + file operations)
-        if !self.safe_mode {
+        if !safe_mode {
             benchmarks.push(Box::new(FileEnumerationBenchmark::new()));
         }
         // Use Lite variants in safe mode (smaller files, different test IDs for DB tracking)
-        if self.safe_mode {
+        if safe_mode {
             benchmarks.push(Box::new(RandomReadLiteBenchmark::new()));
         } else {
             benchmarks.push(Box::new(RandomReadBenchmark::new()));
         }
         benchmarks.push(Box::new(MetadataOpsBenchmark::new()));
         benchmarks.push(Box::new(TraversalBenchmark::new()));
-        if self.safe_mode {
+        if safe_mode {
             benchmarks.push(Box::new(LargeFileReadLiteBenchmark::new()));
         } else {
             benchmarks.push(Box::new(LargeFileReadBenchmark::new()));
@@ -245,16 +487,16 @@ impl WorkBenchProApp {
         benchmarks.push(Box::new(MultiThreadBenchmark::new()));
         benchmarks.push(Box::new(MixedWorkloadBenchmark::new()));
         benchmarks.push(Box::new(SustainedWriteBenchmark::new()));
-        if !self.safe_mode {
+        if !safe_mode {
             benchmarks.push(Box::new(ArchiveOpsBenchmark::new()));
         }
         benchmarks.push(Box::new(WindowsCompressionBenchmark::new()));
-        if !self.safe_mode {
+        if !safe_mode {
             benchmarks.push(Box::new(PowerShellBenchmark::new()));
         }
 
         // Responsiveness (latency + memory benchmarks)
-        if self.safe_mode {
+        if safe_mode {
             benchmarks.push(Box::new(StorageLatencyLiteBenchmark::new()));
         } else {
             benchmarks.push(Box::new(StorageLatencyBenchmark::new()));
@@ -275,15 +517,27 @@ impl WorkBenchProApp {
         benchmarks.push(Box::new(SymlinkBenchmark::new()));
         benchmarks.push(Box::new(EnvironmentBenchmark::new()));
 
+        benchmarks
+    }
+
+    /// Start the full suite, or the smaller Safe Mode one if `safe_mode`, after
+    /// `quiet_period` of idle time if given
+    fn start_benchmark(&mut self, safe_mode: bool, quiet_period: Option<Duration>) {
+        let benchmarks = Self::create_benchmarks(safe_mode);
+
         // Reset running state
         self.overall_progress = 0.0;
         self.current_test_progress = 0.0;
         self.current_test = String::new();
         self.current_message = "Starting...".to_string();
         self.completed_tests.clear();
+        self.eta_secs = None;
+        self.live_samples.clear();
+        self.live_benchmark_id.clear();
 
         // Start runner
-        let receiver = self.runner.start(benchmarks);
+        let eta = self.estimate_eta(&benchmarks);
+        let receiver = self.runner.start(benchmarks, eta, quiet_period);
         self.receiver = Some(receiver);
         self.state = AppState::Running;
     }
@@ -354,9 +608,14 @@ impl WorkBenchProApp {
         self.current_test = String::new();
         self.current_message = format!("Starting single test: {}...", benchmark_name);
         self.completed_tests.clear();
+        self.eta_secs = None;
+        self.live_samples.clear();
+        self.live_benchmark_id.clear();
 
         // Start runner with just this one benchmark
-        let receiver = self.runner.start(vec![benchmark]);
+        let benchmarks = vec![benchmark];
+        let eta = self.estimate_eta(&benchmarks);
+        let receiver = self.runner.start(benchmarks, eta, None);
         self.receiver = Some(receiver);
         self.state = AppState::Running;
     }
@@ -370,15 +629,27 @@ impl WorkBenchProApp {
             while let Ok(msg) = rx.try_recv() {
                 match msg {
                     BenchmarkMessage::Progress {
-                        benchmark_id: _,
+                        benchmark_id,
                         overall_progress,
                         test_progress,
+                        eta_secs,
                         message,
                     } => {
+                        // Start a fresh live chart for each benchmark
+                        if benchmark_id != self.live_benchmark_id {
+                            self.live_samples.clear();
+                            self.live_benchmark_id = benchmark_id;
+                        }
                         self.overall_progress = overall_progress;
                         self.current_test_progress = test_progress;
+                        self.eta_secs = Some(eta_secs);
                         self.current_message = message;
                     }
+                    BenchmarkMessage::Sample { benchmark_id, sample } => {
+                        if benchmark_id == self.live_benchmark_id {
+                            self.live_samples.push(sample);
+                        }
+                    }
                     BenchmarkMessage::TestComplete { result } => {
                         self.current_test = result.name.clone();
                         self.completed_tests.push(format!(
@@ -386,7 +657,9 @@ impl WorkBenchProApp {
                             result.name, result.value, result.unit
                         ));
                     }
-                    BenchmarkMessage::AllComplete { run } => {
+                    BenchmarkMessage::AllComplete { mut run } => {
+                        run.sign(&self.signing_key);
+
                         // Save to history
                         match self.history_storage.save(&run) {
                             Ok(path) => {
@@ -400,12 +673,12 @@ impl WorkBenchProApp {
                             }
                         }
 
-                        // Generate recommendations
-                        // TODO: In future, fetch percentile ranks from cloud for comparison
-                        self.recommendations = Some(RecommendationEngine::analyze(&run, None));
+                        // Generate recommendations, ranked against cached community statistics
+                        self.recommendations = Some(Self::analyze_run(&run, self.community_stats.as_ref()));
 
                         self.last_run = Some(*run);
                         self.state = AppState::Results;
+                        self.fetch_community_stats();
                         should_keep_receiver = false;
 
                         // Results view will resize itself based on content
@@ -443,10 +716,11 @@ impl WorkBenchProApp {
         // If the run doesn't have a remote_id locally, check if it exists in the cloud
         if let Some(run) = self.history_runs.get(idx) {
             if run.remote_id.is_none() {
-                // Try to find a matching record in the cloud
-                if let Ok(Some(remote_id)) = self.cloud_client.find_matching(&run.machine_name, &run.timestamp) {
-                    self.delete_found_remote_id = Some(remote_id);
-                }
+                // Uploads carry the machine name as redacted by the privacy policy
+                self.delete_match_request = Some(self.cloud.submit(CloudTask::FindMatching {
+                    machine_name: self.saved_upload_policy.apply(run, &self.host_salt).machine_name,
+                    run_timestamp: run.timestamp,
+                }));
             }
         }
 
@@ -454,6 +728,10 @@ impl WorkBenchProApp {
     }
 
     fn reset_delete_dialog(&mut self) {
+        for request in [self.delete_match_request.take(), self.delete_request.take()].into_iter().flatten() {
+            self.cloud.cancel(request);
+        }
+
         self.show_delete_dialog = false;
         self.delete_run_index = None;
         self.delete_also_cloud = false;
@@ -473,23 +751,26 @@ impl WorkBenchProApp {
         }
 
         let run = &self.history_runs[idx];
+        self.delete_error = None;
 
-        // Determine if there's a cloud record (either from local remote_id or found by matching)
-        let cloud_remote_id = run.remote_id.clone().or_else(|| self.delete_found_remote_id.clone());
-        let has_cloud_record = cloud_remote_id.is_some();
-
-        // If user wants to delete from cloud too - no password needed since having the local file proves ownership
-        if self.delete_also_cloud && has_cloud_record {
-            if let Some(ref remote_id) = cloud_remote_id {
-                match CloudClient::new().delete(remote_id) {
-                    Ok(_) => {}
-                    Err(e) => {
-                        self.delete_error = Some(format!("Failed to delete from cloud: {}", e));
-                        return;
-                    }
-                }
+        // If user wants to delete from cloud too, the upload's deletion token proves ownership.
+        // The local file is deleted once the cloud copy is gone.
+        match (run.remote_id.clone(), run.delete_token.clone()) {
+            (Some(remote_id), Some(delete_token)) if self.delete_also_cloud => {
+                self.delete_request = Some(self.cloud.submit(CloudTask::Delete {
+                    remote_id,
+                    delete_token,
+                }));
             }
+            _ => self.delete_local_run(),
         }
+    }
+
+    fn delete_local_run(&mut self) {
+        let Some(run) = self.delete_run_index.and_then(|idx| self.history_runs.get(idx)) else {
+            self.reset_delete_dialog();
+            return;
+        };
 
         // Delete local file
         if let Err(e) = self.history_storage.delete(run) {
@@ -497,64 +778,77 @@ impl WorkBenchProApp {
             return;
         }
 
+        // Don't upload a run the user deleted
+        if let Err(e) = self.upload_queue.remove(run.id) {
+            tracing::error!("Failed to update upload queue: {}", e);
+        }
+
         self.reset_delete_dialog();
         self.reload_history();
     }
 
     fn open_remove_upload_dialog(&mut self, idx: usize) {
         self.remove_upload_run_index = Some(idx);
-        self.remove_upload_password = String::new();
         self.remove_upload_error = None;
         self.show_remove_upload_dialog = true;
     }
 
     fn reset_remove_upload_dialog(&mut self) {
+        if let Some(request) = self.remove_upload_request.take() {
+            self.cloud.cancel(request);
+        }
+
         self.show_remove_upload_dialog = false;
-        self.remove_upload_password = String::new();
         self.remove_upload_error = None;
+        self.remove_upload_notice = None;
         self.remove_upload_run_index = None;
     }
 
     fn remove_upload(&mut self) {
-        // Verify password first
-        if !verify_admin_password(&self.remove_upload_password) {
-            self.remove_upload_error = Some("Invalid admin password".to_string());
+        let Some(run) = self.remove_upload_run_index.and_then(|idx| self.history_runs.get(idx)) else {
             return;
-        }
-
-        if let Some(idx) = self.remove_upload_run_index {
-            if idx < self.history_runs.len() {
-                let run = &self.history_runs[idx];
-
-                // Delete from cloud if remote_id exists
-                if let Some(ref remote_id) = run.remote_id {
-                    if let Err(e) = self.cloud_client.delete(remote_id) {
-                        self.remove_upload_error = Some(format!("Failed to remove: {}", e));
-                        return;
-                    }
-                }
+        };
+        self.remove_upload_error = None;
 
-                // Clear upload status locally
-                if let Some(history_run) = self.history_runs.get_mut(idx) {
-                    history_run.remote_id = None;
-                    history_run.uploaded_at = None;
+        // Delete from cloud if we own the upload; the local status is cleared once it is gone.
+        // Without a deletion token only the local status can be cleared.
+        match (run.remote_id.clone(), run.delete_token.clone()) {
+            (Some(remote_id), Some(delete_token)) => {
+                self.remove_upload_request = Some(self.cloud.submit(CloudTask::Delete {
+                    remote_id,
+                    delete_token,
+                }));
+            }
+            _ => {
+                self.clear_upload_status();
+                self.remove_upload_notice = Some(
+                    "Only the local upload status was cleared. Without a deletion token the run could not be removed from the server and is still public.".to_string(),
+                );
+            }
+        }
+    }
 
-                    // Re-save to persist the change
-                    if let Err(e) = self.history_storage.save(history_run) {
-                        tracing::error!("Failed to update history after removing upload: {}", e);
-                    }
-                }
+    fn clear_upload_status(&mut self) {
+        // Clear upload status locally
+        if let Some(history_run) = self.remove_upload_run_index.and_then(|idx| self.history_runs.get_mut(idx)) {
+            history_run.remote_id = None;
+            history_run.uploaded_at = None;
+            history_run.delete_token = None;
 
-                tracing::info!("Upload removed successfully");
-                self.reset_remove_upload_dialog();
+            // Re-save to persist the change
+            if let Err(e) = self.history_storage.save(history_run) {
+                tracing::error!("Failed to update history after removing upload: {}", e);
             }
         }
-    }
 
-    fn upload_run(&mut self, run: &BenchmarkRun) {
-        self.upload_in_progress = true;
-        self.upload_error = None;
+        tracing::info!("Upload status cleared");
+    }
 
+    /// Redacted run and fields to upload. The preview shows exactly this, and it is what gets queued.
+    fn prepare_upload(&self, run: &BenchmarkRun) -> (BenchmarkRun, String, Option<String>, Option<String>) {
+        let display_name = self
+            .upload_policy
+            .apply_display_name(&self.upload_display_name, &run.system_info.hostname, &self.host_salt);
         let user_name = if self.upload_user_name.trim().is_empty() {
             None
         } else {
@@ -565,44 +859,177 @@ impl WorkBenchProApp {
         } else {
             Some(self.upload_description.trim().to_string())
         };
-        match self.cloud_client.upload(run, &self.upload_display_name, user_name, description) {
-            Ok(remote_id) => {
-                self.upload_in_progress = false;
-                self.upload_success = true;
-
-                // Update the run with remote ID
-                if let Some(idx) = self.upload_run_index {
-                    if let Some(history_run) = self.history_runs.get_mut(idx) {
-                        history_run.remote_id = Some(remote_id.clone());
-                        history_run.uploaded_at = Some(chrono::Utc::now());
-                        // Re-save to persist the remote_id
-                        if let Err(e) = self.history_storage.save(history_run) {
-                            tracing::error!("Failed to update history with remote ID: {}", e);
-                        }
-                    }
-                } else if let Some(ref mut last) = self.last_run {
-                    last.remote_id = Some(remote_id);
-                    last.uploaded_at = Some(chrono::Utc::now());
-                }
 
-                tracing::info!("Upload successful");
+        let mut upload = self.upload_policy.apply(run, &self.host_salt);
+        if upload.signature.is_some() && upload.content_hash() != run.content_hash() {
+            // Redaction changed the signed content; vouch for it again only if the original was intact
+            if run.signature_status == SignatureStatus::Valid {
+                upload.sign(&self.signing_key);
+            } else {
+                upload.signature = None;
+            }
+        }
+
+        (upload, display_name, user_name, description)
+    }
+
+    /// Payload the upload dialog would send, pretty-printed
+    fn upload_preview(&self, run: &BenchmarkRun) -> String {
+        let (run, display_name, user_name, description) = self.prepare_upload(run);
+        match CloudClient::payload(&run, &display_name, user_name, description) {
+            Ok(payload) => serde_json::to_string_pretty(&payload).unwrap_or_default(),
+            Err(e) => format!("Failed to build payload: {}", e),
+        }
+    }
+
+    fn upload_run(&mut self, run: &BenchmarkRun) {
+        self.upload_error = None;
+        let (run, display_name, user_name, description) = self.prepare_upload(run);
+
+        // Queue first so the upload survives network failures and restarts
+        match self.upload_queue.enqueue(&run, &display_name, user_name, description) {
+            Ok(()) => {
+                self.upload_in_progress = true;
+                self.upload_dialog_run = Some(run.id);
             }
             Err(e) => {
+                self.upload_error = Some(format!("Failed to queue upload: {}", e));
+            }
+        }
+    }
+
+    /// Start the next due upload in the background, one at a time
+    fn process_upload_queue(&mut self, ctx: &egui::Context) {
+        if self.upload_request.is_none() {
+            if let Some(entry) = self.upload_queue.next_due(chrono::Utc::now()).cloned() {
+                let request = self.cloud.submit(CloudTask::Upload {
+                    run: Box::new(entry.run.clone()),
+                    display_name: entry.display_name,
+                    user_name: entry.user_name,
+                    description: entry.description,
+                });
+                self.upload_request = Some((request, entry.run.id));
+            }
+        }
+
+        // Wake up for the next retry
+        if self.upload_request.is_none() {
+            if let Some(next) = self.upload_queue.next_attempt_at() {
+                let wait = (next - chrono::Utc::now()).to_std().unwrap_or_default();
+                ctx.request_repaint_after(wait);
+            }
+        }
+    }
+
+    fn finish_upload(&mut self, run_id: Uuid, message: CloudMessage) {
+        let for_dialog = self.upload_dialog_run == Some(run_id);
+
+        match message {
+            CloudMessage::Complete { result: CloudResult::Uploaded { remote_id, delete_token }, .. } => {
+                if let Err(e) = self.upload_queue.remove(run_id) {
+                    tracing::error!("Failed to update upload queue: {}", e);
+                }
+                self.mark_uploaded(run_id, remote_id, delete_token);
+
+                if for_dialog {
+                    self.upload_in_progress = false;
+                    self.upload_success = true;
+                }
+            }
+            CloudMessage::Cancelled { id } => {
+                // Cancelled from the dialog: the user no longer wants it uploaded. The request
+                // may still have reached the server, which the worker reports afterwards.
+                if let Err(e) = self.upload_queue.remove(run_id) {
+                    tracing::error!("Failed to update upload queue: {}", e);
+                }
+                self.cancelled_uploads.insert(id, run_id);
+                if for_dialog {
+                    self.upload_in_progress = false;
+                }
+            }
+            CloudMessage::Error { error, .. } => {
+                tracing::warn!("Upload of run {} failed: {}", run_id, error);
+                if let Err(e) = self.upload_queue.record_failure(run_id, &error, chrono::Utc::now()) {
+                    tracing::error!("Failed to update upload queue: {}", e);
+                }
+
+                if for_dialog {
+                    self.upload_in_progress = false;
+                    self.upload_error = Some(format!("{}\nThe upload is queued and will be retried automatically.", error));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the remote ID and deletion token on the local copies of a run and persist them
+    fn mark_uploaded(&mut self, run_id: Uuid, remote_id: String, delete_token: Option<String>) {
+        let now = chrono::Utc::now();
+        let runs = self.history_runs.iter_mut().chain(self.last_run.as_mut());
+
+        for run in runs.filter(|r| r.id == run_id) {
+            // Re-matching an existing upload returns no token; keep the one stored for it
+            if delete_token.is_some() || run.remote_id.as_ref() != Some(&remote_id) {
+                run.delete_token = delete_token.clone();
+            }
+            run.remote_id = Some(remote_id.clone());
+            run.uploaded_at = Some(now);
+            // Re-save to persist the remote_id
+            if let Err(e) = self.history_storage.save(run) {
+                tracing::error!("Failed to update history with remote ID: {}", e);
+            }
+        }
+    }
+
+    /// Stop the upload started from the dialog and take it off the queue
+    fn cancel_dialog_upload(&mut self) {
+        let Some(run_id) = self.upload_dialog_run else {
+            return;
+        };
+
+        match self.upload_request {
+            // In flight: the queue entry is dropped when `Cancelled` comes back
+            Some((request, id)) if id == run_id => self.cloud.cancel(request),
+            _ => {
+                if let Err(e) = self.upload_queue.remove(run_id) {
+                    tracing::error!("Failed to update upload queue: {}", e);
+                }
                 self.upload_in_progress = false;
-                self.upload_error = Some(e.to_string());
             }
         }
     }
 
+    /// Latest progress message of a request, if it is still running
+    fn cloud_status(&self, request: Option<RequestId>) -> Option<&str> {
+        match &self.cloud_status {
+            Some((id, message)) if Some(*id) == request => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Spinner with the latest progress message of a cloud request
+    fn show_cloud_progress(ui: &mut egui::Ui, status: Option<&str>, fallback: &str) {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label(
+                egui::RichText::new(status.unwrap_or(fallback))
+                    .size(Theme::SIZE_CAPTION)
+                    .color(Theme::TEXT_SECONDARY),
+            );
+        });
+    }
+
     fn reset_upload_dialog(&mut self) {
         self.show_upload_dialog = false;
         self.upload_in_progress = false;
         self.upload_error = None;
         self.upload_success = false;
         self.upload_run_index = None;
+        self.upload_dialog_run = None;
         self.upload_display_name = self.system_info.hostname.clone();
         self.upload_user_name = String::new();
         self.upload_description = String::new();
+        self.upload_policy = self.saved_upload_policy;
     }
 }
 
@@ -614,6 +1041,10 @@ impl eframe::App for WorkBenchProApp {
         // Process system check if running
         self.process_system_check();
 
+        // Send queued community uploads and handle finished cloud requests
+        self.process_upload_queue(ctx);
+        self.process_cloud_messages(ctx);
+
         // Process any pending window resize
         if let Some(size) = self.pending_window_resize.take() {
             ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
@@ -626,6 +1057,11 @@ impl eframe::App for WorkBenchProApp {
         let mut results_action = ResultsAction::None;
         let mut history_action = HistoryAction::None;
         let mut historic_view_back = false;
+        let mut trends_back = false;
+        let mut community_action = CommunityAction::None;
+        let mut community_run_back = false;
+        let mut community_compare = false;
+        let mut comparison_back = false;
 
         egui::CentralPanel::default().show(ctx, |ui| {
             match &self.state {
@@ -633,21 +1069,29 @@ impl eframe::App for WorkBenchProApp {
                     home_action = HomeView::show(ui, &self.system_info, &mut self.safe_mode);
                 }
                 AppState::PreCheck => {
-                    precheck_action = PreCheckView::show(ui, self.system_check.as_ref());
+                    precheck_action = PreCheckView::show(ui, self.system_check.as_ref(), &mut self.quiet_period_secs);
                 }
                 AppState::Running => {
                     action_cancel = RunningView::show(
                         ui,
                         self.overall_progress,
+                        self.eta_secs,
                         self.current_test_progress,
                         &self.current_test,
                         &self.current_message,
                         &self.completed_tests,
+                        &self.live_samples,
                     );
                 }
                 AppState::Results => {
                     if let Some(run) = &self.last_run {
-                        results_action = ResultsView::show_with_save(ui, run, self.recommendations.as_ref());
+                        results_action = ResultsView::show_with_save(
+                            ui,
+                            run,
+                            self.score_cache.get(run),
+                            self.recommendations.as_ref(),
+                            self.community_stats.as_ref().map(|cache| &cache.community),
+                        );
                     }
                 }
                 AppState::History => {
@@ -657,11 +1101,61 @@ impl eframe::App for WorkBenchProApp {
                         Some(self.history_storage.storage_path()),
                         self.last_save_error.as_deref(),
                         self.history_storage.last_load_stats.as_ref(),
+                        self.upload_queue.entries(),
                     );
                 }
                 AppState::ViewingHistoricRun(idx) => {
                     if let Some(run) = self.history_runs.get(*idx) {
-                        historic_view_back = ResultsView::show(ui, run);
+                        historic_view_back = ResultsView::show(
+                            ui,
+                            run,
+                            self.score_cache.get(run),
+                            self.community_stats.as_ref().map(|cache| &cache.community),
+                        );
+                    }
+                }
+                AppState::Trends => {
+                    trends_back = TrendsView::show(
+                        ui,
+                        &self.history_runs,
+                        &mut self.trend_test,
+                        &mut self.trend_show_bands,
+                    );
+                }
+                AppState::Community => {
+                    let loading = self.community_browse_request.or(self.community_fetch_request).map(|request| {
+                        match &self.cloud_status {
+                            Some((id, message)) if *id == request => message.as_str(),
+                            _ => "Loading community results...",
+                        }
+                    });
+                    community_action = CommunityView::show(
+                        ui,
+                        &mut self.community_filters,
+                        &self.community_runs,
+                        loading,
+                        self.community_error.as_deref(),
+                    );
+                }
+                AppState::CommunityRun => {
+                    if let Some(run) = &self.community_run {
+                        ui.add_space(8.0);
+                        community_compare = CommunityView::show_compare_bar(
+                            ui,
+                            &self.history_runs,
+                            &mut self.compare_local_idx,
+                        );
+                        community_run_back = ResultsView::show(
+                            ui,
+                            run,
+                            self.score_cache.get(run),
+                            self.community_stats.as_ref().map(|cache| &cache.community),
+                        );
+                    }
+                }
+                AppState::Comparison => {
+                    if let Some(report) = &self.comparison {
+                        comparison_back = ComparisonView::show(ui, report);
                     }
                 }
             }
@@ -670,6 +1164,16 @@ impl eframe::App for WorkBenchProApp {
         // Show upload dialog if active
         let mut upload_should_close = false;
         let mut upload_should_upload = false;
+        let mut upload_should_cancel = false;
+
+        // Run the upload dialog is for, so its payload can be previewed
+        let upload_target = if !self.show_upload_dialog {
+            None
+        } else if let Some(idx) = self.upload_run_index {
+            self.history_runs.get(idx).cloned()
+        } else {
+            self.last_run.clone()
+        };
 
         if self.show_upload_dialog {
             egui::Window::new("Upload to Community")
@@ -706,11 +1210,17 @@ impl eframe::App for WorkBenchProApp {
                             ui.add_space(8.0);
                             ui.spinner();
                             ui.label(
-                                egui::RichText::new("Uploading...")
+                                egui::RichText::new(
+                                    self.cloud_status(self.upload_request.map(|(id, _)| id)).unwrap_or("Uploading..."),
+                                )
                                     .size(Theme::SIZE_BODY)
                                     .color(Theme::TEXT_SECONDARY),
                             );
                             ui.add_space(8.0);
+
+                            if ui.button("Cancel").clicked() {
+                                upload_should_cancel = true;
+                            }
                         });
                     } else {
                         // Input state
@@ -754,19 +1264,45 @@ impl eframe::App for WorkBenchProApp {
                             ui.separator();
                             ui.add_space(8.0);
 
-                            // Privacy notice
+                            // Privacy controls
                             ui.label(
-                                egui::RichText::new("Privacy notice")
+                                egui::RichText::new("Privacy")
                                     .size(Theme::SIZE_CAPTION)
                                     .color(Theme::TEXT_PRIMARY)
                                     .strong(),
                             );
                             ui.add_space(4.0);
                             ui.label(
-                                egui::RichText::new("Only benchmark measurements and basic hardware info \n(CPU, RAM, storage type) are uploaded. No files, personal \ndata, or identifying information is collected. All fields \nabove are optional — your upload can be fully anonymous.")
+                                egui::RichText::new("Only benchmark measurements and hardware info are \nuploaded, never files or personal data. Check the \npreview below to see exactly what is sent.")
                                     .size(Theme::SIZE_CAPTION)
                                     .color(Theme::TEXT_SECONDARY),
                             );
+                            ui.add_space(4.0);
+                            ui.checkbox(&mut self.upload_policy.hash_hostname, "Replace computer name with an anonymous ID");
+                            ui.checkbox(&mut self.upload_policy.drop_storage_names, "Leave out storage device names");
+                            ui.checkbox(&mut self.upload_policy.drop_part_numbers, "Leave out firmware revisions and part numbers");
+                            ui.checkbox(&mut self.upload_policy.round_memory, "Round memory size to 4 GB");
+
+                            if self.upload_policy != self.saved_upload_policy && ui.small_button("Save as default").clicked() {
+                                match self.upload_policy.save() {
+                                    Ok(()) => self.saved_upload_policy = self.upload_policy,
+                                    Err(e) => self.upload_error = Some(format!("Failed to save privacy settings: {}", e)),
+                                }
+                            }
+
+                            if let Some(ref run) = upload_target {
+                                ui.add_space(4.0);
+                                egui::CollapsingHeader::new("Preview upload").show(ui, |ui| {
+                                    let preview = self.upload_preview(run);
+                                    egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                                        ui.add(
+                                            egui::TextEdit::multiline(&mut preview.as_str())
+                                                .code_editor()
+                                                .desired_width(280.0),
+                                        );
+                                    });
+                                });
+                            }
 
                             if let Some(ref err) = self.upload_error {
                                 ui.add_space(8.0);
@@ -805,19 +1341,16 @@ impl eframe::App for WorkBenchProApp {
                 });
         }
 
+        if upload_should_cancel {
+            self.cancel_dialog_upload();
+        }
+
         if upload_should_close {
             self.reset_upload_dialog();
         }
 
         if upload_should_upload {
-            // Get the run to upload
-            let run_to_upload = if let Some(idx) = self.upload_run_index {
-                self.history_runs.get(idx).cloned()
-            } else {
-                self.last_run.clone()
-            };
-
-            if let Some(run) = run_to_upload {
+            if let Some(run) = upload_target {
                 self.upload_run(&run);
             }
         }
@@ -827,6 +1360,10 @@ impl eframe::App for WorkBenchProApp {
         let mut remove_should_confirm = false;
 
         if self.show_remove_upload_dialog {
+            let has_delete_token = self.remove_upload_run_index
+                .and_then(|idx| self.history_runs.get(idx))
+                .is_some_and(|r| r.delete_token.is_some());
+
             egui::Window::new("Remove Upload")
                 .collapsible(false)
                 .resizable(false)
@@ -836,27 +1373,25 @@ impl eframe::App for WorkBenchProApp {
 
                     ui.vertical(|ui| {
                         ui.label(
-                            egui::RichText::new("Admin authentication required")
-                                .size(Theme::SIZE_BODY)
-                                .color(Theme::WARNING),
-                        );
-                        ui.add_space(8.0);
-
-                        ui.label(
-                            egui::RichText::new("Enter admin password:")
-                                .size(Theme::SIZE_CAPTION)
-                                .color(Theme::TEXT_SECONDARY),
-                        );
-                        let response = ui.add(
-                            egui::TextEdit::singleline(&mut self.remove_upload_password)
-                                .password(true)
-                                .desired_width(280.0)
-                                .hint_text("Admin password"),
+                            egui::RichText::new("Remove this run from the community results?")
+                                .size(Theme::SIZE_BODY),
                         );
 
-                        // Submit on Enter key
-                        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                            remove_should_confirm = true;
+                        // Uploads made before deletion tokens existed can't be retracted from here
+                        if let Some(ref notice) = self.remove_upload_notice {
+                            ui.add_space(8.0);
+                            ui.label(
+                                egui::RichText::new(notice)
+                                    .size(Theme::SIZE_CAPTION)
+                                    .color(Theme::WARNING),
+                            );
+                        } else if !has_delete_token {
+                            ui.add_space(8.0);
+                            ui.label(
+                                egui::RichText::new("No deletion token is stored for this upload, so it stays online. Only the local upload status will be cleared.")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .color(Theme::WARNING),
+                            );
                         }
 
                         if let Some(ref err) = self.remove_upload_error {
@@ -868,9 +1403,22 @@ impl eframe::App for WorkBenchProApp {
                             );
                         }
 
+                        let removing = self.remove_upload_request.is_some();
+                        if removing {
+                            ui.add_space(8.0);
+                            Self::show_cloud_progress(ui, self.cloud_status(self.remove_upload_request), "Removing...");
+                        }
+
                         ui.add_space(12.0);
 
                         ui.horizontal(|ui| {
+                            if self.remove_upload_notice.is_some() {
+                                if ui.button("Close").clicked() {
+                                    remove_should_close = true;
+                                }
+                                return;
+                            }
+
                             if ui.button("Cancel").clicked() {
                                 remove_should_close = true;
                             }
@@ -883,7 +1431,7 @@ impl eframe::App for WorkBenchProApp {
                             )
                             .fill(Theme::ERROR);
 
-                            if ui.add(remove_btn).clicked() {
+                            if ui.add_enabled(!removing, remove_btn).clicked() {
                                 remove_should_confirm = true;
                             }
                         });
@@ -905,11 +1453,10 @@ impl eframe::App for WorkBenchProApp {
 
         if self.show_delete_dialog {
             // Check if there's a cloud record (either local remote_id or found by matching)
-            let has_cloud_record = self.delete_run_index
-                .and_then(|idx| self.history_runs.get(idx))
-                .map(|r| r.remote_id.is_some())
-                .unwrap_or(false)
-                || self.delete_found_remote_id.is_some();
+            // The cloud copy can only be removed with the token its upload returned
+            let run = self.delete_run_index.and_then(|idx| self.history_runs.get(idx));
+            let owns_cloud_record = run.is_some_and(|r| r.remote_id.is_some() && r.delete_token.is_some());
+            let has_cloud_record = run.is_some_and(|r| r.remote_id.is_some()) || self.delete_found_remote_id.is_some();
 
             egui::Window::new("Delete Benchmark")
                 .collapsible(false)
@@ -925,13 +1472,16 @@ impl eframe::App for WorkBenchProApp {
                         );
                         ui.add_space(8.0);
 
-                        if has_cloud_record {
-                            let label = if self.delete_found_remote_id.is_some() {
-                                "Also remove from community results (found matching upload)"
-                            } else {
-                                "Also remove from community results"
-                            };
-                            ui.checkbox(&mut self.delete_also_cloud, label);
+                        if self.delete_match_request.is_some() {
+                            Self::show_cloud_progress(ui, None, "Checking community results...");
+                        } else if owns_cloud_record {
+                            ui.checkbox(&mut self.delete_also_cloud, "Also remove from community results");
+                        } else if has_cloud_record {
+                            ui.label(
+                                egui::RichText::new("This run was uploaded without a deletion token, so the community copy can't be removed from here.")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
                         }
 
                         if let Some(ref err) = self.delete_error {
@@ -943,6 +1493,12 @@ impl eframe::App for WorkBenchProApp {
                             );
                         }
 
+                        let deleting = self.delete_request.is_some();
+                        if deleting {
+                            ui.add_space(8.0);
+                            Self::show_cloud_progress(ui, self.cloud_status(self.delete_request), "Deleting...");
+                        }
+
                         ui.add_space(12.0);
 
                         ui.horizontal(|ui| {
@@ -958,7 +1514,7 @@ impl eframe::App for WorkBenchProApp {
                             )
                             .fill(Theme::ERROR);
 
-                            if ui.add(delete_btn).clicked() {
+                            if ui.add_enabled(!deleting, delete_btn).clicked() {
                                 delete_should_confirm = true;
                             }
                         });
@@ -985,6 +1541,15 @@ impl eframe::App for WorkBenchProApp {
                 self.reload_history();
                 self.state = AppState::History;
             }
+            HomeAction::Community => {
+                // Comparisons pick from local history
+                self.reload_history();
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 700.0)));
+                self.state = AppState::Community;
+                if self.community_runs.is_empty() {
+                    self.browse_community();
+                }
+            }
             #[cfg(feature = "debug-logging")]
             HomeAction::RunSingleTest(idx) => {
                 // Debug feature: run a single test directly (skip pre-check)
@@ -1009,7 +1574,16 @@ impl eframe::App for WorkBenchProApp {
             PreCheckAction::Proceed => {
                 // Expand window for benchmark running view
                 ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 750.0)));
-                self.start_benchmark();
+                self.start_benchmark(self.safe_mode, None);
+            }
+            PreCheckAction::ProceedInSafeMode => {
+                // Only this run is made smaller; the Safe Mode setting stays as the user left it
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 750.0)));
+                self.start_benchmark(true, None);
+            }
+            PreCheckAction::WaitForQuiet => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 750.0)));
+                self.start_benchmark(self.safe_mode, Some(Duration::from_secs(self.quiet_period_secs as u64)));
             }
         }
 
@@ -1035,7 +1609,7 @@ impl eframe::App for WorkBenchProApp {
                 self.show_upload_dialog = true;
             }
         }
-        if historic_view_back {
+        if historic_view_back || trends_back {
             self.state = AppState::History;
         }
 
@@ -1047,18 +1621,66 @@ impl eframe::App for WorkBenchProApp {
             }
             HistoryAction::ViewRun(idx) => {
                 self.state = AppState::ViewingHistoricRun(idx);
+                self.fetch_community_stats();
             }
             HistoryAction::Upload(idx) => {
                 self.upload_run_index = Some(idx);
                 self.upload_display_name = self.system_info.hostname.clone();
                 self.show_upload_dialog = true;
             }
+            HistoryAction::RetryUploads => {
+                if let Err(e) = self.upload_queue.retry_now() {
+                    tracing::error!("Failed to update upload queue: {}", e);
+                }
+            }
             HistoryAction::RemoveUpload(idx) => {
                 self.open_remove_upload_dialog(idx);
             }
             HistoryAction::DeleteRun(idx) => {
                 self.open_delete_dialog(idx);
             }
+            HistoryAction::Trends => {
+                // Make room for the chart
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 700.0)));
+                self.state = AppState::Trends;
+            }
+        }
+
+        // Handle community actions
+        match community_action {
+            CommunityAction::None => {}
+            CommunityAction::Back => {
+                self.state = AppState::Home;
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(755.0, 400.0)));
+            }
+            CommunityAction::Search => {
+                self.community_filters.page = 0;
+                self.browse_community();
+            }
+            CommunityAction::PreviousPage => {
+                self.community_filters.page = self.community_filters.page.saturating_sub(1);
+                self.browse_community();
+            }
+            CommunityAction::NextPage => {
+                self.community_filters.page += 1;
+                self.browse_community();
+            }
+            CommunityAction::Open(remote_id) => {
+                self.open_community_run(remote_id);
+            }
+        }
+        if community_run_back {
+            self.state = AppState::Community;
+        }
+        if community_compare {
+            let local = self.compare_local_idx.and_then(|idx| self.history_runs.get(idx));
+            if let (Some(local), Some(remote)) = (local, &self.community_run) {
+                self.comparison = Some(ComparisonReport::new(local.clone(), remote.clone()));
+                self.state = AppState::Comparison;
+            }
+        }
+        if comparison_back {
+            self.state = AppState::CommunityRun;
         }
     }
 }
@@ -261,6 +261,7 @@ impl Benchmark for AppLaunchBenchmark {
                 median: total_avg,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -130,6 +130,12 @@ impl Benchmark for ArchiveOpsBenchmark {
         45
     }
 
+    /// Source files average under 20 KB on disk; the archive and the extracted
+    /// copy take up to as much again each
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.app_archive_files as u64 * 20 * 1024 * 3
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         // Check if tar is available
         if !Self::is_tar_available() {
@@ -232,6 +238,7 @@ impl Benchmark for ArchiveOpsBenchmark {
                 median: avg_total,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
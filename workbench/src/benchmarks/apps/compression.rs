@@ -244,6 +244,7 @@ impl Benchmark for WindowsCompressionBenchmark {
                 median: total_avg,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -905,6 +905,7 @@ impl Benchmark for CSharpCompileBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -191,6 +191,7 @@ impl Benchmark for DefenderImpactBenchmark {
                 median: total_time / 4.0,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
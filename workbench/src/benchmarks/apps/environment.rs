@@ -175,6 +175,7 @@ impl Benchmark for EnvironmentBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
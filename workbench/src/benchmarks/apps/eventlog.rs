@@ -171,6 +171,7 @@ impl Benchmark for EventLogBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -173,6 +173,7 @@ impl Benchmark for NetworkBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -262,6 +262,7 @@ impl Benchmark for PowerShellBenchmark {
                 median: total_avg,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
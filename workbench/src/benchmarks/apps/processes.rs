@@ -172,6 +172,7 @@ impl Benchmark for ProcessesBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -157,6 +157,7 @@ impl Benchmark for RegistryBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
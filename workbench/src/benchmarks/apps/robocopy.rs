@@ -253,6 +253,7 @@ impl Benchmark for RobocopyBenchmark {
                 median: avg_total,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
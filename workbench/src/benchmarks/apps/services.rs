@@ -169,6 +169,7 @@ impl Benchmark for ServicesBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -208,6 +208,7 @@ impl Benchmark for SymlinkBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
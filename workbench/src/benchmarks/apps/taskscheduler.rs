@@ -161,6 +161,7 @@ impl Benchmark for TaskSchedulerBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
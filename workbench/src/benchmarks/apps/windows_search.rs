@@ -264,6 +264,7 @@ impl Benchmark for WindowsSearchBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -146,6 +146,7 @@ impl Benchmark for WmicBenchmark {
                 median: avg_combined,
                 std_dev: 0.0,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
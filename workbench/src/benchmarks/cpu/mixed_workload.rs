@@ -106,9 +106,15 @@ impl Benchmark for MixedWorkloadBenchmark {
         true
     }
 
+    /// 64 KB inputs, plus one run's compressed outputs of at most the same size
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.cpu_mixed_file_count as u64 * 64 * 1024 * 2
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         // Setup with configured file count
         let input_files = self.setup_with_count(progress, config.cpu_mixed_file_count)?;
+        let pool = config.thread_pool()?;
 
         progress.update(0.2, "Running mixed workload...");
 
@@ -128,30 +134,32 @@ impl Benchmark for MixedWorkloadBenchmark {
             let timer = Timer::new();
 
             // Process all files in parallel: read -> compress -> write
-            let results: Vec<std::io::Result<usize>> = input_files
-                .par_iter()
-                .map(|input_path| {
-                    // Read
-                    let mut file = File::open(input_path)?;
-                    let mut data = Vec::new();
-                    file.read_to_end(&mut data)?;
-
-                    // Compute (compress)
-                    let compressed = compress_prepend_size(&data);
-
-                    // Write
-                    let output_name = input_path
-                        .file_name()
-                        .unwrap()
-                        .to_string_lossy()
-                        .replace(".dat", ".lz4");
-                    let output_path = output_dir.join(output_name);
-                    let mut out_file = File::create(&output_path)?;
-                    out_file.write_all(&compressed)?;
-
-                    Ok(data.len() + compressed.len())
-                })
-                .collect();
+            let results: Vec<std::io::Result<usize>> = pool.install(|| {
+                input_files
+                    .par_iter()
+                    .map(|input_path| {
+                        // Read
+                        let mut file = File::open(input_path)?;
+                        let mut data = Vec::new();
+                        file.read_to_end(&mut data)?;
+
+                        // Compute (compress)
+                        let compressed = compress_prepend_size(&data);
+
+                        // Write
+                        let output_name = input_path
+                            .file_name()
+                            .unwrap()
+                            .to_string_lossy()
+                            .replace(".dat", ".lz4");
+                        let output_path = output_dir.join(output_name);
+                        let mut out_file = File::create(&output_path)?;
+                        out_file.write_all(&compressed)?;
+
+                        Ok(data.len() + compressed.len())
+                    })
+                    .collect()
+            });
 
             let elapsed = timer.elapsed_secs();
 
@@ -210,6 +218,7 @@ impl Benchmark for MixedWorkloadBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
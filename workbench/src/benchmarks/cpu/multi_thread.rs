@@ -53,7 +53,8 @@ impl Benchmark for MultiThreadBenchmark {
 
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         let chunk_size: usize = 64 * 1024; // 64KB chunks
-        let num_threads = rayon::current_num_threads();
+        let pool = config.thread_pool()?;
+        let num_threads = pool.current_num_threads();
         let chunks_per_thread = config.cpu_multi_thread_chunks as usize;
         let total_chunks = num_threads * chunks_per_thread;
 
@@ -78,9 +79,11 @@ impl Benchmark for MultiThreadBenchmark {
         progress.update(0.1, "Warming up...");
 
         // Warmup
-        chunks[..num_threads].par_iter().for_each(|data| {
-            let compressed = compress_prepend_size(data);
-            let _ = decompress_size_prepended(&compressed);
+        pool.install(|| {
+            chunks[..num_threads].par_iter().for_each(|data| {
+                let compressed = compress_prepend_size(data);
+                let _ = decompress_size_prepended(&compressed);
+            });
         });
 
         progress.update(0.2, "Running parallel benchmark...");
@@ -98,11 +101,13 @@ impl Benchmark for MultiThreadBenchmark {
 
             let timer = Timer::new();
 
-            chunks.par_iter().for_each(|data| {
-                let compressed = compress_prepend_size(data);
-                if let Ok(decompressed) = decompress_size_prepended(&compressed) {
-                    bytes_clone.fetch_add(data.len() + decompressed.len(), Ordering::Relaxed);
-                }
+            pool.install(|| {
+                chunks.par_iter().for_each(|data| {
+                    let compressed = compress_prepend_size(data);
+                    if let Ok(decompressed) = decompress_size_prepended(&compressed) {
+                        bytes_clone.fetch_add(data.len() + decompressed.len(), Ordering::Relaxed);
+                    }
+                });
             });
 
             let elapsed = timer.elapsed_secs();
@@ -149,6 +154,7 @@ impl Benchmark for MultiThreadBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
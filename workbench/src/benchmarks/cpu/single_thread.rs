@@ -137,6 +137,7 @@ impl Benchmark for SingleThreadBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, ProgressCallback};
+use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, LiveSample, ProgressCallback};
 use crate::core::Timer;
 use crate::models::{TestDetails, TestResult};
 
@@ -57,6 +57,11 @@ impl Benchmark for SustainedWriteBenchmark {
         true
     }
 
+    /// Each run replaces the previous run's file, so one file's worth at a time
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.cpu_sustained_write_gb as u64 * 1024 * 1024 * 1024
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         let total_size: u64 = config.cpu_sustained_write_gb as u64 * 1024 * 1024 * 1024;
         let chunk_size: usize = 4 * 1024 * 1024; // 4MB chunks
@@ -99,6 +104,7 @@ impl Benchmark for SustainedWriteBenchmark {
 
         let mut throughputs: Vec<f64> = Vec::new();
         let num_runs = config.iterations.min(2) as usize; // Capped at 2 due to large data size
+        let test_timer = Timer::new();
 
         for run in 0..num_runs {
             if progress.is_cancelled() {
@@ -112,6 +118,7 @@ impl Benchmark for SustainedWriteBenchmark {
             let mut file = File::create(&self.test_file)?;
             let timer = Timer::new();
             let mut bytes_written: u64 = 0;
+            let mut window_start = timer.elapsed_secs();
 
             for i in 0..num_chunks {
                 if progress.is_cancelled() {
@@ -127,6 +134,21 @@ impl Benchmark for SustainedWriteBenchmark {
                     file.sync_data()?;
                 }
 
+                // Live throughput per 64MB window - shows SLC cache exhaustion
+                if (i + 1) % 16 == 0 {
+                    let now = timer.elapsed_secs();
+                    let window_mb = (16 * chunk_size) as f64 / (1024.0 * 1024.0);
+                    if now > window_start {
+                        progress.sample(LiveSample {
+                            series: "Write throughput",
+                            unit: "MB/s",
+                            elapsed_secs: test_timer.elapsed_secs(),
+                            value: window_mb / (now - window_start),
+                        });
+                    }
+                    window_start = now;
+                }
+
                 if i % 64 == 0 {
                     let run_progress = 0.05 + (run as f32 / num_runs as f32) * 0.9
                         + (i as f32 / num_chunks as f32) * (0.9 / num_runs as f32);
@@ -197,6 +219,7 @@ impl Benchmark for SustainedWriteBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
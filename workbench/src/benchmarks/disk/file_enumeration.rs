@@ -122,6 +122,11 @@ impl Benchmark for FileEnumerationBenchmark {
         true
     }
 
+    /// Each small file still takes a whole 4 KiB cluster
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.disk_file_enum_count as u64 * 4096
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         // Setup with configured file count
         self.setup_with_count(progress, config.disk_file_enum_count)?;
@@ -190,6 +195,7 @@ impl Benchmark for FileEnumerationBenchmark {
                 median,
                 std_dev,
                 percentiles: Some(Percentiles::from_sorted_values(&durations_ms)),
+                ..Default::default()
             },
         })
     }
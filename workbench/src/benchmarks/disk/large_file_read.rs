@@ -9,6 +9,9 @@ use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, ProgressCallback};
 use crate::core::Timer;
 use crate::models::{TestDetails, TestResult};
 
+/// Size of the Safe Mode test file
+const LITE_FILE_MB: u64 = 128;
+
 /// Large file sequential read benchmark - simulates opening large CAD files
 pub struct LargeFileReadBenchmark {
     test_file: PathBuf,
@@ -110,6 +113,10 @@ impl Benchmark for LargeFileReadBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.disk_large_file_mb as u64 * 1024 * 1024
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         let file_size_mb = config.disk_large_file_mb;
 
@@ -177,6 +184,7 @@ impl Benchmark for LargeFileReadBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -195,10 +203,9 @@ impl LargeFileReadLiteBenchmark {
     }
 
     fn setup(&self, progress: &dyn ProgressCallback) -> Result<()> {
-        let size_mb: u32 = 128;
-        progress.update(0.0, &format!("Creating test file ({}MB)...", size_mb));
+        progress.update(0.0, &format!("Creating test file ({}MB)...", LITE_FILE_MB));
 
-        let file_size: u64 = size_mb as u64 * 1024 * 1024;
+        let file_size: u64 = LITE_FILE_MB * 1024 * 1024;
         let chunk_size: usize = 4 * 1024 * 1024;
         let num_chunks = (file_size / chunk_size as u64) as usize;
 
@@ -227,7 +234,7 @@ impl LargeFileReadLiteBenchmark {
     }
 
     fn run_read(&self) -> Result<f64> {
-        let file_size: u64 = 128 * 1024 * 1024;
+        let file_size: u64 = LITE_FILE_MB * 1024 * 1024;
         let chunk_size: usize = 1024 * 1024;
 
         let mut file = File::open(&self.test_file)?;
@@ -280,6 +287,10 @@ impl Benchmark for LargeFileReadLiteBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, _config: &BenchmarkConfig) -> u64 {
+        LITE_FILE_MB * 1024 * 1024
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         self.setup(progress)?;
 
@@ -330,13 +341,14 @@ impl Benchmark for LargeFileReadLiteBenchmark {
             unit: "MB/s".to_string(),
             details: TestDetails {
                 iterations: num_runs as u32,
-                duration_secs: (128.0 * num_runs as f64) / mean,
+                duration_secs: (LITE_FILE_MB as f64 * num_runs as f64) / mean,
                 min,
                 max,
                 mean,
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -146,6 +146,7 @@ impl Benchmark for MetadataOpsBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
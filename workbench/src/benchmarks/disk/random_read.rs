@@ -9,6 +9,9 @@ use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, ProgressCallback};
 use crate::core::Timer;
 use crate::models::{Percentiles, TestDetails, TestResult};
 
+/// Size of the Safe Mode test file
+const LITE_FILE_MB: u64 = 64;
+
 /// Small file random read benchmark - simulates loading source files
 pub struct RandomReadBenchmark {
     test_file: PathBuf,
@@ -88,6 +91,10 @@ impl Benchmark for RandomReadBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, config: &BenchmarkConfig) -> u64 {
+        config.disk_random_read_file_mb as u64 * 1024 * 1024
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         let file_size_mb = config.disk_random_read_file_mb;
         let num_reads = config.disk_random_read_count as usize;
@@ -171,6 +178,8 @@ impl Benchmark for RandomReadBenchmark {
                 median,
                 std_dev,
                 percentiles: Some(percentiles),
+                samples: TestDetails::downsample(&latencies_ms),
+                ..Default::default()
             },
         })
     }
@@ -190,10 +199,9 @@ impl RandomReadLiteBenchmark {
     }
 
     fn setup(&self, progress: &dyn ProgressCallback) -> Result<()> {
-        let size_mb = 64; // 64MB instead of 1GB
-        progress.update(0.0, &format!("Creating test file ({}MB)...", size_mb));
+        progress.update(0.0, &format!("Creating test file ({}MB)...", LITE_FILE_MB));
 
-        let file_size: u64 = size_mb as u64 * 1024 * 1024;
+        let file_size: u64 = LITE_FILE_MB * 1024 * 1024;
         let chunk_size: usize = 1024 * 1024;
         let num_chunks = (file_size / chunk_size as u64) as usize;
 
@@ -253,12 +261,16 @@ impl Benchmark for RandomReadLiteBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, _config: &BenchmarkConfig) -> u64 {
+        LITE_FILE_MB * 1024 * 1024
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, _config: &BenchmarkConfig) -> Result<TestResult> {
         self.setup(progress)?;
 
         progress.update(0.3, "Running random read tests...");
 
-        let file_size: u64 = 64 * 1024 * 1024; // 64MB
+        let file_size: u64 = LITE_FILE_MB * 1024 * 1024;
         let read_size: usize = 4096;
         let num_reads: usize = 5000; // Fewer reads for lite version
         let max_offset = file_size - read_size as u64;
@@ -330,6 +342,8 @@ impl Benchmark for RandomReadLiteBenchmark {
                 median,
                 std_dev,
                 percentiles: Some(percentiles),
+                samples: TestDetails::downsample(&latencies_ms),
+                ..Default::default()
             },
         })
     }
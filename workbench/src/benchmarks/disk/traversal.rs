@@ -196,6 +196,7 @@ impl Benchmark for TraversalBenchmark {
                 median,
                 std_dev,
                 percentiles: Some(Percentiles::from_sorted_values(&durations_ms)),
+                ..Default::default()
             },
         })
     }
@@ -147,6 +147,8 @@ impl Benchmark for ProcessSpawnBenchmark {
                     p99: p99,
                     p999: spawn_times_ms[((count as f64) * 0.999).min(count as f64 - 1.0) as usize],
                 }),
+                samples: TestDetails::downsample(&spawn_times_ms),
+                ..Default::default()
             },
         })
     }
@@ -5,10 +5,17 @@ use std::path::PathBuf;
 use anyhow::Result;
 use rand::Rng;
 
-use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, ProgressCallback};
+use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, LiveSample, ProgressCallback};
 use crate::core::Timer;
 use crate::models::{Percentiles, TestDetails, TestResult};
 
+/// Number of reads averaged into each live chart sample
+const LIVE_SAMPLE_WINDOW: usize = 250;
+
+const TEST_FILE_BYTES: u64 = 1024 * 1024 * 1024; // 1GB
+/// Size of the Safe Mode test file
+const LITE_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
 /// Storage latency distribution benchmark
 /// Measures P50, P95, P99, P99.9 latency for random 4KB reads
 pub struct StorageLatencyBenchmark {
@@ -25,7 +32,7 @@ impl StorageLatencyBenchmark {
     fn setup(&self, progress: &dyn ProgressCallback) -> Result<()> {
         progress.update(0.0, "Creating test file (1GB)...");
 
-        let file_size: u64 = 1024 * 1024 * 1024; // 1GB
+        let file_size: u64 = TEST_FILE_BYTES;
         let chunk_size: usize = 4 * 1024 * 1024; // 4MB chunks
         let num_chunks = (file_size / chunk_size as u64) as usize;
 
@@ -89,11 +96,15 @@ impl Benchmark for StorageLatencyBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, _config: &BenchmarkConfig) -> u64 {
+        TEST_FILE_BYTES
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
         // Setup
         self.setup(progress)?;
 
-        let file_size: u64 = 1024 * 1024 * 1024;
+        let file_size: u64 = TEST_FILE_BYTES;
         let read_size: usize = 4096; // 4KB
         let num_reads: usize = config.lat_storage_read_count as usize;
         let max_offset = file_size - read_size as u64;
@@ -104,6 +115,7 @@ impl Benchmark for StorageLatencyBenchmark {
         let mut buffer = vec![0u8; read_size];
         let mut rng = rand::thread_rng();
         let mut latencies_us: Vec<f64> = Vec::with_capacity(num_reads);
+        let test_timer = Timer::new();
 
         // Warmup
         for _ in 0..100 {
@@ -128,6 +140,17 @@ impl Benchmark for StorageLatencyBenchmark {
 
             latencies_us.push(elapsed_us);
 
+            // Live mean latency per window of reads
+            if (i + 1) % LIVE_SAMPLE_WINDOW == 0 {
+                let window = &latencies_us[latencies_us.len() - LIVE_SAMPLE_WINDOW..];
+                progress.sample(LiveSample {
+                    series: "Read latency",
+                    unit: "ms",
+                    elapsed_secs: test_timer.elapsed_secs(),
+                    value: window.iter().sum::<f64>() / LIVE_SAMPLE_WINDOW as f64 / 1000.0,
+                });
+            }
+
             if i % 1000 == 0 {
                 progress.update(
                     0.3 + (i as f32 / num_reads as f32) * 0.6,
@@ -197,6 +220,10 @@ impl Benchmark for StorageLatencyBenchmark {
                     p99: p99 / 1000.0,
                     p999: p999 / 1000.0,
                 }),
+                samples: TestDetails::downsample(
+                    &latencies_us.iter().map(|us| us / 1000.0).collect::<Vec<_>>(),
+                ),
+                ..Default::default()
             },
         })
     }
@@ -215,10 +242,9 @@ impl StorageLatencyLiteBenchmark {
     }
 
     fn setup(&self, progress: &dyn ProgressCallback) -> Result<()> {
-        let size_mb = 64;
-        progress.update(0.0, &format!("Creating test file ({}MB)...", size_mb));
+        progress.update(0.0, &format!("Creating test file ({}MB)...", LITE_FILE_BYTES / (1024 * 1024)));
 
-        let file_size: u64 = size_mb * 1024 * 1024;
+        let file_size: u64 = LITE_FILE_BYTES;
         let chunk_size: usize = 4 * 1024 * 1024;
         let num_chunks = (file_size / chunk_size as u64) as usize;
 
@@ -278,10 +304,14 @@ impl Benchmark for StorageLatencyLiteBenchmark {
         true
     }
 
+    fn scratch_bytes(&self, _config: &BenchmarkConfig) -> u64 {
+        LITE_FILE_BYTES
+    }
+
     fn run(&self, progress: &dyn ProgressCallback, _config: &BenchmarkConfig) -> Result<TestResult> {
         self.setup(progress)?;
 
-        let file_size: u64 = 64 * 1024 * 1024;
+        let file_size: u64 = LITE_FILE_BYTES;
         let read_size: usize = 4096;
         let num_reads: usize = 5000;
         let max_offset = file_size - read_size as u64;
@@ -292,6 +322,7 @@ impl Benchmark for StorageLatencyLiteBenchmark {
         let mut buffer = vec![0u8; read_size];
         let mut rng = rand::thread_rng();
         let mut latencies_us: Vec<f64> = Vec::with_capacity(num_reads);
+        let test_timer = Timer::new();
 
         // Warmup
         for _ in 0..50 {
@@ -315,6 +346,17 @@ impl Benchmark for StorageLatencyLiteBenchmark {
 
             latencies_us.push(elapsed_us);
 
+            // Live mean latency per window of reads
+            if (i + 1) % LIVE_SAMPLE_WINDOW == 0 {
+                let window = &latencies_us[latencies_us.len() - LIVE_SAMPLE_WINDOW..];
+                progress.sample(LiveSample {
+                    series: "Read latency",
+                    unit: "ms",
+                    elapsed_secs: test_timer.elapsed_secs(),
+                    value: window.iter().sum::<f64>() / LIVE_SAMPLE_WINDOW as f64 / 1000.0,
+                });
+            }
+
             if i % 500 == 0 {
                 progress.update(
                     0.3 + (i as f32 / num_reads as f32) * 0.6,
@@ -377,6 +419,10 @@ impl Benchmark for StorageLatencyLiteBenchmark {
                     p99: p99 / 1000.0,
                     p999: p999 / 1000.0,
                 }),
+                samples: TestDetails::downsample(
+                    &latencies_us.iter().map(|us| us / 1000.0).collect::<Vec<_>>(),
+                ),
+                ..Default::default()
             },
         })
     }
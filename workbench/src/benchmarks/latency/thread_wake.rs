@@ -199,6 +199,8 @@ impl Benchmark for ThreadWakeBenchmark {
                     p99: wake_times_us[((count as f64) * 0.99) as usize],
                     p999: wake_times_us[((count as f64) * 0.999).min(count as f64 - 1.0) as usize],
                 }),
+                samples: TestDetails::downsample(&wake_times_us),
+                ..Default::default()
             },
         })
     }
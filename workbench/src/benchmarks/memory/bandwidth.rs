@@ -47,7 +47,8 @@ impl Benchmark for MemoryBandwidthBenchmark {
     }
 
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult> {
-        let num_threads = rayon::current_num_threads();
+        let pool = config.thread_pool()?;
+        let num_threads = pool.current_num_threads();
         let per_thread_size: usize = config.mem_bandwidth_buffer_mb as usize * 1024 * 1024;
         let total_size = per_thread_size * num_threads;
 
@@ -73,12 +74,14 @@ impl Benchmark for MemoryBandwidthBenchmark {
         progress.update(0.1, "Warming up...");
 
         // Warmup
-        sources
-            .par_iter()
-            .zip(destinations.par_iter_mut())
-            .for_each(|(src, dst)| {
-                dst.copy_from_slice(src);
-            });
+        pool.install(|| {
+            sources
+                .par_iter()
+                .zip(destinations.par_iter_mut())
+                .for_each(|(src, dst)| {
+                    dst.copy_from_slice(src);
+                });
+        });
 
         progress.update(0.2, "Measuring memory bandwidth...");
 
@@ -93,15 +96,17 @@ impl Benchmark for MemoryBandwidthBenchmark {
 
             let timer = Timer::new();
 
-            for _ in 0..copies_per_run {
-                // Parallel copy: all threads copy simultaneously
-                sources
-                    .par_iter()
-                    .zip(destinations.par_iter_mut())
-                    .for_each(|(src, dst)| {
-                        dst.copy_from_slice(src);
-                    });
-            }
+            pool.install(|| {
+                for _ in 0..copies_per_run {
+                    // Parallel copy: all threads copy simultaneously
+                    sources
+                        .par_iter()
+                        .zip(destinations.par_iter_mut())
+                        .for_each(|(src, dst)| {
+                            dst.copy_from_slice(src);
+                        });
+                }
+            });
 
             let elapsed = timer.elapsed_secs();
             let bytes_copied = total_size * copies_per_run * 2; // Read + Write
@@ -147,6 +152,7 @@ impl Benchmark for MemoryBandwidthBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
@@ -140,6 +140,7 @@ impl Benchmark for MemoryLatencyBenchmark {
                 median,
                 std_dev,
                 percentiles: None,
+                ..Default::default()
             },
         })
     }
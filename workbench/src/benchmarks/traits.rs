@@ -11,6 +11,8 @@ pub struct BenchmarkConfig {
     pub iterations: u32,
     /// Custom test path (None = system temp)
     pub test_path: Option<PathBuf>,
+    /// Worker threads for multi-threaded tests, sized to the CPUs this process may use
+    pub threads: usize,
 
     // Disk settings
     pub disk_file_enum_count: u32,
@@ -50,6 +52,11 @@ impl BenchmarkConfig {
     pub fn test_dir(&self) -> PathBuf {
         self.test_path.clone().unwrap_or_else(std::env::temp_dir)
     }
+
+    /// Thread pool for parallel work, with `threads` workers
+    pub fn thread_pool(&self) -> Result<rayon::ThreadPool> {
+        Ok(rayon::ThreadPoolBuilder::new().num_threads(self.threads.max(1)).build()?)
+    }
 }
 
 impl Default for BenchmarkConfig {
@@ -57,6 +64,7 @@ impl Default for BenchmarkConfig {
         Self {
             iterations: 3,
             test_path: None,
+            threads: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
 
             // Disk - Quick preset (fast execution)
             disk_file_enum_count: 10_000,
@@ -102,14 +110,7 @@ pub enum Category {
 }
 
 impl Category {
-    pub fn label(&self) -> &'static str {
-        match self {
-            Category::ProjectOperations => "Project Operations",
-            Category::BuildPerformance => "Build Performance",
-            Category::Responsiveness => "Responsiveness",
-        }
-    }
-
+    /// Highest score a single test can reach (2.5x the reference machine)
     pub fn max_score(&self) -> u32 {
         2500
     }
@@ -139,10 +140,29 @@ pub trait Benchmark: Send + Sync {
         false
     }
 
+    /// Peak disk space its scratch files take under `config`, in bytes.
+    /// The pre-check sums this over queued tests against free space on `test_dir()`.
+    fn scratch_bytes(&self, _config: &BenchmarkConfig) -> u64 {
+        0
+    }
+
     /// Run the benchmark and return results
     fn run(&self, progress: &dyn ProgressCallback, config: &BenchmarkConfig) -> Result<TestResult>;
 }
 
+/// A single point of a live time series reported while a benchmark runs
+#[derive(Debug, Clone)]
+pub struct LiveSample {
+    /// Series name, e.g. "Write throughput"
+    pub series: &'static str,
+    /// Unit of the y value, e.g. "MB/s"
+    pub unit: &'static str,
+    /// Seconds since the benchmark started
+    pub elapsed_secs: f64,
+    /// Measured value
+    pub value: f64,
+}
+
 /// Callback for reporting progress during benchmark execution
 pub trait ProgressCallback: Send + Sync {
     /// Update progress (0.0 - 1.0) with a message
@@ -150,6 +170,9 @@ pub trait ProgressCallback: Send + Sync {
 
     /// Check if the benchmark should be cancelled
     fn is_cancelled(&self) -> bool;
+
+    /// Report a live measurement for charting (ignored by default)
+    fn sample(&self, _sample: LiveSample) {}
 }
 
 /// No-op progress callback for testing
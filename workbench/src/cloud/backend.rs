@@ -0,0 +1,308 @@
+//! Results backend abstraction
+//!
+//! Community features talk to a `ResultsBackend`, so the public Supabase database,
+//! a self-hosted team server, or an in-memory store can be swapped freely.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::{BenchmarkRun, StorageType, SystemInfo};
+
+#[derive(Debug, Error)]
+pub enum CloudError {
+    #[error("Network error: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("Failed to parse response: {0}")]
+    Parse(String),
+    #[error("Server error: {0}")]
+    Server(String),
+    #[error("Cloud configuration error: {0}")]
+    Config(String),
+}
+
+/// A summary of a community benchmark run for browsing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityRun {
+    pub id: String,
+    pub display_name: String,
+    pub cpu_name: String,
+    pub cpu_cores: i32,
+    pub cpu_threads: i32,
+    pub memory_gb: f64,
+    pub os_name: String,
+    pub storage_type: Option<String>,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+/// Filter options for browsing community results
+#[derive(Debug, Clone, Default)]
+pub struct BrowseFilter {
+    pub cpu_contains: Option<String>,
+    pub os_name: Option<String>,
+    pub min_memory_gb: Option<f64>,
+    pub limit: usize,
+    /// Number of runs to skip, for paging
+    pub offset: usize,
+}
+
+impl BrowseFilter {
+    pub fn new() -> Self {
+        Self {
+            limit: 50,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_cpu(mut self, cpu: &str) -> Self {
+        self.cpu_contains = Some(cpu.to_string());
+        self
+    }
+
+    pub fn with_os(mut self, os: &str) -> Self {
+        self.os_name = Some(os.to_string());
+        self
+    }
+
+    pub fn with_min_memory(mut self, gb: f64) -> Self {
+        self.min_memory_gb = Some(gb);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Core-count band of a hardware class
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoreBand {
+    #[serde(rename = "1-4")]
+    UpTo4,
+    #[serde(rename = "5-8")]
+    UpTo8,
+    #[serde(rename = "9-16")]
+    UpTo16,
+    #[serde(rename = "17+")]
+    Over16,
+}
+
+impl CoreBand {
+    pub fn of(cores: u32) -> Self {
+        match cores {
+            0..=4 => CoreBand::UpTo4,
+            5..=8 => CoreBand::UpTo8,
+            9..=16 => CoreBand::UpTo16,
+            _ => CoreBand::Over16,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoreBand::UpTo4 => "1-4 cores",
+            CoreBand::UpTo8 => "5-8 cores",
+            CoreBand::UpTo16 => "9-16 cores",
+            CoreBand::Over16 => "17+ cores",
+        }
+    }
+}
+
+/// Installed-memory band of a hardware class
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoryBand {
+    #[serde(rename = "8")]
+    UpTo8,
+    #[serde(rename = "16")]
+    Around16,
+    #[serde(rename = "32")]
+    Around32,
+    #[serde(rename = "64+")]
+    AtLeast64,
+}
+
+impl MemoryBand {
+    /// Bands are centred on common module sizes, since usable memory reads a little low
+    pub fn of(memory_gb: f64) -> Self {
+        if memory_gb < 12.0 {
+            MemoryBand::UpTo8
+        } else if memory_gb < 24.0 {
+            MemoryBand::Around16
+        } else if memory_gb < 48.0 {
+            MemoryBand::Around32
+        } else {
+            MemoryBand::AtLeast64
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemoryBand::UpTo8 => "8 GB or less",
+            MemoryBand::Around16 => "16 GB",
+            MemoryBand::Around32 => "32 GB",
+            MemoryBand::AtLeast64 => "64 GB+",
+        }
+    }
+}
+
+/// Slice of the community that statistics are computed over. Fields left as
+/// `None` match every machine, so the default class is the whole community.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HardwareClass {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub core_band: Option<CoreBand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_band: Option<MemoryBand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_type: Option<StorageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_name: Option<String>,
+}
+
+impl HardwareClass {
+    /// The class a machine belongs to, with every field set
+    pub fn of(info: &SystemInfo) -> Self {
+        Self {
+            cpu_vendor: Some(Self::normalize_vendor(&info.cpu.vendor)),
+            core_band: Some(CoreBand::of(info.cpu.cores)),
+            memory_band: Some(MemoryBand::of(info.memory.total_gb())),
+            storage_type: info.storage.first().map(|s| s.device_type),
+            os_name: Some(info.os.name.clone()),
+        }
+    }
+
+    /// Whether a machine falls in this class
+    pub fn matches(&self, info: &SystemInfo) -> bool {
+        let other = Self::of(info);
+        fn field_matches<T: PartialEq>(wanted: &Option<T>, actual: &Option<T>) -> bool {
+            wanted.is_none() || wanted == actual
+        }
+
+        field_matches(&self.cpu_vendor, &other.cpu_vendor)
+            && field_matches(&self.core_band, &other.core_band)
+            && field_matches(&self.memory_band, &other.memory_band)
+            && field_matches(&self.storage_type, &other.storage_type)
+            && field_matches(&self.os_name, &other.os_name)
+    }
+
+    /// Short description, e.g. "AMD, 9-16 cores, 32 GB, NVMe, Windows"
+    pub fn label(&self) -> String {
+        let parts: Vec<&str> = [
+            self.cpu_vendor.as_deref(),
+            self.core_band.map(|b| b.label()),
+            self.memory_band.map(|b| b.label()),
+            self.storage_type.map(|t| t.label()),
+            self.os_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if parts.is_empty() {
+            "All machines".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    /// CPUID vendor strings such as "GenuineIntel" reduced to the brand
+    fn normalize_vendor(vendor: &str) -> String {
+        let lower = vendor.to_lowercase();
+        if lower.contains("intel") {
+            "Intel".to_string()
+        } else if lower.contains("amd") {
+            "AMD".to_string()
+        } else if lower.contains("apple") {
+            "Apple".to_string()
+        } else {
+            vendor.trim().to_string()
+        }
+    }
+}
+
+/// A histogram bucket for distribution visualization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub bucket_start: f64,
+    pub bucket_end: f64,
+    pub count: i64,
+}
+
+/// Statistics for a single test across all community runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestStatistics {
+    pub test_id: String,
+    pub test_name: String,
+    pub unit: String,
+    pub sample_count: i64,
+    pub min_value: f64,
+    pub max_value: f64,
+    pub mean_value: f64,
+    pub std_dev: f64,
+    pub p10: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub histogram_buckets: Vec<HistogramBucket>,
+}
+
+/// Percentile rank for a user's test result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PercentileRank {
+    pub test_id: String,
+    pub test_name: String,
+    pub unit: String,
+    pub user_value: f64,
+    pub percentile_rank: f64,
+    pub beats_count: i64,
+    pub total_count: i64,
+    pub is_higher_better: bool,
+}
+
+/// What the server hands back for a new upload
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadReceipt {
+    pub remote_id: String,
+    /// Secret that authorises deleting the upload; servers without ownership
+    /// tokens don't issue one
+    pub delete_token: Option<String>,
+}
+
+/// Storage and statistics service for shared benchmark runs
+pub trait ResultsBackend: Send + Sync {
+    /// Browse uploaded benchmark runs, newest first
+    fn browse(&self, filter: &BrowseFilter) -> Result<Vec<CommunityRun>, CloudError>;
+
+    /// Fetch full details of a specific benchmark run
+    fn fetch(&self, id: &str) -> Result<BenchmarkRun, CloudError>;
+
+    /// Upload a local benchmark run, returning its remote ID and deletion token
+    fn upload(
+        &self,
+        run: &BenchmarkRun,
+        display_name: &str,
+        user_name: Option<String>,
+        description: Option<String>,
+    ) -> Result<UploadReceipt, CloudError>;
+
+    /// Delete an uploaded benchmark run, authorised by the token its upload returned
+    fn delete(&self, remote_id: &str, delete_token: &str) -> Result<(), CloudError>;
+
+    /// Find an uploaded run by machine name and timestamp, returning its remote ID
+    fn find_matching(&self, machine_name: &str, run_timestamp: &DateTime<Utc>) -> Result<Option<String>, CloudError>;
+
+    /// Statistics with histogram buckets for all tests, over the runs of one hardware class
+    fn fetch_statistics(&self, class: &HardwareClass) -> Result<Vec<TestStatistics>, CloudError>;
+
+    /// Percentile rank of each test of an uploaded run
+    fn fetch_percentile_rank(&self, run_id: &str) -> Result<Vec<PercentileRank>, CloudError>;
+}
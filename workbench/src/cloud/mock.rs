@@ -0,0 +1,234 @@
+//! In-memory results backend for tests
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use super::backend::{
+    BrowseFilter, CloudError, CommunityRun, HardwareClass, PercentileRank, ResultsBackend, TestStatistics, UploadReceipt,
+};
+use super::statistics;
+use crate::models::{BenchmarkRun, CategoryResults};
+
+/// Uploaded run and the metadata it was uploaded with
+struct StoredRun {
+    run: BenchmarkRun,
+    display_name: String,
+    uploaded_at: DateTime<Utc>,
+    delete_token: String,
+}
+
+/// Results backend that keeps uploads in memory, in upload order
+#[derive(Default)]
+pub struct MockBackend {
+    runs: Mutex<Vec<(String, StoredRun)>>,
+    /// When set, each upload waits for a message on it before completing
+    upload_gate: Mutex<Option<Receiver<()>>>,
+    /// Class whose statistics requests fail, like a server without the sliced RPC
+    failing_class: Mutex<Option<HardwareClass>>,
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold uploads in flight until the returned sender lets them through, one per message
+    pub fn hold_uploads(&self) -> Sender<()> {
+        let (release, gate) = channel();
+        *self.upload_gate.lock().unwrap() = Some(gate);
+        release
+    }
+
+    /// Make statistics requests for `class` fail
+    pub fn fail_statistics_for(&self, class: HardwareClass) {
+        *self.failing_class.lock().unwrap() = Some(class);
+    }
+
+    fn all_results(&self, class: &HardwareClass) -> Vec<CategoryResults> {
+        let runs = self.runs.lock().unwrap();
+        runs.iter()
+            .filter(|(_, stored)| class.matches(&stored.run.system_info))
+            .map(|(_, stored)| stored.run.results.clone())
+            .collect()
+    }
+}
+
+impl ResultsBackend for MockBackend {
+    fn browse(&self, filter: &BrowseFilter) -> Result<Vec<CommunityRun>, CloudError> {
+        let runs = self.runs.lock().unwrap();
+        let contains = |haystack: &str, needle: &Option<String>| {
+            needle
+                .as_ref()
+                .is_none_or(|n| haystack.to_lowercase().contains(&n.to_lowercase()))
+        };
+
+        Ok(runs
+            .iter()
+            .rev()
+            .filter(|(_, s)| contains(&s.run.system_info.cpu.name, &filter.cpu_contains))
+            .filter(|(_, s)| contains(&s.run.system_info.os.name, &filter.os_name))
+            .filter(|(_, s)| {
+                filter
+                    .min_memory_gb
+                    .is_none_or(|min| s.run.system_info.memory.total_gb() >= min)
+            })
+            .skip(filter.offset)
+            .take(filter.limit)
+            .map(|(id, s)| CommunityRun {
+                id: id.clone(),
+                display_name: s.display_name.clone(),
+                cpu_name: s.run.system_info.cpu.name.clone(),
+                cpu_cores: s.run.system_info.cpu.cores as i32,
+                cpu_threads: s.run.system_info.cpu.threads as i32,
+                memory_gb: s.run.system_info.memory.total_gb(),
+                os_name: s.run.system_info.os.name.clone(),
+                storage_type: None,
+                uploaded_at: s.uploaded_at,
+            })
+            .collect())
+    }
+
+    fn fetch(&self, id: &str) -> Result<BenchmarkRun, CloudError> {
+        let runs = self.runs.lock().unwrap();
+        let (_, stored) = runs
+            .iter()
+            .find(|(stored_id, _)| stored_id == id)
+            .ok_or_else(|| CloudError::Parse("Run not found".to_string()))?;
+
+        let mut run = stored.run.clone();
+        run.remote_id = Some(id.to_string());
+        run.uploaded_at = Some(stored.uploaded_at);
+        run.verify_signature();
+        Ok(run)
+    }
+
+    fn upload(
+        &self,
+        run: &BenchmarkRun,
+        display_name: &str,
+        _user_name: Option<String>,
+        _description: Option<String>,
+    ) -> Result<UploadReceipt, CloudError> {
+        if let Some(gate) = self.upload_gate.lock().unwrap().as_ref() {
+            let _ = gate.recv();
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let delete_token = uuid::Uuid::new_v4().simple().to_string();
+        self.runs.lock().unwrap().push((
+            id.clone(),
+            StoredRun {
+                run: run.clone(),
+                display_name: display_name.to_string(),
+                uploaded_at: Utc::now(),
+                delete_token: delete_token.clone(),
+            },
+        ));
+        Ok(UploadReceipt {
+            remote_id: id,
+            delete_token: Some(delete_token),
+        })
+    }
+
+    fn delete(&self, remote_id: &str, delete_token: &str) -> Result<(), CloudError> {
+        let mut runs = self.runs.lock().unwrap();
+        if runs.iter().any(|(id, s)| id == remote_id && s.delete_token != delete_token) {
+            return Err(CloudError::Server("403 Forbidden: invalid delete token".to_string()));
+        }
+        runs.retain(|(id, _)| id != remote_id);
+        Ok(())
+    }
+
+    fn find_matching(&self, machine_name: &str, run_timestamp: &DateTime<Utc>) -> Result<Option<String>, CloudError> {
+        let runs = self.runs.lock().unwrap();
+        Ok(runs
+            .iter()
+            .find(|(_, s)| s.run.machine_name == machine_name && s.run.timestamp == *run_timestamp)
+            .map(|(id, _)| id.clone()))
+    }
+
+    fn fetch_statistics(&self, class: &HardwareClass) -> Result<Vec<TestStatistics>, CloudError> {
+        if self.failing_class.lock().unwrap().as_ref() == Some(class) {
+            return Err(CloudError::Server("404 Not Found: get_test_statistics".to_string()));
+        }
+        Ok(statistics::test_statistics(&self.all_results(class)))
+    }
+
+    fn fetch_percentile_rank(&self, run_id: &str) -> Result<Vec<PercentileRank>, CloudError> {
+        let run = self.fetch(run_id)?;
+        Ok(statistics::percentile_ranks(&run.results, &self.all_results(&HardwareClass::default())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SystemInfo, TestDetails, TestResult};
+
+    fn create_run(machine: &str, read_mbps: f64) -> BenchmarkRun {
+        let mut run = BenchmarkRun::new(
+            machine.to_string(),
+            SystemInfo::test_fixture(machine),
+        );
+        run.results = CategoryResults {
+            project_operations: vec![TestResult {
+                test_id: "large_file_read".to_string(),
+                name: "Large File Read".to_string(),
+                description: String::new(),
+                value: read_mbps,
+                unit: "MB/s".to_string(),
+                details: TestDetails::default(),
+            }],
+            ..Default::default()
+        };
+        run
+    }
+
+    #[test]
+    fn test_upload_browse_fetch_delete() {
+        let backend: Box<dyn ResultsBackend> = Box::new(MockBackend::new());
+        let run = create_run("PC-A", 1000.0);
+
+        let receipt = backend.upload(&run, "Desk", None, None).unwrap();
+        let id = receipt.remote_id;
+        assert_eq!(backend.browse(&BrowseFilter::new()).unwrap().len(), 1);
+        assert!(backend.browse(&BrowseFilter::new().with_os("linux")).unwrap().is_empty());
+        assert!(backend.browse(&BrowseFilter::new().with_offset(1)).unwrap().is_empty());
+        assert_eq!(backend.fetch(&id).unwrap().remote_id.as_deref(), Some(id.as_str()));
+        assert_eq!(backend.find_matching("PC-A", &run.timestamp).unwrap(), Some(id.clone()));
+
+        // Only the owner of the upload can delete it
+        assert!(backend.delete(&id, "not-the-token").is_err());
+        backend.delete(&id, receipt.delete_token.as_deref().unwrap()).unwrap();
+        assert!(backend.fetch(&id).is_err());
+    }
+
+    #[test]
+    fn test_statistics_and_percentile_rank() {
+        let backend = MockBackend::new();
+        let ids: Vec<String> = [("PC-A", 500.0), ("PC-B", 1000.0), ("PC-C", 2000.0)]
+            .into_iter()
+            .map(|(machine, value)| backend.upload(&create_run(machine, value), machine, None, None).unwrap().remote_id)
+            .collect();
+
+        let stats = backend.fetch_statistics(&HardwareClass::default()).unwrap();
+        assert_eq!(stats[0].sample_count, 3);
+        assert_eq!(stats[0].p50, 1000.0);
+
+        // A workstation only counts towards its own class
+        let mut workstation = create_run("WS-A", 4000.0);
+        workstation.system_info.cpu.cores = 32;
+        backend.upload(&workstation, "WS-A", None, None).unwrap();
+
+        let laptop_class = HardwareClass::of(&create_run("PC-D", 0.0).system_info);
+        assert_eq!(backend.fetch_statistics(&laptop_class).unwrap()[0].sample_count, 3);
+        let workstation_class = HardwareClass::of(&workstation.system_info);
+        assert_eq!(backend.fetch_statistics(&workstation_class).unwrap()[0].sample_count, 1);
+
+        let ranks = backend.fetch_percentile_rank(&ids[2]).unwrap();
+        assert_eq!(ranks[0].beats_count, 2);
+        assert!(ranks[0].is_higher_better);
+    }
+}
@@ -1,8 +1,20 @@
 //! Cloud sync module for community benchmark sharing
 //!
 //! Provides functionality to browse, upload, and fetch benchmark results
-//! from the community database.
+//! from the community database or a self-hosted results server.
 
+mod backend;
 mod client;
+mod config;
+#[cfg(test)]
+pub mod mock;
+mod privacy;
+mod ranking;
+pub mod statistics;
+mod worker;
 
+pub use backend::{BrowseFilter, CommunityRun, HardwareClass, PercentileRank, TestStatistics};
 pub use client::CloudClient;
+pub use privacy::{HostSalt, RedactionPolicy};
+pub use ranking::{estimate_percentile_rank, estimate_percentile_ranks, CommunityStatistics};
+pub use worker::{CloudMessage, CloudResult, CloudTask, CloudWorker, RequestId};
@@ -0,0 +1,240 @@
+//! Redaction applied to runs before they are uploaded
+//!
+//! The policy is saved as `privacy.json` in the app data directory. The upload
+//! dialog previews the payload of the redacted run, and that same redacted run
+//! is what gets queued and sent.
+//!
+//! Hostnames are replaced with a salted hash. The salt is derived from this
+//! install's signing key, so pseudonyms stay stable across uploads from one
+//! machine but can't be reversed by hashing a list of likely hostnames.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::models::BenchmarkRun;
+use crate::storage::app_data_dir;
+
+/// Memory is rounded to a multiple of this many GiB
+const MEMORY_ROUNDING_GB: u64 = 4;
+
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// Which identifying fields are anonymised before upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionPolicy {
+    /// Replace the hostname and machine name with a stable hash
+    pub hash_hostname: bool,
+    /// Remove storage device names (models, volume labels) and mount points
+    pub drop_storage_names: bool,
+    /// Remove storage firmware revisions and memory module part numbers
+    pub drop_part_numbers: bool,
+    /// Round installed memory to a multiple of 4 GB
+    pub round_memory: bool,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            hash_hostname: true,
+            drop_storage_names: true,
+            drop_part_numbers: true,
+            round_memory: false,
+        }
+    }
+}
+
+impl RedactionPolicy {
+    /// Load the saved policy, or the default if none was saved
+    pub fn load() -> Self {
+        Self::read_file(&Self::policy_path()).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::policy_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn policy_path() -> PathBuf {
+        app_data_dir().join("privacy.json")
+    }
+
+    fn read_file(path: &PathBuf) -> Option<Self> {
+        let json = fs::read_to_string(path).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(policy) => Some(policy),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Copy of `run` with the policy applied. The run keeps its ID and timestamp.
+    pub fn apply(&self, run: &BenchmarkRun, salt: &HostSalt) -> BenchmarkRun {
+        let mut run = run.clone();
+        let info = &mut run.system_info;
+
+        if self.hash_hostname {
+            run.machine_name = salt.pseudonym(&run.machine_name);
+            info.hostname = salt.pseudonym(&info.hostname);
+        }
+
+        if self.drop_storage_names {
+            for storage in &mut info.storage {
+                storage.name.clear();
+                storage.model = None;
+                storage.mount_point = None;
+            }
+        }
+
+        if self.drop_part_numbers {
+            for storage in &mut info.storage {
+                storage.firmware = None;
+            }
+            for module in &mut info.memory.modules {
+                module.part_number = None;
+            }
+        }
+
+        if self.round_memory {
+            let step = MEMORY_ROUNDING_GB * GIB;
+            let rounded = (info.memory.total_bytes + step / 2) / step * step;
+            info.memory.total_bytes = rounded.max(step);
+        }
+
+        // Local bookkeeping never leaves the machine
+        run.notes = None;
+        run.tags.clear();
+        run.delete_token = None;
+
+        run
+    }
+
+    /// Display name to upload. A display name that is just the hostname is hashed with it.
+    pub fn apply_display_name(&self, display_name: &str, hostname: &str, salt: &HostSalt) -> String {
+        if self.hash_hostname && display_name.eq_ignore_ascii_case(hostname) {
+            salt.pseudonym(hostname)
+        } else {
+            display_name.to_string()
+        }
+    }
+}
+
+/// Per-install secret mixed into hostname pseudonyms
+#[derive(Clone)]
+pub struct HostSalt([u8; 32]);
+
+impl HostSalt {
+    /// Derived from the signing key, which is random per install and never uploaded
+    pub fn from_signing_key(key: &SigningKey) -> Self {
+        Self(
+            Sha256::new()
+                .chain_update(b"workbench-pro hostname salt")
+                .chain_update(key.to_bytes())
+                .finalize()
+                .into(),
+        )
+    }
+
+    /// Stable pseudonym for a hostname, so uploads from one machine still group together
+    fn pseudonym(&self, hostname: &str) -> String {
+        let digest = Sha256::new()
+            .chain_update(self.0)
+            .chain_update(hostname.to_lowercase().as_bytes())
+            .finalize();
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("host-{}", &hex[..12])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cloud::CloudClient;
+    use crate::models::{MemoryModule, StorageInfo, StorageType, SystemInfo};
+
+    fn salt() -> HostSalt {
+        HostSalt::from_signing_key(&SigningKey::from_bytes(&[7; 32]))
+    }
+
+    fn create_run() -> BenchmarkRun {
+        let mut system_info = SystemInfo::test_fixture("ALICE-LAPTOP");
+        system_info.memory.total_bytes = 31 * GIB + GIB / 2;
+        system_info.storage = vec![StorageInfo {
+            name: "Alice's Samsung 990 PRO".to_string(),
+            device_type: StorageType::NVMe,
+            capacity_bytes: 2000 * GIB,
+            mount_point: Some("/home/alice".to_string()),
+            firmware: Some("4B2QJXD7".to_string()),
+            ..Default::default()
+        }];
+        system_info.memory.modules = vec![MemoryModule {
+            slot: "DIMM_A1".to_string(),
+            size_bytes: 16 * GIB,
+            part_number: Some("KF432C16BB/16".to_string()),
+            ..Default::default()
+        }];
+        let mut run = BenchmarkRun::new("ALICE-LAPTOP".to_string(), system_info);
+        run.notes = Some("after driver update".to_string());
+        run.delete_token = Some("secret".to_string());
+        run
+    }
+
+    #[test]
+    fn test_redaction_removes_identifying_fields() {
+        let run = create_run();
+        let policy = RedactionPolicy {
+            hash_hostname: true,
+            drop_storage_names: true,
+            drop_part_numbers: true,
+            round_memory: true,
+        };
+
+        let redacted = policy.apply(&run, &salt());
+        assert_eq!(redacted.id, run.id);
+        assert!(redacted.machine_name.starts_with("host-"));
+        assert_eq!(redacted.machine_name, policy.apply(&run, &salt()).machine_name);
+        assert_eq!(redacted.system_info.memory.total_bytes, 32 * GIB);
+        assert_eq!(policy.apply_display_name("alice-laptop", "ALICE-LAPTOP", &salt()), redacted.machine_name);
+        assert_eq!(policy.apply_display_name("Desk", "ALICE-LAPTOP", &salt()), "Desk");
+
+        // Another install can't reproduce the pseudonym
+        let other = HostSalt::from_signing_key(&SigningKey::from_bytes(&[8; 32]));
+        assert_ne!(policy.apply(&run, &other).machine_name, redacted.machine_name);
+
+        // The payload that gets sent carries none of it
+        let payload = CloudClient::payload(&redacted, "Desk", None, None).unwrap().to_string();
+        assert!(!payload.contains("ALICE"));
+        assert!(!payload.contains("Samsung"));
+        assert!(!payload.contains("alice"));
+        assert!(!payload.contains("secret"));
+        assert!(!payload.contains("4B2QJXD7"));
+        assert!(!payload.contains("KF432C16BB"));
+    }
+
+    #[test]
+    fn test_disabled_policy_keeps_fields() {
+        let policy = RedactionPolicy {
+            hash_hostname: false,
+            drop_storage_names: false,
+            drop_part_numbers: false,
+            round_memory: false,
+        };
+
+        let redacted = policy.apply(&create_run(), &salt());
+        assert_eq!(redacted.machine_name, "ALICE-LAPTOP");
+        assert_eq!(redacted.system_info.storage[0].name, "Alice's Samsung 990 PRO");
+        assert_eq!(redacted.system_info.memory.total_bytes, 31 * GIB + GIB / 2);
+        assert_eq!(redacted.system_info.storage[0].firmware.as_deref(), Some("4B2QJXD7"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Percentile ranks estimated locally from community statistics
+//!
+//! Works for any run, uploaded or not, and needs no connection once the
+//! statistics are cached. Ranks are interpolated within histogram buckets, so
+//! they are estimates rather than the exact ranks the server computes.
+
+use serde::{Deserialize, Serialize};
+
+use super::backend::{HardwareClass, PercentileRank, TestStatistics};
+use crate::models::{BenchmarkRun, SystemInfo, TestResult};
+
+/// Statistics over the whole community and over one hardware class
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommunityStatistics {
+    /// Every uploaded run
+    pub statistics: Vec<TestStatistics>,
+    /// Class the `similar` statistics were computed for, normally this machine's
+    #[serde(default)]
+    pub class: HardwareClass,
+    #[serde(default)]
+    pub similar: Vec<TestStatistics>,
+}
+
+impl CommunityStatistics {
+    pub fn for_test(&self, test_id: &str) -> Option<&TestStatistics> {
+        self.statistics.iter().find(|s| s.test_id == test_id)
+    }
+
+    /// Statistics among similar machines, if `info` describes a machine of the fetched class
+    pub fn similar_for_test(&self, test_id: &str, info: &SystemInfo) -> Option<&TestStatistics> {
+        if !self.class.matches(info) {
+            return None;
+        }
+        self.similar.iter().find(|s| s.test_id == test_id)
+    }
+}
+
+/// Rank of every test of `run` that has community statistics
+pub fn estimate_percentile_ranks(run: &BenchmarkRun, statistics: &[TestStatistics]) -> Vec<PercentileRank> {
+    run.results
+        .iter()
+        .filter_map(|result| {
+            let stats = statistics.iter().find(|s| s.test_id == result.test_id)?;
+            estimate_percentile_rank(result, stats)
+        })
+        .collect()
+}
+
+/// Share of community runs `result` beats, direction-aware
+pub fn estimate_percentile_rank(result: &TestResult, stats: &TestStatistics) -> Option<PercentileRank> {
+    let total: i64 = stats.histogram_buckets.iter().map(|b| b.count).sum();
+    if total == 0 || !result.value.is_finite() {
+        return None;
+    }
+
+    // Samples strictly below and above the value, assuming each bucket is evenly filled
+    let (mut below, mut above) = (0.0, 0.0);
+    for bucket in &stats.histogram_buckets {
+        let count = bucket.count as f64;
+        let width = bucket.bucket_end - bucket.bucket_start;
+        if width > 0.0 {
+            let fraction = ((result.value - bucket.bucket_start) / width).clamp(0.0, 1.0);
+            below += count * fraction;
+            above += count * (1.0 - fraction);
+        } else if result.value > bucket.bucket_start {
+            below += count;
+        } else if result.value < bucket.bucket_start {
+            above += count;
+        }
+    }
+
+    let higher_is_better = result.higher_is_better();
+    let beats = if higher_is_better { below } else { above };
+
+    Some(PercentileRank {
+        test_id: result.test_id.clone(),
+        test_name: result.name.clone(),
+        unit: result.unit.clone(),
+        user_value: result.value,
+        percentile_rank: beats / total as f64 * 100.0,
+        beats_count: beats.round() as i64,
+        total_count: total,
+        is_higher_better: higher_is_better,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cloud::statistics::{percentile_ranks, test_statistics};
+    use crate::models::{CategoryResults, TestDetails};
+
+    fn results(test_id: &str, value: f64, unit: &str) -> CategoryResults {
+        CategoryResults {
+            responsiveness: vec![TestResult {
+                test_id: test_id.to_string(),
+                name: test_id.to_string(),
+                description: String::new(),
+                value,
+                unit: unit.to_string(),
+                details: TestDetails::default(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_estimate_is_close_to_exact_rank() {
+        let runs: Vec<CategoryResults> = (1..=200).map(|v| results("read", v as f64, "MB/s")).collect();
+        let stats = test_statistics(&runs);
+
+        for value in [1.0, 37.5, 120.0, 200.0] {
+            let run = results("read", value, "MB/s");
+            let exact = &percentile_ranks(&run, &runs)[0];
+            let estimate = estimate_percentile_rank(&run.responsiveness[0], &stats[0]).unwrap();
+            assert!((estimate.percentile_rank - exact.percentile_rank).abs() < 1.0, "value {}", value);
+        }
+    }
+
+    #[test]
+    fn test_estimate_respects_direction() {
+        let runs: Vec<CategoryResults> = (1..=100).map(|v| results("storage_latency", v as f64, "ms")).collect();
+        let stats = test_statistics(&runs);
+
+        // Lower latency beats almost everyone
+        let fast = estimate_percentile_rank(&results("storage_latency", 2.0, "ms").responsiveness[0], &stats[0]).unwrap();
+        assert!(!fast.is_higher_better);
+        assert!(fast.percentile_rank > 95.0);
+
+        // Values outside the community range are clamped to 0 and 100
+        let slow = estimate_percentile_rank(&results("storage_latency", 500.0, "ms").responsiveness[0], &stats[0]).unwrap();
+        assert_eq!(slow.percentile_rank, 0.0);
+    }
+}
@@ -0,0 +1,186 @@
+//! Community statistics computed from uploaded results
+//!
+//! Used by the results server binary to answer `get_test_statistics` and
+//! `get_percentile_rank` without a database.
+
+use std::collections::BTreeMap;
+
+use super::backend::{HistogramBucket, PercentileRank, TestStatistics};
+use crate::models::CategoryResults;
+
+/// Number of equal-width histogram buckets per test
+pub const HISTOGRAM_BUCKETS: usize = 20;
+
+/// All values of one test across the uploaded runs
+struct TestValues {
+    name: String,
+    unit: String,
+    higher_is_better: bool,
+    values: Vec<f64>,
+}
+
+fn collect_values<'a>(runs: impl IntoIterator<Item = &'a CategoryResults>) -> BTreeMap<String, TestValues> {
+    let mut tests: BTreeMap<String, TestValues> = BTreeMap::new();
+    for results in runs {
+        for result in results.iter().filter(|r| r.value.is_finite()) {
+            tests
+                .entry(result.test_id.clone())
+                .or_insert_with(|| TestValues {
+                    name: result.name.clone(),
+                    unit: result.unit.clone(),
+                    higher_is_better: result.higher_is_better(),
+                    values: Vec::new(),
+                })
+                .values
+                .push(result.value);
+        }
+    }
+    tests
+}
+
+/// Per-test statistics with histogram buckets, sorted by test_id
+pub fn test_statistics<'a>(runs: impl IntoIterator<Item = &'a CategoryResults>) -> Vec<TestStatistics> {
+    collect_values(runs)
+        .into_iter()
+        .map(|(test_id, mut test)| {
+            test.values.sort_by(|a, b| a.total_cmp(b));
+            let values = &test.values;
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let std_dev = (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+            let percentile = |p: f64| values[((p / 100.0) * (values.len() - 1) as f64).round() as usize];
+
+            TestStatistics {
+                test_id,
+                test_name: test.name,
+                unit: test.unit,
+                sample_count: values.len() as i64,
+                min_value: values[0],
+                max_value: values[values.len() - 1],
+                mean_value: mean,
+                std_dev,
+                p10: percentile(10.0),
+                p25: percentile(25.0),
+                p50: percentile(50.0),
+                p75: percentile(75.0),
+                p90: percentile(90.0),
+                histogram_buckets: histogram(values),
+            }
+        })
+        .collect()
+}
+
+/// Equal-width buckets between the smallest and largest sorted value
+fn histogram(sorted_values: &[f64]) -> Vec<HistogramBucket> {
+    let min = sorted_values[0];
+    let max = sorted_values[sorted_values.len() - 1];
+    if max <= min {
+        return vec![HistogramBucket {
+            bucket_start: min,
+            bucket_end: max,
+            count: sorted_values.len() as i64,
+        }];
+    }
+
+    let width = (max - min) / HISTOGRAM_BUCKETS as f64;
+    let mut counts = [0i64; HISTOGRAM_BUCKETS];
+    for value in sorted_values {
+        let idx = (((value - min) / width) as usize).min(HISTOGRAM_BUCKETS - 1);
+        counts[idx] += 1;
+    }
+
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| HistogramBucket {
+            bucket_start: min + i as f64 * width,
+            bucket_end: min + (i + 1) as f64 * width,
+            count,
+        })
+        .collect()
+}
+
+/// Percentage of uploaded runs each test of `run` beats, direction-aware
+pub fn percentile_ranks<'a>(
+    run: &CategoryResults,
+    runs: impl IntoIterator<Item = &'a CategoryResults>,
+) -> Vec<PercentileRank> {
+    let tests = collect_values(runs);
+
+    run.iter()
+        .filter_map(|result| {
+            let test = tests.get(&result.test_id)?;
+            let beats_count = test
+                .values
+                .iter()
+                .filter(|v| {
+                    if test.higher_is_better {
+                        **v < result.value
+                    } else {
+                        **v > result.value
+                    }
+                })
+                .count() as i64;
+            let total_count = test.values.len() as i64;
+
+            Some(PercentileRank {
+                test_id: result.test_id.clone(),
+                test_name: result.name.clone(),
+                unit: result.unit.clone(),
+                user_value: result.value,
+                percentile_rank: beats_count as f64 / total_count as f64 * 100.0,
+                beats_count,
+                total_count,
+                is_higher_better: test.higher_is_better,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TestDetails, TestResult};
+
+    fn results(test_id: &str, value: f64, unit: &str) -> CategoryResults {
+        CategoryResults {
+            responsiveness: vec![TestResult {
+                test_id: test_id.to_string(),
+                name: test_id.to_string(),
+                description: String::new(),
+                value,
+                unit: unit.to_string(),
+                details: TestDetails::default(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_statistics_and_histogram() {
+        let runs: Vec<CategoryResults> = (1..=100).map(|v| results("read", v as f64, "MB/s")).collect();
+
+        let stats = test_statistics(&runs);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].sample_count, 100);
+        assert_eq!(stats[0].min_value, 1.0);
+        assert_eq!(stats[0].max_value, 100.0);
+        assert_eq!(stats[0].p50, 51.0);
+        assert_eq!(stats[0].histogram_buckets.len(), HISTOGRAM_BUCKETS);
+        assert_eq!(stats[0].histogram_buckets.iter().map(|b| b.count).sum::<i64>(), 100);
+    }
+
+    #[test]
+    fn test_percentile_rank_respects_direction() {
+        let runs = vec![
+            results("latency", 1.0, "ms"),
+            results("latency", 2.0, "ms"),
+            results("latency", 4.0, "ms"),
+        ];
+
+        let ranks = percentile_ranks(&runs[0], &runs);
+        assert_eq!(ranks[0].beats_count, 2);
+        assert!(!ranks[0].is_higher_better);
+        assert!(percentile_ranks(&results("unknown", 1.0, "ms"), &runs).is_empty());
+    }
+}
@@ -0,0 +1,375 @@
+//! Background execution of results backend calls
+//!
+//! Every request runs on its own thread and reports back through a channel,
+//! the same way `BenchmarkRunner` does, so the UI never blocks on the network.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use chrono::{DateTime, Utc};
+
+use super::backend::{BrowseFilter, CommunityRun, HardwareClass, ResultsBackend};
+use super::ranking::CommunityStatistics;
+use crate::models::BenchmarkRun;
+
+/// Identifies a submitted request in the messages it produces
+pub type RequestId = u64;
+
+/// Work the cloud worker can do
+#[derive(Debug, Clone)]
+pub enum CloudTask {
+    /// Upload a run, reusing an existing upload of the same run if the server has one
+    Upload {
+        run: Box<BenchmarkRun>,
+        display_name: String,
+        user_name: Option<String>,
+        description: Option<String>,
+    },
+    Delete {
+        remote_id: String,
+        delete_token: String,
+    },
+    FindMatching {
+        machine_name: String,
+        run_timestamp: DateTime<Utc>,
+    },
+    /// Statistics for the whole community and for the hardware class `similar_to`
+    FetchStatistics {
+        similar_to: HardwareClass,
+    },
+    Browse(BrowseFilter),
+    Fetch {
+        remote_id: String,
+    },
+}
+
+/// Successful outcome of a `CloudTask`
+#[derive(Debug, Clone)]
+pub enum CloudResult {
+    /// `delete_token` is None when the server issued none, or when an earlier
+    /// attempt turned out to have been uploaded already; keep any token stored for it
+    Uploaded { remote_id: String, delete_token: Option<String> },
+    Deleted,
+    Matched { remote_id: Option<String> },
+    Statistics(CommunityStatistics),
+    Browsed(Vec<CommunityRun>),
+    Fetched(Box<BenchmarkRun>),
+}
+
+/// Messages sent from request threads to the UI
+#[derive(Debug, Clone)]
+pub enum CloudMessage {
+    Progress { id: RequestId, message: String },
+    Complete { id: RequestId, result: CloudResult },
+    Error { id: RequestId, error: String },
+    Cancelled { id: RequestId },
+}
+
+impl CloudMessage {
+    pub fn id(&self) -> RequestId {
+        match self {
+            CloudMessage::Progress { id, .. }
+            | CloudMessage::Complete { id, .. }
+            | CloudMessage::Error { id, .. }
+            | CloudMessage::Cancelled { id } => *id,
+        }
+    }
+
+    /// Whether this is the last message of its request
+    pub fn is_final(&self) -> bool {
+        !matches!(self, CloudMessage::Progress { .. })
+    }
+}
+
+/// Runs results backend calls in background threads
+pub struct CloudWorker {
+    backend: Arc<dyn ResultsBackend>,
+    sender: Sender<CloudMessage>,
+    receiver: Receiver<CloudMessage>,
+    next_id: RequestId,
+    /// Cancel flags of requests that have not reported a final message yet
+    in_flight: HashMap<RequestId, Arc<Mutex<bool>>>,
+    /// Cancelled requests whose thread is still running
+    cancelled: HashSet<RequestId>,
+    /// Messages generated locally (cancellations) waiting to be polled
+    pending: Vec<CloudMessage>,
+}
+
+impl CloudWorker {
+    pub fn new(backend: Arc<dyn ResultsBackend>) -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            backend,
+            sender,
+            receiver,
+            next_id: 1,
+            in_flight: HashMap::new(),
+            cancelled: HashSet::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Start a request in the background
+    pub fn submit(&mut self, task: CloudTask) -> RequestId {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let cancel_flag = Arc::new(Mutex::new(false));
+        self.in_flight.insert(id, Arc::clone(&cancel_flag));
+
+        let backend = Arc::clone(&self.backend);
+        let tx = self.sender.clone();
+        thread::spawn(move || {
+            let cancelled = || *cancel_flag.lock().unwrap();
+            let progress = |message: &str| {
+                let _ = tx.send(CloudMessage::Progress {
+                    id,
+                    message: message.to_string(),
+                });
+            };
+
+            let result = Self::run_task(backend.as_ref(), task, &progress, &cancelled);
+
+            // Always end with a final message, even after cancelling, so `poll` can
+            // forget the request; it decides what of a cancelled request gets through
+            let _ = tx.send(match result {
+                Ok(result) => CloudMessage::Complete { id, result },
+                Err(error) => CloudMessage::Error { id, error },
+            });
+        });
+
+        id
+    }
+
+    fn run_task(
+        backend: &dyn ResultsBackend,
+        task: CloudTask,
+        progress: &dyn Fn(&str),
+        cancelled: &dyn Fn() -> bool,
+    ) -> Result<CloudResult, String> {
+        match task {
+            CloudTask::Upload {
+                run,
+                display_name,
+                user_name,
+                description,
+            } => {
+                // An earlier attempt may have reached the server even though it reported an error
+                progress("Checking for an earlier upload...");
+                if let Ok(Some(remote_id)) = backend.find_matching(&run.machine_name, &run.timestamp) {
+                    return Ok(CloudResult::Uploaded {
+                        remote_id,
+                        delete_token: None,
+                    });
+                }
+                if cancelled() {
+                    return Err("Cancelled".to_string());
+                }
+
+                progress("Uploading...");
+                backend
+                    .upload(&run, &display_name, user_name, description)
+                    .map(|receipt| CloudResult::Uploaded {
+                        remote_id: receipt.remote_id,
+                        delete_token: receipt.delete_token,
+                    })
+            }
+            CloudTask::Delete {
+                remote_id,
+                delete_token,
+            } => {
+                progress("Removing from community results...");
+                backend.delete(&remote_id, &delete_token).map(|_| CloudResult::Deleted)
+            }
+            CloudTask::FindMatching {
+                machine_name,
+                run_timestamp,
+            } => {
+                progress("Looking for a matching upload...");
+                backend
+                    .find_matching(&machine_name, &run_timestamp)
+                    .map(|remote_id| CloudResult::Matched { remote_id })
+            }
+            CloudTask::FetchStatistics { similar_to } => {
+                progress("Fetching community statistics...");
+                backend.fetch_statistics(&HardwareClass::default()).map(|statistics| {
+                    // Whole-community statistics are still worth having without the slice
+                    progress("Fetching statistics for similar machines...");
+                    let similar = backend.fetch_statistics(&similar_to).unwrap_or_else(|e| {
+                        tracing::warn!("Failed to fetch statistics for {}: {}", similar_to.label(), e);
+                        Vec::new()
+                    });
+                    CloudResult::Statistics(CommunityStatistics {
+                        statistics,
+                        class: similar_to,
+                        similar,
+                    })
+                })
+            }
+            CloudTask::Browse(filter) => {
+                progress("Loading community results...");
+                backend.browse(&filter).map(CloudResult::Browsed)
+            }
+            CloudTask::Fetch { remote_id } => {
+                progress("Downloading run...");
+                backend.fetch(&remote_id).map(|run| CloudResult::Fetched(Box::new(run)))
+            }
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Cancel a request. `Cancelled` is reported at once and the result is discarded,
+    /// except a finished upload: that is on the server whatever the user wanted, so
+    /// its `Uploaded` still arrives afterwards to record the remote ID and token.
+    pub fn cancel(&mut self, id: RequestId) {
+        if let Some(flag) = self.in_flight.remove(&id) {
+            if let Ok(mut flag) = flag.lock() {
+                *flag = true;
+            }
+            self.cancelled.insert(id);
+            self.pending.push(CloudMessage::Cancelled { id });
+        }
+    }
+
+    /// Check if any request is in flight
+    pub fn is_busy(&self) -> bool {
+        !self.in_flight.is_empty()
+    }
+
+    /// Collect all messages received since the last poll
+    pub fn poll(&mut self) -> Vec<CloudMessage> {
+        let mut messages = std::mem::take(&mut self.pending);
+        for message in self.receiver.try_iter() {
+            let id = message.id();
+            if self.in_flight.contains_key(&id) {
+                if message.is_final() {
+                    self.in_flight.remove(&id);
+                }
+                messages.push(message);
+            } else if message.is_final()
+                && self.cancelled.remove(&id)
+                && matches!(message, CloudMessage::Complete { result: CloudResult::Uploaded { .. }, .. })
+            {
+                messages.push(message);
+            }
+        }
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::cloud::mock::MockBackend;
+    use crate::models::SystemInfo;
+
+    /// Poll until the request finishes, returning its final message
+    fn wait_for(worker: &mut CloudWorker, id: RequestId) -> CloudMessage {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            if let Some(message) = worker.poll().into_iter().find(|m| m.id() == id && m.is_final()) {
+                return message;
+            }
+            assert!(Instant::now() < deadline, "request {} did not finish", id);
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_requests_report_results() {
+        let mut worker = CloudWorker::new(Arc::new(MockBackend::new()));
+
+        let id = worker.submit(CloudTask::FetchStatistics {
+            similar_to: HardwareClass::default(),
+        });
+        assert!(worker.is_busy());
+        assert!(matches!(
+            wait_for(&mut worker, id),
+            CloudMessage::Complete { result: CloudResult::Statistics(stats), .. } if stats.statistics.is_empty()
+        ));
+        assert!(!worker.is_busy());
+
+        let id = worker.submit(CloudTask::Delete {
+            remote_id: "missing".to_string(),
+            delete_token: "token".to_string(),
+        });
+        assert!(matches!(
+            wait_for(&mut worker, id),
+            CloudMessage::Complete { result: CloudResult::Deleted, .. }
+        ));
+    }
+
+    #[test]
+    fn test_cancel_reports_cancelled() {
+        let mut worker = CloudWorker::new(Arc::new(MockBackend::new()));
+
+        let id = worker.submit(CloudTask::FetchStatistics {
+            similar_to: HardwareClass::default(),
+        });
+        worker.cancel(id);
+
+        assert!(matches!(wait_for(&mut worker, id), CloudMessage::Cancelled { .. }));
+        assert!(!worker.is_busy());
+
+        // Nothing else arrives for the cancelled request
+        thread::sleep(Duration::from_millis(50));
+        assert!(worker.poll().is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_upload_still_reports_uploaded() {
+        let backend = Arc::new(MockBackend::new());
+        let release = backend.hold_uploads();
+        let mut worker = CloudWorker::new(backend.clone());
+        let run = BenchmarkRun::new("PC-A".to_string(), SystemInfo::test_fixture("PC-A"));
+
+        let id = worker.submit(CloudTask::Upload {
+            run: Box::new(run),
+            display_name: "PC-A".to_string(),
+            user_name: None,
+            description: None,
+        });
+
+        // Cancel once the upload itself is on its way
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !worker
+            .poll()
+            .iter()
+            .any(|m| matches!(m, CloudMessage::Progress { message, .. } if message == "Uploading..."))
+        {
+            assert!(Instant::now() < deadline, "upload did not start");
+            thread::sleep(Duration::from_millis(5));
+        }
+        worker.cancel(id);
+        assert!(matches!(worker.poll().as_slice(), [CloudMessage::Cancelled { .. }]));
+        release.send(()).unwrap();
+
+        match wait_for(&mut worker, id) {
+            CloudMessage::Complete { result: CloudResult::Uploaded { remote_id, delete_token }, .. } => {
+                assert!(backend.fetch(&remote_id).is_ok());
+                assert!(delete_token.is_some());
+            }
+            other => panic!("expected the upload to be reported, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_statistics_without_similar_machines() {
+        let backend = Arc::new(MockBackend::new());
+        let run = BenchmarkRun::new("PC-A".to_string(), SystemInfo::test_fixture("PC-A"));
+        backend.upload(&run, "PC-A", None, None).unwrap();
+        let class = HardwareClass::of(&run.system_info);
+        backend.fail_statistics_for(class.clone());
+        let mut worker = CloudWorker::new(backend);
+
+        let id = worker.submit(CloudTask::FetchStatistics { similar_to: class });
+        match wait_for(&mut worker, id) {
+            CloudMessage::Complete { result: CloudResult::Statistics(stats), .. } => assert!(stats.similar.is_empty()),
+            other => panic!("expected community statistics, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Linux background activity from procfs: disk I/O, swap traffic and pressure stalls
+//!
+//! Counters are cumulative, so activity is measured as the difference between
+//! two snapshots taken around the pre-check's sampling window.
+
+use std::time::Duration;
+
+use super::fs_tree::FsTree;
+use super::system_check::{BackgroundActivity, PressureStall};
+
+/// Block devices whose activity isn't disk I/O
+const VIRTUAL_DEVICES: &[&str] = &["loop", "ram", "zram"];
+
+/// Cumulative counters at one point in time
+#[derive(Debug, Clone, Default)]
+pub struct ActivitySnapshot {
+    /// Milliseconds each block device has spent doing I/O
+    io_ticks: Vec<(String, u64)>,
+    /// Pages swapped in plus pages swapped out
+    swap_pages: Option<u64>,
+}
+
+impl ActivitySnapshot {
+    /// `root` is the filesystem root, so that `proc/...` resolves
+    pub fn read(root: &FsTree) -> Self {
+        let io_ticks = root
+            .read("proc/diskstats")
+            .map(|stats| parse_diskstats(&stats))
+            .unwrap_or_default();
+        let swap_pages = root.read("proc/vmstat").and_then(|vmstat| {
+            let counter = |name: &str| {
+                vmstat.lines().find_map(|line| {
+                    let (key, value) = line.split_once(' ')?;
+                    (key == name).then(|| value.trim().parse::<u64>().ok())?
+                })
+            };
+            Some(counter("pswpin")? + counter("pswpout")?)
+        });
+
+        Self { io_ticks, swap_pages }
+    }
+
+    /// Activity from `earlier` until this snapshot, `elapsed` apart
+    pub fn since(&self, earlier: &ActivitySnapshot, elapsed: Duration) -> BackgroundActivity {
+        let window_ms = elapsed.as_millis() as f64;
+        if window_ms <= 0.0 {
+            return BackgroundActivity::default();
+        }
+
+        let busiest = self
+            .io_ticks
+            .iter()
+            .filter_map(|(name, ticks)| {
+                let (_, before) = earlier.io_ticks.iter().find(|(n, _)| n == name)?;
+                Some((name, ticks.saturating_sub(*before)))
+            })
+            .max_by_key(|(_, busy_ms)| *busy_ms);
+
+        BackgroundActivity {
+            disk_busy_percent: busiest.map(|(_, busy_ms)| (busy_ms as f64 / window_ms * 100.0).min(100.0) as f32),
+            busiest_disk: busiest.map(|(name, _)| name.clone()),
+            swap_pages_per_sec: match (earlier.swap_pages, self.swap_pages) {
+                (Some(before), Some(after)) => Some(after.saturating_sub(before) as f64 / (window_ms / 1000.0)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Device name and I/O milliseconds of each `/proc/diskstats` line
+fn parse_diskstats(stats: &str) -> Vec<(String, u64)> {
+    stats
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let name = *fields.get(2)?;
+            if VIRTUAL_DEVICES.iter().any(|prefix| name.starts_with(prefix)) {
+                return None;
+            }
+            // Field 13 of the line (index 12) is time spent doing I/O
+            Some((name.to_string(), fields.get(12)?.parse().ok()?))
+        })
+        .collect()
+}
+
+/// The "some avg10" figure of each `/proc/pressure` file: the share of the last
+/// 10 seconds in which at least one task stalled on that resource
+pub fn read_pressure(root: &FsTree) -> Option<PressureStall> {
+    let some_avg10 = |resource: &str| {
+        let pressure = root.read(format!("proc/pressure/{}", resource))?;
+        let some = pressure.lines().find(|line| line.starts_with("some "))?;
+        some.split_whitespace()
+            .find_map(|field| field.strip_prefix("avg10="))?
+            .parse::<f32>()
+            .ok()
+    };
+
+    let stall = PressureStall {
+        cpu: some_avg10("cpu"),
+        io: some_avg10("io"),
+        memory: some_avg10("memory"),
+    };
+    // Kernels without PSI, or with it disabled, have no pressure files
+    (stall.cpu.is_some() || stall.io.is_some() || stall.memory.is_some()).then_some(stall)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    fn diskstats(nvme_io_ms: u64, sda_io_ms: u64) -> String {
+        format!(
+            "   7       0 loop0 48 0 2104 9 0 0 0 0 0 {} 9 0 0 0 0 0 0\n\
+             259       0 nvme0n1 183712 60244 14218010 32521 422386 281958 23093800 319771 0 {} 381232 0 0 0 0 0 0\n\
+             8       0 sda 5120 12 80344 2210 901 310 55120 4123 0 {} 6333 0 0 0 0 0 0\n",
+            nvme_io_ms * 10,
+            nvme_io_ms,
+            sda_io_ms
+        )
+    }
+
+    fn vmstat(pswpin: u64, pswpout: u64) -> String {
+        format!("nr_free_pages 812345\npswpin {}\npswpout {}\npgfault 99\n", pswpin, pswpout)
+    }
+
+    #[test]
+    fn test_activity_between_snapshots() {
+        let before = FakeFsTree::new(&[
+            ("proc/diskstats", &diskstats(10_000, 500)),
+            ("proc/vmstat", &vmstat(100, 200)),
+        ]);
+        let after = FakeFsTree::new(&[
+            ("proc/diskstats", &diskstats(11_500, 600)),
+            ("proc/vmstat", &vmstat(2100, 3200)),
+        ]);
+
+        let earlier = ActivitySnapshot::read(&before.tree());
+        let later = ActivitySnapshot::read(&after.tree());
+        let activity = later.since(&earlier, Duration::from_millis(2500));
+
+        // loop0 moved more but isn't a disk
+        assert_eq!(activity.busiest_disk.as_deref(), Some("nvme0n1"));
+        assert_eq!(activity.disk_busy_percent, Some(60.0));
+        assert_eq!(activity.swap_pages_per_sec, Some(2000.0));
+    }
+
+    #[test]
+    fn test_pressure() {
+        let fake = FakeFsTree::new(&[
+            ("proc/pressure/cpu", "some avg10=12.50 avg60=8.01 avg300=2.00 total=123456\nfull avg10=0.00 avg60=0.00 avg300=0.00 total=0\n"),
+            ("proc/pressure/io", "some avg10=0.31 avg60=0.10 avg300=0.02 total=4567\nfull avg10=0.20 avg60=0.05 avg300=0.01 total=3456\n"),
+        ]);
+
+        let stall = read_pressure(&fake.tree()).unwrap();
+        assert_eq!(stall.cpu, Some(12.5));
+        assert_eq!(stall.io, Some(0.31));
+        assert_eq!(stall.memory, None);
+
+        assert!(read_pressure(&FakeFsTree::new(&[]).tree()).is_none());
+    }
+}
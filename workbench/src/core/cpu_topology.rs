@@ -0,0 +1,173 @@
+//! Linux CPU frequencies, SMT state and hybrid core groups from sysfs
+
+use std::collections::HashSet;
+
+use super::fs_tree::FsTree;
+use crate::models::{CoreGroup, CoreKind};
+
+const CPU_DIR: &str = "devices/system/cpu";
+
+/// Intel hybrid CPUs register one PMU per core type, each listing its CPUs
+const HYBRID_PMUS: &[(CoreKind, &str)] = &[
+    (CoreKind::Performance, "devices/cpu_core/cpus"),
+    (CoreKind::Efficiency, "devices/cpu_atom/cpus"),
+];
+
+#[derive(Debug, Default)]
+pub struct CpuTopology {
+    pub base_frequency_mhz: Option<u32>,
+    pub max_frequency_mhz: Option<u32>,
+    pub smt_enabled: Option<bool>,
+    pub core_groups: Vec<CoreGroup>,
+}
+
+pub fn detect_topology(sysfs: &FsTree) -> CpuTopology {
+    let online = sysfs
+        .read(format!("{}/online", CPU_DIR))
+        .map(|list| parse_cpu_list(&list))
+        .unwrap_or_default();
+
+    CpuTopology {
+        // Only intel_pstate publishes the base frequency
+        base_frequency_mhz: online
+            .iter()
+            .find_map(|cpu| sysfs.read_u64(format!("{}/cpu{}/cpufreq/base_frequency", CPU_DIR, cpu)))
+            .map(khz_to_mhz),
+        max_frequency_mhz: max_frequency_mhz(sysfs, &online),
+        smt_enabled: sysfs.read_u64(format!("{}/smt/active", CPU_DIR)).map(|active| active == 1),
+        core_groups: core_groups(sysfs),
+    }
+}
+
+fn core_groups(sysfs: &FsTree) -> Vec<CoreGroup> {
+    let groups: Vec<CoreGroup> = HYBRID_PMUS
+        .iter()
+        .filter_map(|(kind, path)| {
+            let cpus = parse_cpu_list(&sysfs.read(path)?);
+            if cpus.is_empty() {
+                return None;
+            }
+
+            // SMT siblings share a core ID within their package
+            let cores: HashSet<(u64, u64)> = cpus
+                .iter()
+                .map(|cpu| {
+                    let topology = format!("{}/cpu{}/topology", CPU_DIR, cpu);
+                    (
+                        sysfs.read_u64(format!("{}/physical_package_id", topology)).unwrap_or(0),
+                        sysfs.read_u64(format!("{}/core_id", topology)).unwrap_or(*cpu as u64),
+                    )
+                })
+                .collect();
+
+            Some(CoreGroup {
+                kind: *kind,
+                cores: cores.len() as u32,
+                threads: cpus.len() as u32,
+                max_frequency_mhz: max_frequency_mhz(sysfs, &cpus),
+            })
+        })
+        .collect();
+
+    // A lone group is just a regular CPU
+    if groups.len() > 1 {
+        groups
+    } else {
+        Vec::new()
+    }
+}
+
+fn max_frequency_mhz(sysfs: &FsTree, cpus: &[u32]) -> Option<u32> {
+    cpus.iter()
+        .filter_map(|cpu| sysfs.read_u64(format!("{}/cpu{}/cpufreq/cpuinfo_max_freq", CPU_DIR, cpu)))
+        .max()
+        .map(khz_to_mhz)
+}
+
+fn khz_to_mhz(khz: u64) -> u32 {
+    (khz / 1000) as u32
+}
+
+/// Parse a kernel CPU list such as "0-3,8,10-11"
+fn parse_cpu_list(list: &str) -> Vec<u32> {
+    list.split(',')
+        .filter_map(|range| match range.trim().split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?..=end.parse().ok()?).collect()),
+            None => range.trim().parse().ok().map(|cpu| vec![cpu]),
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    fn cpu_files(cpu: u32, core_id: u32, max_khz: u32) -> Vec<(String, String)> {
+        let dir = format!("{}/cpu{}", CPU_DIR, cpu);
+        vec![
+            (format!("{}/topology/physical_package_id", dir), "0".to_string()),
+            (format!("{}/topology/core_id", dir), core_id.to_string()),
+            (format!("{}/cpufreq/cpuinfo_max_freq", dir), max_khz.to_string()),
+            (format!("{}/cpufreq/base_frequency", dir), "3000000".to_string()),
+        ]
+    }
+
+    fn fake(files: &[(String, String)]) -> FakeFsTree {
+        let files: Vec<(&str, &str)> = files.iter().map(|(p, c)| (p.as_str(), c.as_str())).collect();
+        FakeFsTree::new(&files)
+    }
+
+    #[test]
+    fn test_hybrid_topology() {
+        // 2 P-cores with SMT siblings, 4 E-cores without
+        let mut files = vec![
+            (format!("{}/online", CPU_DIR), "0-7".to_string()),
+            (format!("{}/smt/active", CPU_DIR), "1".to_string()),
+            ("devices/cpu_core/cpus".to_string(), "0-3".to_string()),
+            ("devices/cpu_atom/cpus".to_string(), "4-7\n".to_string()),
+        ];
+        for (cpu, core_id) in [(0, 0), (1, 0), (2, 4), (3, 4)] {
+            files.extend(cpu_files(cpu, core_id, 5400000));
+        }
+        for cpu in 4..8 {
+            files.extend(cpu_files(cpu, 8 + cpu, 4200000));
+        }
+        let fake = fake(&files);
+
+        let topology = detect_topology(&fake.tree());
+        assert_eq!(topology.base_frequency_mhz, Some(3000));
+        assert_eq!(topology.max_frequency_mhz, Some(5400));
+        assert_eq!(topology.smt_enabled, Some(true));
+        assert_eq!(
+            topology.core_groups,
+            [
+                CoreGroup { kind: CoreKind::Performance, cores: 2, threads: 4, max_frequency_mhz: Some(5400) },
+                CoreGroup { kind: CoreKind::Efficiency, cores: 4, threads: 4, max_frequency_mhz: Some(4200) },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_uniform_topology() {
+        let mut files = vec![
+            (format!("{}/online", CPU_DIR), "0-1".to_string()),
+            (format!("{}/smt/active", CPU_DIR), "0".to_string()),
+        ];
+        files.extend(cpu_files(0, 0, 4700000));
+        files.extend(cpu_files(1, 1, 4650000));
+        let fake = fake(&files);
+
+        let topology = detect_topology(&fake.tree());
+        assert_eq!(topology.max_frequency_mhz, Some(4700));
+        assert_eq!(topology.smt_enabled, Some(false));
+        assert!(topology.core_groups.is_empty());
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), [0, 1, 2, 3, 8, 10, 11]);
+        assert!(parse_cpu_list("").is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Remaining-time estimation for benchmark runs.
+//!
+//! Starts from each benchmark's `estimated_duration_secs` and corrects it with
+//! wall-clock durations recorded by previous runs on the same machine. Pauses
+//! between tests come from recorded cool-downs the same way.
+
+use std::collections::BTreeMap;
+
+use crate::models::BenchmarkRun;
+
+/// Minimum pause between consecutive tests; the runner waits longer while the
+/// CPU recovers from the previous test (see `quiet::MIN_COOL_DOWN`)
+pub const INTER_TEST_DELAY_SECS: u64 = 2;
+
+/// Number of recent runs used to estimate a test's duration
+const HISTORY_DEPTH: usize = 3;
+
+/// Estimates overall progress and time remaining for a queued set of benchmarks
+#[derive(Debug, Clone)]
+pub struct EtaEstimator {
+    /// Expected duration of each queued test, in run order
+    expected_secs: Vec<f64>,
+    /// Expected pause after each queued test; zero after the last
+    delay_secs: Vec<f64>,
+}
+
+impl EtaEstimator {
+    /// Build an estimator for `plan` as (test_id, estimated_duration_secs) in run order.
+    /// `history` is searched for runs recorded on `machine_name`.
+    pub fn new(plan: &[(String, u32)], history: &[BenchmarkRun], machine_name: &str) -> Self {
+        let machine_runs: Vec<&BenchmarkRun> = history
+            .iter()
+            .filter(|run| run.machine_name == machine_name && !run.test_durations.is_empty())
+            .collect();
+
+        let measured: Vec<Option<f64>> = plan
+            .iter()
+            .map(|(test_id, _)| Self::recorded(&machine_runs, test_id, |run| &run.test_durations))
+            .collect();
+
+        // How far off the built-in estimates are on this machine, from tests we have history for
+        let (measured_total, estimated_total) = plan
+            .iter()
+            .zip(&measured)
+            .filter_map(|((_, estimate), measured)| measured.map(|m| (m, *estimate as f64)))
+            .fold((0.0, 0.0), |(m, e), (measured, estimate)| (m + measured, e + estimate));
+        let correction = if measured_total > 0.0 && estimated_total > 0.0 {
+            measured_total / estimated_total
+        } else {
+            1.0
+        };
+
+        let expected_secs = plan
+            .iter()
+            .zip(measured)
+            .map(|((_, estimate), measured)| measured.unwrap_or(*estimate as f64 * correction))
+            .collect();
+
+        // Cool-downs adapt to how hot a test ran; tests without history get the
+        // machine's typical cool-down
+        let typical_cool_down = Self::median(
+            machine_runs
+                .iter()
+                .take(HISTORY_DEPTH)
+                .flat_map(|run| run.cool_down_durations.values().copied())
+                .collect(),
+        )
+        .unwrap_or(0.0);
+        let delay_secs = plan
+            .iter()
+            .enumerate()
+            .map(|(i, (test_id, _))| {
+                if i + 1 == plan.len() {
+                    return 0.0;
+                }
+                Self::recorded(&machine_runs, test_id, |run| &run.cool_down_durations)
+                    .unwrap_or(typical_cool_down)
+                    .max(INTER_TEST_DELAY_SECS as f64)
+            })
+            .collect();
+
+        Self { expected_secs, delay_secs }
+    }
+
+    /// Median of the most recent values `field` recorded for a test
+    fn recorded(
+        runs: &[&BenchmarkRun],
+        test_id: &str,
+        field: impl Fn(&BenchmarkRun) -> &BTreeMap<String, f64>,
+    ) -> Option<f64> {
+        Self::median(
+            runs.iter()
+                .filter_map(|run| field(run).get(test_id).copied())
+                .take(HISTORY_DEPTH)
+                .collect(),
+        )
+    }
+
+    fn median(mut values: Vec<f64>) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+
+        values.sort_by(|a, b| a.total_cmp(b));
+        Some(values[values.len() / 2])
+    }
+
+    /// Total expected duration of the run, including pauses between tests
+    pub fn total_secs(&self) -> f64 {
+        self.expected_secs.iter().sum::<f64>() + self.delays_after(0)
+    }
+
+    /// Time-weighted overall progress (0.0 - 1.0) while test `index` is at `test_progress`
+    pub fn overall_progress(&self, index: usize, test_progress: f32) -> f32 {
+        let total = self.total_secs();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        let remaining = self.remaining_secs(index, test_progress);
+        ((total - remaining) / total).clamp(0.0, 1.0) as f32
+    }
+
+    /// Seconds left while test `index` is at `test_progress`
+    pub fn remaining_secs(&self, index: usize, test_progress: f32) -> f64 {
+        let Some(current) = self.expected_secs.get(index) else {
+            return 0.0;
+        };
+
+        let current_remaining = current * (1.0 - test_progress.clamp(0.0, 1.0) as f64);
+        let later: f64 = self.expected_secs.iter().skip(index + 1).sum();
+
+        current_remaining + later + self.delays_after(index)
+    }
+
+    /// Pauses still to come once test `index` finishes
+    fn delays_after(&self, index: usize) -> f64 {
+        self.delay_secs.iter().skip(index).sum()
+    }
+
+    /// Format a duration as "1h 02m", "3m 05s" or "42s"
+    pub fn format_remaining(secs: f64) -> String {
+        let secs = secs.max(0.0).round() as u64;
+        if secs >= 3600 {
+            format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+        } else if secs >= 60 {
+            format!("{}m {:02}s", secs / 60, secs % 60)
+        } else {
+            format!("{}s", secs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SystemInfo;
+
+    fn create_run(machine: &str, durations: &[(&str, f64)]) -> BenchmarkRun {
+        let mut run = BenchmarkRun::new(
+            machine.to_string(),
+            SystemInfo::test_fixture(machine),
+        );
+        for (test_id, secs) in durations {
+            run.test_durations.insert(test_id.to_string(), *secs);
+        }
+        run
+    }
+
+    fn plan() -> Vec<(String, u32)> {
+        vec![("a".to_string(), 10), ("b".to_string(), 20)]
+    }
+
+    #[test]
+    fn test_uses_built_in_estimates_without_history() {
+        let eta = EtaEstimator::new(&plan(), &[], "PC-A");
+
+        assert_eq!(eta.total_secs(), 10.0 + 20.0 + INTER_TEST_DELAY_SECS as f64);
+        assert_eq!(eta.remaining_secs(0, 0.5), 5.0 + 20.0 + INTER_TEST_DELAY_SECS as f64);
+        assert_eq!(eta.remaining_secs(1, 1.0), 0.0);
+        assert_eq!(eta.overall_progress(1, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_corrects_estimates_from_history() {
+        // "a" took 4x its estimate on this machine; "b" has no history and gets the same factor
+        let history = vec![
+            create_run("PC-A", &[("a", 40.0)]),
+            create_run("PC-B", &[("a", 1.0), ("b", 1.0)]),
+        ];
+        let eta = EtaEstimator::new(&plan(), &history, "PC-A");
+
+        assert_eq!(eta.remaining_secs(1, 0.0), 80.0);
+        assert_eq!(eta.remaining_secs(0, 0.0), 40.0 + 80.0 + INTER_TEST_DELAY_SECS as f64);
+    }
+
+    #[test]
+    fn test_includes_recorded_cool_downs() {
+        let mut previous = create_run("PC-A", &[("a", 10.0), ("b", 20.0)]);
+        previous.cool_down_durations.insert("a".to_string(), 14.0);
+        let eta = EtaEstimator::new(&plan(), &[previous.clone()], "PC-A");
+        assert_eq!(eta.total_secs(), 10.0 + 14.0 + 20.0);
+
+        // A test without a recorded cool-down gets the machine's typical one
+        let three = vec![("c".to_string(), 5), ("a".to_string(), 10), ("b".to_string(), 20)];
+        let eta = EtaEstimator::new(&three, &[previous], "PC-A");
+        assert_eq!(eta.remaining_secs(0, 0.0), 5.0 + 14.0 + 10.0 + 14.0 + 20.0);
+    }
+
+    #[test]
+    fn test_format_remaining() {
+        assert_eq!(EtaEstimator::format_remaining(42.4), "42s");
+        assert_eq!(EtaEstimator::format_remaining(185.0), "3m 05s");
+        assert_eq!(EtaEstimator::format_remaining(3720.0), "1h 02m");
+    }
+}
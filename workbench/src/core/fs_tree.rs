@@ -0,0 +1,120 @@
+//! Read-only access to a directory tree such as sysfs or procfs
+//!
+//! Linux detection reads through an `FsTree` instead of `/sys` or `/proc`
+//! directly, so tests can point it at a fake tree. `FsTree::sysfs()` serves
+//! device attributes, `FsTree::host()` procfs, cgroupfs and other system files.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct FsTree {
+    root: PathBuf,
+}
+
+impl FsTree {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The real sysfs mount
+    pub fn sysfs() -> Self {
+        Self::new("/sys")
+    }
+
+    /// The whole filesystem, for paths outside `/sys`
+    pub fn host() -> Self {
+        Self::new("/")
+    }
+
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.root.join(path).exists()
+    }
+
+    /// Path with symlinks resolved, relative to the root
+    pub fn canonical(&self, path: impl AsRef<Path>) -> Option<PathBuf> {
+        let root = fs::canonicalize(&self.root).ok()?;
+        let resolved = fs::canonicalize(self.root.join(path)).ok()?;
+        resolved.strip_prefix(root).ok().map(Path::to_path_buf)
+    }
+
+    /// Trimmed contents of a file, or None if it is missing, unreadable or empty
+    pub fn read(&self, path: impl AsRef<Path>) -> Option<String> {
+        let contents = fs::read_to_string(self.root.join(path)).ok()?;
+        let trimmed = contents.trim();
+        (!trimmed.is_empty()).then(|| trimmed.to_string())
+    }
+
+    /// Raw contents of a binary file
+    pub fn read_bytes(&self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        fs::read(self.root.join(path)).ok()
+    }
+
+    /// A file holding one decimal or `0x`-prefixed hex number
+    pub fn read_u64(&self, path: impl AsRef<Path>) -> Option<u64> {
+        let value = self.read(path)?;
+        match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => value.parse().ok(),
+        }
+    }
+
+    /// Names of the entries of a directory, sorted; empty if it doesn't exist
+    pub fn list(&self, path: impl AsRef<Path>) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(self.root.join(path))
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
+    /// Value of `key` in a `KEY=value` file such as `uevent`
+    pub fn read_key(&self, path: impl AsRef<Path>, key: &str) -> Option<String> {
+        self.read(path)?.lines().find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            (k.trim() == key).then(|| v.trim().to_string())
+        })
+    }
+}
+
+/// Temporary directory tree for tests, removed when dropped
+#[cfg(test)]
+pub struct FakeFsTree {
+    root: PathBuf,
+}
+
+#[cfg(test)]
+impl FakeFsTree {
+    /// Create a tree holding `files`, given as (relative path, contents)
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        let root = std::env::temp_dir().join(format!("workbench_sysfs_{}", uuid::Uuid::new_v4()));
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        Self { root }
+    }
+
+    pub fn tree(&self) -> FsTree {
+        FsTree::new(&self.root)
+    }
+
+    /// Add a symlink at `path` pointing to `target`, both relative to the root
+    pub fn link(&self, path: &str, target: &str) {
+        let path = self.root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::os::unix::fs::symlink(self.root.join(target), path).unwrap();
+    }
+}
+
+#[cfg(test)]
+impl Drop for FakeFsTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
@@ -0,0 +1,131 @@
+//! Linux GPU detection from `/sys/class/drm`
+
+use super::pci_ids;
+use super::fs_tree::FsTree;
+use crate::models::GpuInfo;
+
+/// A DRM card with what's needed to pick the main GPU
+struct DrmCard {
+    info: GpuInfo,
+    discrete: bool,
+    boot_vga: bool,
+}
+
+/// The main GPU: a discrete one if present, then the one the firmware booted on
+pub fn detect_gpu(sysfs: &FsTree) -> Option<GpuInfo> {
+    sysfs
+        .list("class/drm")
+        .iter()
+        .filter(|name| is_card(name))
+        .filter_map(|card| read_card(sysfs, card))
+        .max_by_key(|card| (card.discrete, card.info.vram_bytes.is_some(), card.boot_vga))
+        .map(|card| card.info)
+}
+
+/// card0, card1, ... but not connectors such as card0-DP-1
+fn is_card(name: &str) -> bool {
+    name.strip_prefix("card")
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn read_card(sysfs: &FsTree, card: &str) -> Option<DrmCard> {
+    let device = format!("class/drm/{}/device", card);
+    let uevent = format!("{}/uevent", device);
+
+    // PCI_ID in uevent is "VVVV:DDDD"; the vendor and device files are "0xvvvv"
+    let pci_id = sysfs.read_key(&uevent, "PCI_ID").and_then(|id| {
+        let (vendor, device) = id.split_once(':')?;
+        Some((u16::from_str_radix(vendor, 16).ok()?, u16::from_str_radix(device, 16).ok()?))
+    });
+    let vendor_id = sysfs
+        .read_u64(format!("{}/vendor", device))
+        .map(|id| id as u16)
+        .or(pci_id.map(|(vendor, _)| vendor))?;
+    let device_id = sysfs
+        .read_u64(format!("{}/device", device))
+        .map(|id| id as u16)
+        .or(pci_id.map(|(_, device)| device))?;
+
+    let vendor = pci_ids::vendor_name(vendor_id)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{:04x}", vendor_id));
+    let name = match pci_ids::device_name(vendor_id, device_id) {
+        Some(name) => format!("{} {}", vendor, name),
+        None => format!("{} GPU [{:04x}:{:04x}]", vendor, vendor_id, device_id),
+    };
+
+    let driver = sysfs.read_key(&uevent, "DRIVER");
+    // Only some drivers (e.g. nvidia) publish a module version
+    let driver_version = driver
+        .as_ref()
+        .and_then(|driver| sysfs.read(format!("module/{}/version", driver)));
+
+    // amdgpu reports VRAM; other drivers don't expose it in sysfs
+    let vram_bytes = sysfs.read_u64(format!("{}/mem_info_vram_total", device)).filter(|&bytes| bytes > 0);
+
+    // Integrated AMD GPUs report a small VRAM carve-out, so judge by vendor and size
+    let discrete = match vendor_id {
+        0x10de => true,
+        0x1002 => vram_bytes.is_some_and(|bytes| bytes > 2 * 1024 * 1024 * 1024),
+        0x8086 => pci_ids::device_name(vendor_id, device_id).is_some_and(|name| name.starts_with("Arc")),
+        _ => false,
+    };
+
+    Some(DrmCard {
+        info: GpuInfo {
+            name,
+            vendor,
+            vram_bytes,
+            driver,
+            driver_version,
+        },
+        discrete,
+        boot_vga: sysfs.read(format!("{}/boot_vga", device)).as_deref() == Some("1"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    #[test]
+    fn test_detects_amd_gpu_with_vram() {
+        let fake = FakeFsTree::new(&[
+            ("class/drm/card0/device/vendor", "0x1002\n"),
+            ("class/drm/card0/device/device", "0x73bf\n"),
+            ("class/drm/card0/device/uevent", "DRIVER=amdgpu\nPCI_CLASS=30000\nPCI_ID=1002:73BF\n"),
+            ("class/drm/card0/device/mem_info_vram_total", "17163091968\n"),
+            ("class/drm/card0-DP-1/status", "connected\n"),
+        ]);
+
+        let gpu = detect_gpu(&fake.tree()).unwrap();
+        assert_eq!(gpu.name, "AMD Radeon RX 6800/6800 XT/6900 XT");
+        assert_eq!(gpu.vendor, "AMD");
+        assert_eq!(gpu.vram_bytes, Some(17163091968));
+        assert_eq!(gpu.driver.as_deref(), Some("amdgpu"));
+        assert_eq!(gpu.driver_version, None);
+    }
+
+    #[test]
+    fn test_prefers_discrete_gpu_and_falls_back_to_ids() {
+        let fake = FakeFsTree::new(&[
+            // Integrated GPU the firmware booted on
+            ("class/drm/card0/device/uevent", "DRIVER=i915\nPCI_ID=8086:A7A0\n"),
+            ("class/drm/card0/device/boot_vga", "1\n"),
+            // Discrete NVIDIA GPU, known only from uevent
+            ("class/drm/card1/device/uevent", "DRIVER=nvidia\nPCI_ID=10DE:2757\n"),
+            ("module/nvidia/version", "550.54.14\n"),
+        ]);
+
+        let gpu = detect_gpu(&fake.tree()).unwrap();
+        assert_eq!(gpu.name, "NVIDIA GPU [10de:2757]");
+        assert_eq!(gpu.driver_version.as_deref(), Some("550.54.14"));
+    }
+
+    #[test]
+    fn test_no_drm_cards() {
+        let fake = FakeFsTree::new(&[("class/drm/version", "drm 1.1.0\n")]);
+        assert!(detect_gpu(&fake.tree()).is_none());
+    }
+}
@@ -0,0 +1,58 @@
+//! Hypervisor identification from CPUID and SMBIOS, shared by the platform detectors
+
+/// SMBIOS system vendors and product names of virtual machines, matched as prefixes
+const SMBIOS_HYPERVISORS: &[(&str, &str)] = &[
+    ("QEMU", "KVM"),
+    ("VMware", "VMware"),
+    ("innotek GmbH", "VirtualBox"),
+    ("Xen", "Xen"),
+    ("Amazon EC2", "KVM"),
+    ("Google", "KVM"),
+    ("Parallels", "Parallels"),
+    ("Virtual Machine", "Hyper-V"),
+];
+
+/// Hypervisors whose CPUID leaf the host itself also sees: Hyper-V's root
+/// partition (any Windows with VBS or HVCI on) and Xen's dom0
+const HOST_VISIBLE_HYPERVISORS: &[&str] = &["Hyper-V", "Xen"];
+
+/// The hypervisor this machine runs under, if it is a guest. `cpuid` is the name
+/// from the CPUID hypervisor leaf; `vendor` and `product` are the SMBIOS system
+/// manufacturer and product name, empty where unknown.
+pub fn identify_hypervisor(cpuid: Option<String>, vendor: &str, product: &str) -> Option<String> {
+    let smbios = SMBIOS_HYPERVISORS
+        .iter()
+        .find(|(prefix, _)| vendor.starts_with(prefix) || product.starts_with(prefix))
+        .map(|(_, hypervisor)| hypervisor.to_string());
+
+    match cpuid {
+        // On bare metal these still show up in CPUID, so only firmware of a VM confirms a guest
+        Some(name) if HOST_VISIBLE_HYPERVISORS.contains(&name.as_str()) => smbios.map(|_| name),
+        Some(name) => Some(name),
+        None => smbios,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_partition_is_not_a_guest() {
+        // Windows with VBS on a Dell desktop, and on a Surface
+        assert_eq!(identify_hypervisor(Some("Hyper-V".to_string()), "Dell Inc.", "OptiPlex 7010"), None);
+        assert_eq!(identify_hypervisor(Some("Hyper-V".to_string()), "Microsoft Corporation", "Surface Pro 9"), None);
+        assert_eq!(identify_hypervisor(Some("Hyper-V".to_string()), "", ""), None);
+
+        let guest = identify_hypervisor(Some("Hyper-V".to_string()), "Microsoft Corporation", "Virtual Machine");
+        assert_eq!(guest.as_deref(), Some("Hyper-V"));
+    }
+
+    #[test]
+    fn test_other_evidence() {
+        assert_eq!(identify_hypervisor(Some("KVM".to_string()), "", "").as_deref(), Some("KVM"));
+        assert_eq!(identify_hypervisor(None, "QEMU", "Standard PC").as_deref(), Some("KVM"));
+        assert_eq!(identify_hypervisor(Some("Xen".to_string()), "Xen", "HVM domU").as_deref(), Some("Xen"));
+        assert_eq!(identify_hypervisor(None, "Dell Inc.", "OptiPlex 7010"), None);
+    }
+}
@@ -1,19 +1,46 @@
+#[cfg(target_os = "linux")]
+mod activity;
+#[cfg(target_os = "linux")]
+mod cpu_topology;
+pub mod eta;
+#[cfg(target_os = "linux")]
+mod fs_tree;
+#[cfg(target_os = "linux")]
+mod gpu;
+mod hypervisor;
+#[cfg(target_os = "linux")]
+mod pci_ids;
+#[cfg(target_os = "linux")]
+mod power;
 pub mod process;
+pub mod quiet;
 pub mod recommendations;
 pub mod runner;
+pub mod scoring;
+#[cfg(target_os = "linux")]
+mod smbios;
+#[cfg(target_os = "linux")]
+mod storage;
 pub mod system_check;
 pub mod system_info;
+mod thermal;
 pub mod timer;
+pub mod trends;
+#[cfg(target_os = "linux")]
+mod virtualization;
 
+pub use eta::EtaEstimator;
 pub use process::{hidden_command, system_command, system32_path, CommandExt};
 pub use recommendations::{
-    DeviceType, PercentileRank, Recommendation, RecommendationCategory, RecommendationEngine,
-    RecommendationPriority, RecommendationsReport,
+    PercentileRank, Recommendation, RecommendationCategory, RecommendationEngine, RecommendationPriority,
+    RecommendationsReport,
 };
 pub use runner::{BenchmarkMessage, BenchmarkRunner};
+pub use scoring::{RunScore, ScoreCache};
 pub use system_check::{
-    PowerPlan, PowerState, ProcessInfo, SystemCheckResult, SystemChecker, SystemWarning,
-    WarningSeverity,
+    PowerPlan, PowerState, ProcessInfo, ScratchRequirement, SystemCheckResult, SystemChecker,
+    SystemWarning, WarningSeverity,
 };
 pub use system_info::SystemInfoCollector;
 pub use timer::Timer;
+pub use trends::{TestTrend, TrendAnalyzer};
@@ -0,0 +1,75 @@
+//! Names for common PCI display devices
+//!
+//! A small subset of the pci.ids database: the GPU vendors and the desktop,
+//! laptop and virtual GPUs most likely to be benchmarked. Unknown devices fall
+//! back to the vendor name and raw IDs.
+
+const VENDORS: &[(u16, &str)] = &[
+    (0x1002, "AMD"),
+    (0x10de, "NVIDIA"),
+    (0x8086, "Intel"),
+    (0x1234, "QEMU"),
+    (0x1414, "Microsoft"),
+    (0x15ad, "VMware"),
+    (0x1af4, "Red Hat"),
+    (0x80ee, "VirtualBox"),
+];
+
+const DEVICES: &[(u16, u16, &str)] = &[
+    // NVIDIA
+    (0x10de, 0x2684, "GeForce RTX 4090"),
+    (0x10de, 0x2704, "GeForce RTX 4080"),
+    (0x10de, 0x2782, "GeForce RTX 4070 Ti"),
+    (0x10de, 0x2786, "GeForce RTX 4070"),
+    (0x10de, 0x2803, "GeForce RTX 4060 Ti"),
+    (0x10de, 0x2882, "GeForce RTX 4060"),
+    (0x10de, 0x2204, "GeForce RTX 3090"),
+    (0x10de, 0x2206, "GeForce RTX 3080"),
+    (0x10de, 0x2484, "GeForce RTX 3070"),
+    (0x10de, 0x2487, "GeForce RTX 3060"),
+    (0x10de, 0x2503, "GeForce RTX 3060"),
+    (0x10de, 0x1e84, "GeForce RTX 2070 SUPER"),
+    (0x10de, 0x1f08, "GeForce RTX 2060"),
+    (0x10de, 0x1b80, "GeForce GTX 1080"),
+    (0x10de, 0x1b81, "GeForce GTX 1070"),
+    (0x10de, 0x1c03, "GeForce GTX 1060 6GB"),
+    (0x10de, 0x1c82, "GeForce GTX 1050 Ti"),
+    (0x10de, 0x2230, "RTX A6000"),
+    // AMD
+    (0x1002, 0x744c, "Radeon RX 7900 XT/XTX"),
+    (0x1002, 0x73bf, "Radeon RX 6800/6800 XT/6900 XT"),
+    (0x1002, 0x73df, "Radeon RX 6700/6700 XT"),
+    (0x1002, 0x73ff, "Radeon RX 6600/6600 XT"),
+    (0x1002, 0x731f, "Radeon RX 5600/5700"),
+    (0x1002, 0x67df, "Radeon RX 470/480/570/580"),
+    (0x1002, 0x15bf, "Radeon 780M"),
+    (0x1002, 0x1681, "Radeon 680M"),
+    (0x1002, 0x164e, "Radeon Graphics (Ryzen 7000)"),
+    (0x1002, 0x1638, "Radeon Vega (Ryzen 5000)"),
+    // Intel
+    (0x8086, 0x56a0, "Arc A770"),
+    (0x8086, 0x56a1, "Arc A750"),
+    (0x8086, 0xa780, "UHD Graphics 770"),
+    (0x8086, 0x4680, "UHD Graphics 770"),
+    (0x8086, 0x46a6, "Iris Xe Graphics"),
+    (0x8086, 0x9a49, "Iris Xe Graphics"),
+    (0x8086, 0x9bc5, "UHD Graphics 630"),
+    (0x8086, 0x3e92, "UHD Graphics 630"),
+    // Virtual machines
+    (0x1234, 0x1111, "Standard VGA"),
+    (0x1414, 0x5353, "Hyper-V Video"),
+    (0x15ad, 0x0405, "SVGA II Adapter"),
+    (0x1af4, 0x1050, "Virtio GPU"),
+    (0x80ee, 0xbeef, "Graphics Adapter"),
+];
+
+pub fn vendor_name(vendor_id: u16) -> Option<&'static str> {
+    VENDORS.iter().find(|(id, _)| *id == vendor_id).map(|(_, name)| *name)
+}
+
+pub fn device_name(vendor_id: u16, device_id: u16) -> Option<&'static str> {
+    DEVICES
+        .iter()
+        .find(|(vendor, device, _)| *vendor == vendor_id && *device == device_id)
+        .map(|(_, _, name)| *name)
+}
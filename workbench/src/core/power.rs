@@ -0,0 +1,180 @@
+//! Linux power source and power profile detection from sysfs
+
+use super::fs_tree::FsTree;
+use super::system_check::{PowerPlan, PowerState};
+
+const POWER_SUPPLY_DIR: &str = "class/power_supply";
+const PLATFORM_PROFILE: &str = "firmware/acpi/platform_profile";
+const CPUFREQ_DIR: &str = "devices/system/cpu/cpu0/cpufreq";
+
+/// AC adapter and system batteries; peripheral batteries (mice, headsets) are ignored
+pub fn detect_power_state(sysfs: &FsTree) -> PowerState {
+    if !sysfs.exists(POWER_SUPPLY_DIR) {
+        return PowerState::Unknown;
+    }
+
+    let mut on_ac = false;
+    let mut discharging = false;
+    let mut capacities: Vec<u64> = Vec::new();
+
+    for supply in sysfs.list(POWER_SUPPLY_DIR) {
+        let dir = format!("{}/{}", POWER_SUPPLY_DIR, supply);
+        match sysfs.read(format!("{}/type", dir)).as_deref() {
+            Some("Mains") | Some("USB") => {
+                on_ac |= sysfs.read_u64(format!("{}/online", dir)) == Some(1);
+            }
+            Some("Battery") if sysfs.read(format!("{}/scope", dir)).as_deref() != Some("Device") => {
+                discharging |= sysfs.read(format!("{}/status", dir)).as_deref() == Some("Discharging");
+                capacities.extend(sysfs.read_u64(format!("{}/capacity", dir)));
+            }
+            _ => {}
+        }
+    }
+
+    // Desktops have no battery; a battery that isn't discharging is on external power
+    if on_ac || !discharging {
+        return PowerState::PluggedIn;
+    }
+    let percent = match capacities.len() {
+        0 => 50, // Same default as an unknown Windows battery level
+        n => (capacities.iter().sum::<u64>() / n as u64).min(100),
+    };
+    PowerState::OnBattery(percent as u8)
+}
+
+/// The most restrictive of the firmware platform profile and the CPU frequency policy
+pub fn detect_power_plan(sysfs: &FsTree) -> PowerPlan {
+    let profile = sysfs.read(PLATFORM_PROFILE).and_then(|profile| {
+        Some(match profile.as_str() {
+            "low-power" | "quiet" | "cool" => PowerPlan::PowerSaver,
+            "balanced" | "balanced-performance" => PowerPlan::Balanced,
+            "performance" => PowerPlan::HighPerformance,
+            _ => return None,
+        })
+    });
+
+    // With intel_pstate or amd-pstate active, the "powersave" governor still scales
+    // dynamically and the energy/performance preference decides how eagerly
+    let cpu = match sysfs.read(format!("{}/energy_performance_preference", CPUFREQ_DIR)) {
+        Some(preference) => match preference.as_str() {
+            "performance" => Some(PowerPlan::HighPerformance),
+            "balance_performance" | "balance_power" | "default" => Some(PowerPlan::Balanced),
+            "power" => Some(PowerPlan::PowerSaver),
+            _ => None,
+        },
+        None => match sysfs.read(format!("{}/scaling_governor", CPUFREQ_DIR)).as_deref() {
+            Some("performance") => Some(PowerPlan::HighPerformance),
+            Some("schedutil") | Some("ondemand") | Some("conservative") => Some(PowerPlan::Balanced),
+            // Older kernels run the pstate drivers in active mode without exposing EPP
+            Some("powersave") if has_active_pstate(sysfs) => Some(PowerPlan::Balanced),
+            Some("powersave") => Some(PowerPlan::PowerSaver),
+            Some(other) => Some(PowerPlan::Unknown(other.to_string())),
+            None => None,
+        },
+    };
+
+    match (profile, cpu) {
+        (Some(profile), Some(cpu)) => most_restrictive(profile, cpu),
+        (Some(plan), None) | (None, Some(plan)) => plan,
+        (None, None) => PowerPlan::Unknown("N/A".to_string()),
+    }
+}
+
+/// Whether a pstate driver in active mode owns frequency scaling, where "powersave"
+/// is the dynamic policy rather than the generic governor pinned to the lowest clock
+fn has_active_pstate(sysfs: &FsTree) -> bool {
+    matches!(
+        sysfs.read(format!("{}/scaling_driver", CPUFREQ_DIR)).as_deref(),
+        Some("intel_pstate") | Some("amd-pstate-epp")
+    )
+}
+
+fn most_restrictive(a: PowerPlan, b: PowerPlan) -> PowerPlan {
+    let rank = |plan: &PowerPlan| match plan {
+        PowerPlan::PowerSaver => 0,
+        PowerPlan::Balanced => 1,
+        PowerPlan::HighPerformance => 2,
+        PowerPlan::Unknown(_) => 3,
+    };
+    if rank(&b) < rank(&a) {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    #[test]
+    fn test_laptop_on_battery() {
+        let fake = FakeFsTree::new(&[
+            ("class/power_supply/AC/type", "Mains\n"),
+            ("class/power_supply/AC/online", "0\n"),
+            ("class/power_supply/BAT0/type", "Battery\n"),
+            ("class/power_supply/BAT0/status", "Discharging\n"),
+            ("class/power_supply/BAT0/capacity", "64\n"),
+            // A wireless mouse battery doesn't count
+            ("class/power_supply/hidpp_battery_0/type", "Battery\n"),
+            ("class/power_supply/hidpp_battery_0/scope", "Device\n"),
+            ("class/power_supply/hidpp_battery_0/capacity", "5\n"),
+        ]);
+        assert_eq!(detect_power_state(&fake.tree()), PowerState::OnBattery(64));
+    }
+
+    #[test]
+    fn test_plugged_in_and_desktop() {
+        let laptop = FakeFsTree::new(&[
+            ("class/power_supply/ACAD/type", "Mains\n"),
+            ("class/power_supply/ACAD/online", "1\n"),
+            ("class/power_supply/BAT1/type", "Battery\n"),
+            ("class/power_supply/BAT1/status", "Charging\n"),
+            ("class/power_supply/BAT1/capacity", "20\n"),
+        ]);
+        assert_eq!(detect_power_state(&laptop.tree()), PowerState::PluggedIn);
+
+        let desktop = FakeFsTree::new(&[("class/power_supply/.keep", "")]);
+        assert_eq!(detect_power_state(&desktop.tree()), PowerState::PluggedIn);
+
+        let no_sysfs = FakeFsTree::new(&[]);
+        assert_eq!(detect_power_state(&no_sysfs.tree()), PowerState::Unknown);
+    }
+
+    #[test]
+    fn test_power_plan() {
+        let performance = FakeFsTree::new(&[
+            ("firmware/acpi/platform_profile", "performance\n"),
+            ("devices/system/cpu/cpu0/cpufreq/scaling_governor", "powersave\n"),
+            ("devices/system/cpu/cpu0/cpufreq/energy_performance_preference", "performance\n"),
+        ]);
+        assert_eq!(detect_power_plan(&performance.tree()), PowerPlan::HighPerformance);
+
+        // A quiet firmware profile limits the CPU whatever its own policy
+        let quiet = FakeFsTree::new(&[
+            ("firmware/acpi/platform_profile", "quiet\n"),
+            ("devices/system/cpu/cpu0/cpufreq/energy_performance_preference", "balance_performance\n"),
+        ]);
+        assert_eq!(detect_power_plan(&quiet.tree()), PowerPlan::PowerSaver);
+
+        let acpi_cpufreq = FakeFsTree::new(&[("devices/system/cpu/cpu0/cpufreq/scaling_governor", "schedutil\n")]);
+        assert_eq!(detect_power_plan(&acpi_cpufreq.tree()), PowerPlan::Balanced);
+
+        // Without EPP, powersave under an active pstate driver still scales up
+        let pstate = FakeFsTree::new(&[
+            ("devices/system/cpu/cpu0/cpufreq/scaling_driver", "intel_pstate\n"),
+            ("devices/system/cpu/cpu0/cpufreq/scaling_governor", "powersave\n"),
+        ]);
+        assert_eq!(detect_power_plan(&pstate.tree()), PowerPlan::Balanced);
+
+        let generic = FakeFsTree::new(&[
+            ("devices/system/cpu/cpu0/cpufreq/scaling_driver", "acpi-cpufreq\n"),
+            ("devices/system/cpu/cpu0/cpufreq/scaling_governor", "powersave\n"),
+        ]);
+        assert_eq!(detect_power_plan(&generic.tree()), PowerPlan::PowerSaver);
+
+        let vm = FakeFsTree::new(&[]);
+        assert_eq!(detect_power_plan(&vm.tree()), PowerPlan::Unknown("N/A".to_string()));
+    }
+}
@@ -0,0 +1,204 @@
+//! Waiting for a quiet system before and between tests
+//!
+//! Before the run, the runner can hold off until CPU, disk and pressure readings
+//! have stayed below `QuietThresholds` for a whole quiet period. Between tests it
+//! waits for the CPU's temperature and clocks to recover from the previous test
+//! instead of pausing for a fixed time.
+
+use std::time::{Duration, Instant};
+
+use super::eta::INTER_TEST_DELAY_SECS;
+use super::system_check::LoadSample;
+use super::thermal::ThermalReading;
+
+/// Quiet period offered in the pre-check
+pub const DEFAULT_QUIET_PERIOD_SECS: u32 = 30;
+/// How much longer than the quiet period to wait before starting anyway, so a
+/// system that never settles doesn't hold the run forever
+pub const MAX_QUIET_OVERRUN: Duration = Duration::from_secs(300);
+
+/// Cool-down between tests, at least long enough to avoid tripping AV
+/// behavioural heuristics with back-to-back activity
+pub const MIN_COOL_DOWN: Duration = Duration::from_secs(INTER_TEST_DELAY_SECS);
+/// Cool-down after which the next test starts whether or not the CPU recovered
+pub const MAX_COOL_DOWN: Duration = Duration::from_secs(20);
+
+/// Idle readings the pre-run baseline is settled from, and the time between them
+pub const BASELINE_SAMPLES: usize = 5;
+pub const BASELINE_SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+/// Cool-down readings checked together; the fastest core dips between boosts
+/// at idle, so clocks count as recovered if any of them is back up
+pub const RECOVERY_WINDOW: usize = 4;
+
+/// Share of the pre-run clock the fastest core must be back to
+const CLOCK_RECOVERY_FRACTION: f64 = 0.9;
+/// Degrees above the pre-run temperature that still count as recovered
+const TEMP_RECOVERY_MARGIN_C: f64 = 5.0;
+/// Temperature that counts as cool whatever it was before the run
+const COOL_TEMP_C: f64 = 60.0;
+
+/// Load below which the system counts as quiet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuietThresholds {
+    pub cpu_percent: f32,
+    pub disk_busy_percent: f32,
+    /// Highest pressure stall of any resource
+    pub pressure_percent: f32,
+}
+
+impl Default for QuietThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_percent: 10.0,
+            disk_busy_percent: 10.0,
+            pressure_percent: 5.0,
+        }
+    }
+}
+
+impl QuietThresholds {
+    /// Whether `sample` is below every threshold; readings a platform lacks pass
+    pub fn allows(&self, sample: &LoadSample) -> bool {
+        let pressure = sample
+            .pressure
+            .map(|p| [p.cpu, p.io, p.memory].into_iter().flatten().fold(0.0, f32::max))
+            .unwrap_or(0.0);
+
+        sample.cpu_percent < self.cpu_percent
+            && sample.disk_busy_percent.unwrap_or(0.0) < self.disk_busy_percent
+            && pressure < self.pressure_percent
+    }
+}
+
+/// How long readings have stayed quiet without interruption
+#[derive(Debug, Clone, Default)]
+pub struct QuietTracker {
+    quiet_since: Option<Instant>,
+}
+
+impl QuietTracker {
+    /// Record whether the latest sample was quiet and return the current quiet streak
+    pub fn observe(&mut self, quiet: bool, now: Instant) -> Duration {
+        if !quiet {
+            self.quiet_since = None;
+            return Duration::ZERO;
+        }
+        now.duration_since(*self.quiet_since.get_or_insert(now))
+    }
+}
+
+/// Pre-run state to recover to, from several idle readings: the median temperature
+/// and clock, so one boost spike or sensor blip doesn't set the bar
+pub fn settled_baseline(readings: &[ThermalReading]) -> ThermalReading {
+    fn median<T: Copy + PartialOrd>(mut values: Vec<T>) -> Option<T> {
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        values.get(values.len() / 2).copied()
+    }
+
+    ThermalReading {
+        temp_c: median(readings.iter().filter_map(|r| r.temp_c).collect()),
+        max_core_mhz: median(readings.iter().filter_map(|r| r.max_core_mhz).collect()),
+        throttle_count: readings.iter().rev().find_map(|r| r.throttle_count),
+    }
+}
+
+/// Whether temperature and clocks are back near their pre-run state and throttling
+/// has stopped. `recent` holds the latest readings, oldest first, at most
+/// `RECOVERY_WINDOW` of them.
+pub fn thermal_recovered(baseline: &ThermalReading, recent: &[ThermalReading]) -> bool {
+    let Some(current) = recent.last() else {
+        return true;
+    };
+
+    let temp_recovered = current.temp_c.is_none_or(|temp| {
+        temp < COOL_TEMP_C || baseline.temp_c.is_some_and(|base| temp <= base + TEMP_RECOVERY_MARGIN_C)
+    });
+    let fastest_mhz = recent.iter().filter_map(|r| r.max_core_mhz).max();
+    let clocks_recovered = match (baseline.max_core_mhz, fastest_mhz) {
+        (Some(base), Some(mhz)) => f64::from(mhz) >= f64::from(base) * CLOCK_RECOVERY_FRACTION,
+        _ => true,
+    };
+    // Throttle counters still climbing mean the CPU is still being held back
+    let first_count = recent.iter().find_map(|r| r.throttle_count);
+    let throttling_stopped = match (first_count, current.throttle_count) {
+        (Some(before), Some(after)) => after <= before,
+        _ => true,
+    };
+
+    temp_recovered && clocks_recovered && throttling_stopped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::system_check::PressureStall;
+
+    #[test]
+    fn test_thresholds() {
+        let thresholds = QuietThresholds::default();
+        let idle = LoadSample {
+            cpu_percent: 3.0,
+            disk_busy_percent: Some(1.0),
+            pressure: Some(PressureStall { cpu: Some(0.5), io: Some(0.0), memory: None }),
+        };
+        assert!(thresholds.allows(&idle));
+
+        let indexing = LoadSample { disk_busy_percent: Some(35.0), ..idle.clone() };
+        assert!(!thresholds.allows(&indexing));
+
+        let io_stalls = LoadSample { pressure: Some(PressureStall { io: Some(8.0), ..Default::default() }), ..idle };
+        assert!(!thresholds.allows(&io_stalls));
+
+        // Platforms without disk or pressure readings go by CPU alone
+        assert!(thresholds.allows(&LoadSample { cpu_percent: 2.0, ..Default::default() }));
+    }
+
+    #[test]
+    fn test_quiet_streak_restarts_on_noise() {
+        let start = Instant::now();
+        let mut tracker = QuietTracker::default();
+
+        assert_eq!(tracker.observe(true, start), Duration::ZERO);
+        assert_eq!(tracker.observe(true, start + Duration::from_secs(5)), Duration::from_secs(5));
+        assert_eq!(tracker.observe(false, start + Duration::from_secs(6)), Duration::ZERO);
+        assert_eq!(tracker.observe(true, start + Duration::from_secs(7)), Duration::ZERO);
+        assert_eq!(tracker.observe(true, start + Duration::from_secs(10)), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_settled_baseline() {
+        let reading = ThermalReading::test_fixture;
+        // One reading caught the fastest core in a boost spike
+        let idle = [
+            reading(47.0, 2100, 10),
+            reading(48.0, 4700, 10),
+            reading(49.0, 2000, 10),
+            reading(48.0, 2200, 10),
+            reading(48.0, 2100, 10),
+        ];
+        assert_eq!(settled_baseline(&idle), reading(48.0, 2100, 10));
+
+        assert_eq!(settled_baseline(&[]), ThermalReading::default());
+    }
+
+    #[test]
+    fn test_thermal_recovery() {
+        let reading = ThermalReading::test_fixture;
+        let baseline = reading(48.0, 2200, 10);
+
+        assert!(thermal_recovered(&baseline, &[reading(58.0, 1800, 12), reading(52.0, 2100, 12)]));
+        // Still hot from the last test
+        assert!(!thermal_recovered(&baseline, &[reading(84.0, 2200, 12), reading(78.0, 2200, 12)]));
+        // Clocks still held down after the temperature dropped
+        assert!(!thermal_recovered(&baseline, &[reading(55.0, 1200, 12), reading(55.0, 1400, 12)]));
+        // An idle dip counts as recovered once any recent reading is back up
+        assert!(thermal_recovered(&baseline, &[reading(55.0, 2300, 12), reading(55.0, 800, 12)]));
+        // Throttle events still being counted
+        assert!(!thermal_recovered(&baseline, &[reading(55.0, 2200, 12), reading(55.0, 2200, 15)]));
+
+        // Nothing readable: nothing to wait for
+        let unknown = ThermalReading::default();
+        assert!(thermal_recovered(&unknown, &[unknown]));
+        assert!(thermal_recovered(&baseline, &[]));
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Analyzes benchmark results and generates optimization recommendations.
 
-use crate::models::{BenchmarkRun, StorageType};
+use crate::models::{BenchmarkRun, StorageType, TestResult};
+
+/// Hypervisors that virtual desktops run on
+const VDI_HYPERVISORS: &[&str] = &["Hyper-V", "VMware", "KVM", "QEMU", "Xen", "VirtualBox", "Parallels"];
 
 /// Category of recommendation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -166,6 +169,13 @@ impl RecommendationEngine {
     fn detect_device_type(run: &BenchmarkRun) -> DeviceType {
         let hostname = run.machine_name.to_lowercase();
 
+        // A VM of a desktop hypervisor is, for a workstation, a virtual desktop. WSL2
+        // and unidentified hypervisors run on a physical machine as far as we know.
+        let hypervisor = run.system_info.virtualization.hypervisor.as_deref();
+        if hypervisor.is_some_and(|h| VDI_HYPERVISORS.contains(&h)) {
+            return DeviceType::VDI;
+        }
+
         // Check for VDI indicators
         if hostname.contains("vdi")
             || hostname.contains("virtual")
@@ -360,31 +370,63 @@ impl RecommendationEngine {
             });
         }
 
-        // Check for thermal throttling indicators
-        if let (Some(single), Some(multi)) = (single_thread, multi_thread) {
-            let expected_multi = single.value * run.system_info.cpu.threads as f64 * 0.7; // 70% scaling expected
-            if multi.value < expected_multi * 0.5 {
-                recommendations.push(Recommendation {
-                    id: "check_thermal".to_string(),
-                    title: "Check CPU Cooling".to_string(),
-                    description: "Multi-threaded performance is significantly lower than expected \
-                                 based on single-threaded results. This may indicate thermal throttling."
-                        .to_string(),
-                    category: RecommendationCategory::Hardware,
-                    priority: RecommendationPriority::High,
-                    expected_improvement: "20-50% faster multi-threaded performance".to_string(),
-                    how_to_apply: vec![
-                        "Check CPU temperatures under load (use HWiNFO or similar)".to_string(),
-                        "Clean dust from CPU cooler and case fans".to_string(),
-                        "Reapply thermal paste if temperatures exceed 90°C under load".to_string(),
-                        "Consider upgrading CPU cooler if using stock cooler".to_string(),
-                    ],
-                    affected_tests: vec![
-                        "Multi-Thread CPU".to_string(),
-                        "Mixed Workload".to_string(),
-                    ],
-                });
+        // Check for thermal throttling: measured during the run where possible,
+        // otherwise inferred from poor multi-thread scaling
+        let measured: Vec<&TestResult> = run.results.iter()
+            .filter(|r| r.details.thermal.is_some())
+            .collect();
+        let throttled: Vec<&TestResult> = measured.iter()
+            .copied()
+            .filter(|r| r.details.thermal.as_ref().is_some_and(|t| t.is_throttled()))
+            .collect();
+
+        let finding = if !measured.is_empty() {
+            (!throttled.is_empty()).then(|| {
+                let peak = throttled.iter()
+                    .filter_map(|r| r.details.thermal.as_ref()?.max_temp_c)
+                    .reduce(f64::max)
+                    .map(|temp| format!(", peaking at {:.0}°C", temp))
+                    .unwrap_or_default();
+                let description = format!(
+                    "The CPU dropped below its base clock while hot in {} of {} tests{}. \
+                     Those results understate what the hardware can do.",
+                    throttled.len(),
+                    measured.len(),
+                    peak
+                );
+                (description, throttled.iter().map(|r| r.name.clone()).collect())
+            })
+        } else {
+            match (single_thread, multi_thread) {
+                (Some(single), Some(multi)) => {
+                    let expected_multi = single.value * run.system_info.cpu.threads as f64 * 0.7; // 70% scaling expected
+                    (multi.value < expected_multi * 0.5).then(|| {
+                        let description = "Multi-threaded performance is significantly lower than expected \
+                                           based on single-threaded results. This may indicate thermal throttling."
+                            .to_string();
+                        (description, vec!["Multi-Thread CPU".to_string(), "Mixed Workload".to_string()])
+                    })
+                }
+                _ => None,
             }
+        };
+
+        if let Some((description, affected_tests)) = finding {
+            recommendations.push(Recommendation {
+                id: "check_thermal".to_string(),
+                title: "Check CPU Cooling".to_string(),
+                description,
+                category: RecommendationCategory::Hardware,
+                priority: RecommendationPriority::High,
+                expected_improvement: "20-50% faster multi-threaded performance".to_string(),
+                how_to_apply: vec![
+                    "Check CPU temperatures under load (use HWiNFO or similar)".to_string(),
+                    "Clean dust from CPU cooler and case fans".to_string(),
+                    "Reapply thermal paste if temperatures exceed 90°C under load".to_string(),
+                    "Consider upgrading CPU cooler if using stock cooler".to_string(),
+                ],
+                affected_tests,
+            });
         }
     }
 
@@ -430,6 +472,33 @@ impl RecommendationEngine {
             });
         }
 
+        // Check for a single populated channel, which halves bandwidth
+        let memory = &run.system_info.memory;
+        if memory.channels == Some(1) && !memory.modules.is_empty() {
+            let slots: Vec<&str> = memory.modules.iter().map(|m| m.slot.as_str()).collect();
+            recommendations.push(Recommendation {
+                id: "single_channel_ram".to_string(),
+                title: "Run RAM in Dual-Channel Mode".to_string(),
+                description: format!(
+                    "All installed memory ({}) sits on a single channel, so the CPU can only use \
+                     half of the memory bandwidth the platform supports.",
+                    slots.join(", ")
+                ),
+                category: RecommendationCategory::Hardware,
+                priority: RecommendationPriority::Medium,
+                expected_improvement: "Up to 2x memory bandwidth".to_string(),
+                how_to_apply: vec![
+                    "Install modules in pairs of matching size and speed".to_string(),
+                    "Use the slots the motherboard manual marks for dual-channel (often A2 and B2)".to_string(),
+                    "Laptops with soldered single-channel memory can't be changed".to_string(),
+                ],
+                affected_tests: vec![
+                    "Memory Bandwidth".to_string(),
+                    "Multi-Thread CPU".to_string(),
+                ],
+            });
+        }
+
         // Check memory bandwidth against percentile
         if let Some(bw_result) = memory_bandwidth {
             let is_slow = percentile_ranks
@@ -598,35 +667,12 @@ impl RecommendationEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{BenchmarkRun, CategoryResults, SystemInfo, CpuInfo, MemoryInfo, OsInfo};
+    use crate::models::{BenchmarkRun, SystemInfo, MemoryModule, TestDetails, ThermalSummary};
 
     fn create_test_run() -> BenchmarkRun {
         BenchmarkRun::new(
             "TEST-PC".to_string(),
-            SystemInfo {
-                hostname: "TEST-PC".to_string(),
-                cpu: CpuInfo {
-                    name: "Test CPU".to_string(),
-                    vendor: "Test".to_string(),
-                    cores: 8,
-                    threads: 16,
-                    base_frequency_mhz: 3000,
-                    max_frequency_mhz: None,
-                    cache_l3_kb: None,
-                },
-                memory: MemoryInfo {
-                    total_bytes: 16 * 1024 * 1024 * 1024,
-                    speed_mhz: None,
-                    memory_type: None,
-                },
-                storage: vec![],
-                gpu: None,
-                os: OsInfo {
-                    name: "Windows".to_string(),
-                    version: "11".to_string(),
-                    build: None,
-                },
-            },
+            SystemInfo::test_fixture("TEST-PC"),
         )
     }
 
@@ -652,6 +698,67 @@ mod tests {
         run.machine_name = "VDI-DESKTOP-001".to_string();
         let report = RecommendationEngine::analyze(&run, None);
         assert_eq!(report.device_type, DeviceType::VDI);
+
+        // A detected hypervisor wins over CPU naming
+        run.machine_name = "DEV-01".to_string();
+        run.system_info.virtualization.hypervisor = Some("KVM".to_string());
+        let report = RecommendationEngine::analyze(&run, None);
+        assert_eq!(report.device_type, DeviceType::VDI);
+
+        // WSL2 and unidentified hypervisors don't make a VDI
+        for hypervisor in ["WSL2", "Unknown"] {
+            run.system_info.virtualization.hypervisor = Some(hypervisor.to_string());
+            let report = RecommendationEngine::analyze(&run, None);
+            assert_eq!(report.device_type, DeviceType::Laptop);
+        }
+    }
+
+    #[test]
+    fn test_single_channel_memory() {
+        let mut run = create_test_run();
+        let report = RecommendationEngine::analyze(&run, None);
+        assert!(!report.recommendations.iter().any(|r| r.id == "single_channel_ram"));
+
+        run.system_info.memory.modules = vec![MemoryModule {
+            slot: "DIMM_A1".to_string(),
+            size_bytes: 16 * 1024 * 1024 * 1024,
+            ..Default::default()
+        }];
+        run.system_info.memory.channels = Some(1);
+        let report = RecommendationEngine::analyze(&run, None);
+        assert!(report.recommendations.iter().any(|r| r.id == "single_channel_ram"));
+    }
+
+    #[test]
+    fn test_thermal_recommendation_uses_measurements() {
+        let mut run = create_test_run();
+        let result = |test_id: &str, value: f64, throttled_fraction: f64| TestResult {
+            test_id: test_id.to_string(),
+            name: test_id.to_string(),
+            description: String::new(),
+            value,
+            unit: "MB/s".to_string(),
+            details: TestDetails {
+                thermal: Some(ThermalSummary {
+                    samples: 10,
+                    max_temp_c: Some(97.0),
+                    throttled_fraction,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        };
+
+        // Poor scaling alone doesn't count once measurements say the CPU kept its clocks
+        run.results.build_performance = vec![result("single_thread", 500.0, 0.0), result("multi_thread", 600.0, 0.0)];
+        let report = RecommendationEngine::analyze(&run, None);
+        assert!(!report.recommendations.iter().any(|r| r.id == "check_thermal"));
+
+        run.results.build_performance[1] = result("multi_thread", 600.0, 0.6);
+        let report = RecommendationEngine::analyze(&run, None);
+        let thermal = report.recommendations.iter().find(|r| r.id == "check_thermal").unwrap();
+        assert_eq!(thermal.affected_tests, ["multi_thread"]);
+        assert!(thermal.description.contains("97°C"));
     }
 
     #[test]
@@ -1,19 +1,29 @@
+use std::collections::VecDeque;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-#[cfg(feature = "debug-logging")]
-use std::time::Instant;
-
-use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, ProgressCallback};
+use crate::benchmarks::{Benchmark, BenchmarkConfig, Category, LiveSample, ProgressCallback};
 use crate::models::{BenchmarkRun, CategoryResults, TestResult};
 
+use super::eta::EtaEstimator;
+use super::quiet::{
+    settled_baseline, thermal_recovered, QuietThresholds, QuietTracker, BASELINE_SAMPLES,
+    BASELINE_SAMPLE_INTERVAL, MAX_COOL_DOWN, MAX_QUIET_OVERRUN, MIN_COOL_DOWN, RECOVERY_WINDOW,
+};
+use super::system_check::LoadSampler;
+use super::thermal::{read_thermal, ThermalMonitor, ThermalReading};
 use super::SystemInfoCollector;
 
 #[cfg(feature = "debug-logging")]
 use tracing::{debug, info, warn, error};
 
+/// How often load is sampled while waiting for a quiet system
+const QUIET_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How often temperature, clocks and throttling are checked during a cool-down
+const COOL_DOWN_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Messages sent from benchmark thread to UI
 #[derive(Debug, Clone)]
 pub enum BenchmarkMessage {
@@ -21,10 +31,16 @@ pub enum BenchmarkMessage {
         benchmark_id: String,
         overall_progress: f32,
         test_progress: f32,
+        /// Estimated seconds until the whole run completes
+        eta_secs: f64,
         message: String,
     },
+    Sample {
+        benchmark_id: String,
+        sample: LiveSample,
+    },
     TestComplete {
-        result: TestResult,
+        result: Box<TestResult>,
     },
     AllComplete {
         run: Box<BenchmarkRun>,
@@ -51,10 +67,13 @@ impl BenchmarkRunner {
         }
     }
 
-    /// Start running benchmarks
+    /// Start running benchmarks, reporting progress and time remaining from `eta`.
+    /// With a `quiet_period`, the first test waits until the system has been idle that long.
     pub fn start(
         &mut self,
         benchmarks: Vec<Box<dyn Benchmark>>,
+        eta: EtaEstimator,
+        quiet_period: Option<Duration>,
     ) -> Receiver<BenchmarkMessage> {
         #[cfg(feature = "debug-logging")]
         {
@@ -72,7 +91,7 @@ impl BenchmarkRunner {
         let cancel_flag = Arc::clone(&self.cancel_flag);
 
         let handle = thread::spawn(move || {
-            Self::run_benchmarks(tx, benchmarks, cancel_flag);
+            Self::run_benchmarks(tx, benchmarks, cancel_flag, Arc::new(eta), quiet_period);
         });
 
         self.handle = Some(handle);
@@ -101,6 +120,8 @@ impl BenchmarkRunner {
         tx: Sender<BenchmarkMessage>,
         benchmarks: Vec<Box<dyn Benchmark>>,
         cancel_flag: Arc<Mutex<bool>>,
+        eta: Arc<EtaEstimator>,
+        quiet_period: Option<Duration>,
     ) {
         #[cfg(feature = "debug-logging")]
         let run_start = Instant::now();
@@ -112,8 +133,21 @@ impl BenchmarkRunner {
         #[cfg(feature = "debug-logging")]
         info!("Total benchmarks to run: {}", benchmarks.len());
         #[cfg(feature = "debug-logging")]
+        info!("Estimated duration: {}", EtaEstimator::format_remaining(eta.total_secs()));
+        #[cfg(feature = "debug-logging")]
         info!("========================================");
 
+        if let Some(period) = quiet_period {
+            #[cfg(feature = "debug-logging")]
+            info!("Waiting for {}s of quiet before starting...", period.as_secs());
+            if !Self::wait_for_quiet(&tx, &cancel_flag, &eta, period) {
+                #[cfg(feature = "debug-logging")]
+                warn!("Benchmark run CANCELLED while waiting for a quiet system");
+                let _ = tx.send(BenchmarkMessage::Cancelled);
+                return;
+            }
+        }
+
         // Collect system info
         #[cfg(feature = "debug-logging")]
         info!("Collecting system information...");
@@ -135,8 +169,25 @@ impl BenchmarkRunner {
 
         let total = benchmarks.len();
 
-        // Use default benchmark config (Quick preset)
-        let benchmark_config = BenchmarkConfig::default();
+        // Use default benchmark config (Quick preset). Its thread count follows the
+        // cpuset and affinity of this process; also cap it by any CPU quota.
+        let default_config = BenchmarkConfig::default();
+        let benchmark_config = BenchmarkConfig {
+            threads: default_config.threads.min(run.system_info.effective_threads() as usize),
+            ..default_config
+        };
+
+        // Idle temperature and clocks that each cool-down waits to get back to
+        let idle_readings: Vec<ThermalReading> = (0..BASELINE_SAMPLES)
+            .map(|i| {
+                if i > 0 {
+                    thread::sleep(BASELINE_SAMPLE_INTERVAL);
+                }
+                read_thermal()
+            })
+            .collect();
+        let thermal_baseline = settled_baseline(&idle_readings);
+        let mut load_sampler = LoadSampler::new();
 
         #[cfg(feature = "debug-logging")]
         {
@@ -154,9 +205,9 @@ impl BenchmarkRunner {
                 return;
             }
 
-            let overall_progress = idx as f32 / total as f32;
+            let overall_progress = eta.overall_progress(idx, 0.0);
+            let eta_secs = eta.remaining_secs(idx, 0.0);
 
-            #[cfg(feature = "debug-logging")]
             let bench_start = Instant::now();
 
             #[cfg(feature = "debug-logging")]
@@ -165,6 +216,7 @@ impl BenchmarkRunner {
                 info!("BENCHMARK [{}/{}]: {} ({})", idx + 1, total, benchmark.name(), benchmark.id());
                 info!("  Category: {:?}", benchmark.category());
                 info!("  Description: {}", benchmark.description());
+                info!("  Remaining (est.): {}", EtaEstimator::format_remaining(eta_secs));
                 info!("  Starting...");
             }
 
@@ -172,7 +224,8 @@ impl BenchmarkRunner {
                 tx: tx.clone(),
                 benchmark_id: benchmark.id().to_string(),
                 cancel_flag: Arc::clone(&cancel_flag),
-                overall_progress,
+                eta: Arc::clone(&eta),
+                index: idx,
                 #[cfg(feature = "debug-logging")]
                 benchmark_name: benchmark.name().to_string(),
             };
@@ -182,12 +235,23 @@ impl BenchmarkRunner {
                 benchmark_id: benchmark.id().to_string(),
                 overall_progress,
                 test_progress: 0.0,
+                eta_secs,
                 message: format!("Running {} ({}/{})", benchmark.name(), idx + 1, total),
             });
 
-            // Run the benchmark
-            match benchmark.run(&progress_callback, &benchmark_config) {
-                Ok(result) => {
+            // Run the benchmark, sampling temperatures and clocks alongside. Latency
+            // tests are short and would measure the sampler's own wake-ups instead.
+            let monitor = (benchmark.category() != Category::Responsiveness)
+                .then(|| ThermalMonitor::start(run.system_info.cpu.base_frequency_mhz));
+            let outcome = benchmark.run(&progress_callback, &benchmark_config);
+            let thermal = monitor.and_then(ThermalMonitor::finish);
+
+            match outcome {
+                Ok(mut result) => {
+                    result.details.thermal = thermal;
+                    run.test_durations
+                        .insert(result.test_id.clone(), bench_start.elapsed().as_secs_f64());
+
                     #[cfg(feature = "debug-logging")]
                     {
                         let elapsed = bench_start.elapsed();
@@ -222,7 +286,7 @@ impl BenchmarkRunner {
                         }
                     }
 
-                    let _ = tx.send(BenchmarkMessage::TestComplete { result });
+                    let _ = tx.send(BenchmarkMessage::TestComplete { result: Box::new(result) });
                 }
                 Err(e) => {
                     #[cfg(feature = "debug-logging")]
@@ -237,12 +301,20 @@ impl BenchmarkRunner {
                 }
             }
 
-            // Add delay between tests to avoid triggering AV behavioral heuristics
-            // that detect rapid suspicious activity patterns
+            // Let the CPU recover before the next test, so heat from this one doesn't
+            // throttle it. The minimum also keeps clear of AV behavioral heuristics
+            // that detect rapid suspicious activity patterns.
             if idx < total - 1 {
-                #[cfg(feature = "debug-logging")]
-                debug!("  Waiting 2s before next test (AV evasion delay)...");
-                thread::sleep(Duration::from_secs(2));
+                let _ = tx.send(BenchmarkMessage::Progress {
+                    benchmark_id: benchmark.id().to_string(),
+                    overall_progress: eta.overall_progress(idx + 1, 0.0),
+                    test_progress: 1.0,
+                    eta_secs: eta.remaining_secs(idx + 1, 0.0),
+                    message: "Cooling down before the next test...".to_string(),
+                });
+                let cooled = Self::cool_down(&cancel_flag, &thermal_baseline, &mut load_sampler);
+                run.cool_down_durations
+                    .insert(benchmark.id().to_string(), cooled.as_secs_f64());
             }
         }
 
@@ -263,6 +335,87 @@ impl BenchmarkRunner {
 
         let _ = tx.send(BenchmarkMessage::AllComplete { run: Box::new(run) });
     }
+
+    /// Sample load until it has stayed below the quiet thresholds for `period`, or
+    /// start anyway once `MAX_QUIET_OVERRUN` past it. Returns false if the run was
+    /// cancelled while waiting.
+    fn wait_for_quiet(
+        tx: &Sender<BenchmarkMessage>,
+        cancel_flag: &Arc<Mutex<bool>>,
+        eta: &EtaEstimator,
+        period: Duration,
+    ) -> bool {
+        let thresholds = QuietThresholds::default();
+        let mut sampler = LoadSampler::new();
+        let mut tracker = QuietTracker::default();
+        let deadline = Instant::now() + period + MAX_QUIET_OVERRUN;
+
+        loop {
+            thread::sleep(QUIET_POLL_INTERVAL);
+            if *cancel_flag.lock().unwrap() {
+                return false;
+            }
+
+            let sample = sampler.sample();
+            let now = Instant::now();
+            let quiet_for = tracker.observe(thresholds.allows(&sample), now);
+            if quiet_for >= period {
+                return true;
+            }
+            if now >= deadline {
+                #[cfg(feature = "debug-logging")]
+                warn!("System never stayed quiet for {}s, starting anyway", period.as_secs());
+                return true;
+            }
+
+            let _ = tx.send(BenchmarkMessage::Progress {
+                benchmark_id: String::new(),
+                overall_progress: 0.0,
+                test_progress: (quiet_for.as_secs_f64() / period.as_secs_f64()) as f32,
+                eta_secs: eta.total_secs() + (period - quiet_for).min(deadline - now).as_secs_f64(),
+                message: format!(
+                    "Waiting for a quiet system: {}s of {}s ({}), starting anyway in {}",
+                    quiet_for.as_secs(),
+                    period.as_secs(),
+                    sample.summary(),
+                    EtaEstimator::format_remaining((deadline - now).as_secs_f64())
+                ),
+            });
+        }
+    }
+
+    /// Wait between tests until temperature, clocks and load are back near their
+    /// pre-run state, within `MIN_COOL_DOWN` and `MAX_COOL_DOWN`. Returns how long
+    /// it waited.
+    fn cool_down(cancel_flag: &Arc<Mutex<bool>>, baseline: &ThermalReading, sampler: &mut LoadSampler) -> Duration {
+        let thresholds = QuietThresholds::default();
+        let started = Instant::now();
+        // Load from the finished test isn't what we're waiting on
+        sampler.sample();
+        let mut recent = VecDeque::from([read_thermal()]);
+
+        loop {
+            thread::sleep(COOL_DOWN_POLL_INTERVAL);
+            let waited = started.elapsed();
+            if waited >= MAX_COOL_DOWN || *cancel_flag.lock().unwrap() {
+                break;
+            }
+
+            if recent.len() == RECOVERY_WINDOW {
+                recent.pop_front();
+            }
+            recent.push_back(read_thermal());
+            let quiet = thresholds.allows(&sampler.sample());
+            if waited >= MIN_COOL_DOWN && quiet && thermal_recovered(baseline, recent.make_contiguous()) {
+                break;
+            }
+        }
+
+        #[cfg(feature = "debug-logging")]
+        debug!("  Cooled down for {:.1}s before next test", started.elapsed().as_secs_f64());
+
+        started.elapsed()
+    }
 }
 
 impl Default for BenchmarkRunner {
@@ -276,20 +429,31 @@ struct ChannelProgressCallback {
     tx: Sender<BenchmarkMessage>,
     benchmark_id: String,
     cancel_flag: Arc<Mutex<bool>>,
-    overall_progress: f32,
+    eta: Arc<EtaEstimator>,
+    /// Position of this benchmark in the run
+    index: usize,
     #[cfg(feature = "debug-logging")]
     benchmark_name: String,
 }
 
 impl ProgressCallback for ChannelProgressCallback {
     fn update(&self, progress: f32, message: &str) {
+        let eta_secs = self.eta.remaining_secs(self.index, progress);
+
         #[cfg(feature = "debug-logging")]
-        debug!("    [{}] Progress: {:.1}% - {}", self.benchmark_name, progress * 100.0, message);
+        debug!(
+            "    [{}] Progress: {:.1}% - {} (ETA {})",
+            self.benchmark_name,
+            progress * 100.0,
+            message,
+            EtaEstimator::format_remaining(eta_secs)
+        );
 
         let _ = self.tx.send(BenchmarkMessage::Progress {
             benchmark_id: self.benchmark_id.clone(),
-            overall_progress: self.overall_progress,
+            overall_progress: self.eta.overall_progress(self.index, progress),
             test_progress: progress,
+            eta_secs,
             message: message.to_string(),
         });
     }
@@ -302,4 +466,11 @@ impl ProgressCallback for ChannelProgressCallback {
         }
         cancelled
     }
+
+    fn sample(&self, sample: LiveSample) {
+        let _ = self.tx.send(BenchmarkMessage::Sample {
+            benchmark_id: self.benchmark_id.clone(),
+            sample,
+        });
+    }
 }
@@ -0,0 +1,235 @@
+//! Composite scoring against a bundled reference machine.
+//!
+//! Each test is scored as the ratio between this run and the reference result,
+//! inverted for lower-is-better metrics, so the reference machine scores
+//! `REFERENCE_SCORE` on every test. Test scores are combined by geometric mean
+//! into category scores, and category scores into the overall score.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::benchmarks::Category;
+use crate::models::{BenchmarkRun, TestResult};
+
+/// Score of the reference machine on every test, category and overall
+pub const REFERENCE_SCORE: f64 = 1000.0;
+
+/// Bundled reference results; bump the version whenever the values change
+const REFERENCE_JSON: &str = include_str!("../../assets/scoring/reference_v1.json");
+
+/// Results of the machine all scores are normalised against
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReferenceMachine {
+    /// Scores are only comparable between runs scored with the same version
+    pub version: u32,
+    pub name: String,
+    /// Primary result value per test_id
+    pub results: BTreeMap<String, f64>,
+}
+
+impl ReferenceMachine {
+    /// The reference machine shipped with this release
+    pub fn bundled() -> Self {
+        serde_json::from_str(REFERENCE_JSON).expect("bundled reference machine is valid JSON")
+    }
+}
+
+/// Score of a single test relative to the reference
+#[derive(Debug, Clone)]
+pub struct TestScore {
+    pub test_id: String,
+    pub score: f64,
+}
+
+/// Geometric mean of the scored tests in one category
+#[derive(Debug, Clone)]
+pub struct CategoryScore {
+    pub category: Category,
+    pub score: f64,
+    pub tests: Vec<TestScore>,
+    /// Tests in the category without a usable reference or value
+    pub unscored: usize,
+}
+
+/// All scores of a run, tagged with the reference version they were computed against
+#[derive(Debug, Clone)]
+pub struct RunScore {
+    pub reference_version: u32,
+    pub reference_name: String,
+    pub categories: Vec<CategoryScore>,
+    /// Geometric mean of the category scores (None if nothing could be scored)
+    pub overall: Option<f64>,
+}
+
+impl RunScore {
+    /// Score of a single test, if it could be scored
+    pub fn test_score(&self, test_id: &str) -> Option<f64> {
+        self.categories
+            .iter()
+            .flat_map(|c| c.tests.iter())
+            .find(|t| t.test_id == test_id)
+            .map(|t| t.score)
+    }
+}
+
+/// Computes composite scores for benchmark runs
+pub struct ScoringEngine {
+    reference: ReferenceMachine,
+}
+
+impl ScoringEngine {
+    pub fn new(reference: ReferenceMachine) -> Self {
+        Self { reference }
+    }
+
+    /// Engine scoring against the bundled reference machine, parsed once
+    pub fn bundled() -> &'static Self {
+        static BUNDLED: OnceLock<ScoringEngine> = OnceLock::new();
+        BUNDLED.get_or_init(|| Self::new(ReferenceMachine::bundled()))
+    }
+
+    /// Score every category of a run; categories without scorable tests are omitted
+    pub fn score_run(&self, run: &BenchmarkRun) -> RunScore {
+        let categories: Vec<CategoryScore> = [
+            (Category::ProjectOperations, &run.results.project_operations),
+            (Category::BuildPerformance, &run.results.build_performance),
+            (Category::Responsiveness, &run.results.responsiveness),
+        ]
+        .into_iter()
+        .filter_map(|(category, results)| self.score_category(category, results))
+        .collect();
+
+        let overall = geometric_mean(categories.iter().map(|c| c.score));
+
+        RunScore {
+            reference_version: self.reference.version,
+            reference_name: self.reference.name.clone(),
+            categories,
+            overall,
+        }
+    }
+
+    fn score_category(&self, category: Category, results: &[TestResult]) -> Option<CategoryScore> {
+        let tests: Vec<TestScore> = results.iter().filter_map(|r| self.score_test(r, category)).collect();
+        let score = geometric_mean(tests.iter().map(|t| t.score))?;
+
+        Some(CategoryScore {
+            category,
+            score,
+            unscored: results.len() - tests.len(),
+            tests,
+        })
+    }
+
+    /// Direction-aware ratio to the reference, capped at the category's max score
+    pub fn score_test(&self, result: &TestResult, category: Category) -> Option<TestScore> {
+        let reference = *self.reference.results.get(&result.test_id)?;
+        if !(result.value.is_finite() && result.value > 0.0 && reference > 0.0) {
+            return None;
+        }
+
+        let ratio = if result.higher_is_better() {
+            result.value / reference
+        } else {
+            reference / result.value
+        };
+
+        Some(TestScore {
+            test_id: result.test_id.clone(),
+            score: (ratio * REFERENCE_SCORE).min(category.max_score() as f64),
+        })
+    }
+}
+
+/// Score of the run on screen, so repaints don't rescore it
+#[derive(Debug, Default)]
+pub struct ScoreCache {
+    scored: Option<(Uuid, RunScore)>,
+}
+
+impl ScoreCache {
+    /// Score of `run` against the bundled reference, computed when a different run is shown
+    pub fn get(&mut self, run: &BenchmarkRun) -> &RunScore {
+        if self.scored.as_ref().is_none_or(|(id, _)| *id != run.id) {
+            self.scored = Some((run.id, ScoringEngine::bundled().score_run(run)));
+        }
+        &self.scored.as_ref().expect("just scored").1
+    }
+}
+
+fn geometric_mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (log_sum, count) = values.fold((0.0, 0usize), |(sum, n), v| (sum + v.ln(), n + 1));
+    (count > 0).then(|| (log_sum / count as f64).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TestDetails;
+
+    fn result(test_id: &str, value: f64, unit: &str) -> TestResult {
+        TestResult {
+            test_id: test_id.to_string(),
+            name: test_id.to_string(),
+            description: String::new(),
+            value,
+            unit: unit.to_string(),
+            details: TestDetails::default(),
+        }
+    }
+
+    fn engine() -> ScoringEngine {
+        ScoringEngine::new(ReferenceMachine {
+            version: 1,
+            name: "Test".to_string(),
+            results: BTreeMap::from([
+                ("read".to_string(), 100.0),
+                ("latency".to_string(), 2.0),
+            ]),
+        })
+    }
+
+    #[test]
+    fn test_bundled_reference_parses() {
+        let reference = ReferenceMachine::bundled();
+        assert!(reference.version >= 1);
+        assert!(reference.results.values().all(|v| *v > 0.0));
+    }
+
+    #[test]
+    fn test_direction_aware_ratios() {
+        let engine = engine();
+        let cat = Category::ProjectOperations;
+
+        // Twice the throughput and half the latency both score double
+        let faster = engine.score_test(&result("read", 200.0, "MB/s"), cat).unwrap();
+        let quicker = engine.score_test(&result("latency", 1.0, "ms (P99)"), cat).unwrap();
+        assert_eq!(faster.score, 2000.0);
+        assert_eq!(quicker.score, 2000.0);
+
+        // Capped, and unknown or empty results are not scored
+        let capped = engine.score_test(&result("read", 1000.0, "MB/s"), cat).unwrap();
+        assert_eq!(capped.score, cat.max_score() as f64);
+        assert!(engine.score_test(&result("unknown", 1.0, "ms"), cat).is_none());
+        assert!(engine.score_test(&result("latency", 0.0, "ms"), cat).is_none());
+    }
+
+    #[test]
+    fn test_geometric_mean_aggregation() {
+        let engine = engine();
+        let results = vec![
+            result("read", 200.0, "MB/s"),
+            result("latency", 4.0, "ms"),
+            result("unknown", 1.0, "ms"),
+        ];
+
+        let category = engine.score_category(Category::BuildPerformance, &results).unwrap();
+        assert!((category.score - 1000.0).abs() < 1e-9);
+        assert_eq!(category.tests.len(), 2);
+        assert_eq!(category.unscored, 1);
+        assert!(engine.score_category(Category::Responsiveness, &[]).is_none());
+    }
+}
@@ -0,0 +1,273 @@
+//! SMBIOS table parsing for memory module details
+//!
+//! Takes the raw structure table, as exposed by `/sys/firmware/dmi/tables/DMI`,
+//! and reads the Memory Device (type 17) records. Offsets follow the DMTF
+//! SMBIOS specification; fields a record is too old to carry come back as None.
+
+use std::collections::HashSet;
+
+use crate::models::MemoryModule;
+
+const MEMORY_DEVICE: u8 = 17;
+const END_OF_TABLE: u8 = 127;
+
+const KB: u64 = 1024;
+const MB: u64 = 1024 * 1024;
+
+/// A structure's formatted area and its string set
+struct Structure<'a> {
+    kind: u8,
+    data: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+impl Structure<'_> {
+    fn byte(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    fn word(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn dword(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// String referenced by the index at `offset`, skipping firmware placeholders
+    fn string(&self, offset: usize) -> Option<String> {
+        let index = self.byte(offset)? as usize;
+        let raw = self.strings.get(index.checked_sub(1)?)?;
+        let value = String::from_utf8_lossy(raw).trim().to_string();
+        let placeholder = ["", "not specified", "unknown", "none", "undefined", "no dimm"]
+            .contains(&value.to_lowercase().as_str());
+        (!placeholder).then_some(value)
+    }
+}
+
+fn structures(table: &[u8]) -> Vec<Structure<'_>> {
+    let mut structures = Vec::new();
+    let mut pos = 0;
+
+    while pos + 4 <= table.len() {
+        let kind = table[pos];
+        let length = table[pos + 1] as usize;
+        if length < 4 || pos + length > table.len() {
+            break;
+        }
+
+        // The string set follows the formatted area and ends with a double NUL
+        let rest = &table[pos + length..];
+        let Some(end) = rest.windows(2).position(|pair| pair == [0, 0]) else {
+            break;
+        };
+        structures.push(Structure {
+            kind,
+            data: &table[pos..pos + length],
+            strings: rest[..end].split(|&b| b == 0).collect(),
+        });
+
+        if kind == END_OF_TABLE {
+            break;
+        }
+        pos += length + end + 2;
+    }
+
+    structures
+}
+
+/// Installed memory modules; empty slots are skipped
+pub fn memory_modules(table: &[u8]) -> Vec<MemoryModule> {
+    structures(table)
+        .iter()
+        .filter(|s| s.kind == MEMORY_DEVICE)
+        .filter_map(memory_module)
+        .collect()
+}
+
+fn memory_module(device: &Structure) -> Option<MemoryModule> {
+    let size_bytes = match device.word(0x0C)? {
+        // Empty slot, or a size the firmware doesn't know
+        0 | 0xFFFF => return None,
+        0x7FFF => (device.dword(0x1C)? & 0x7FFF_FFFF) as u64 * MB,
+        size if size & 0x8000 != 0 => (size & 0x7FFF) as u64 * KB,
+        size => size as u64 * MB,
+    };
+
+    Some(MemoryModule {
+        slot: device.string(0x10).unwrap_or_default(),
+        bank: device.string(0x11),
+        size_bytes,
+        memory_type: device.byte(0x12).and_then(memory_type_name).map(str::to_string),
+        speed_mhz: speed(device, 0x15, 0x54),
+        configured_speed_mhz: speed(device, 0x20, 0x58),
+        manufacturer: device.string(0x17),
+        part_number: device.string(0x1A),
+    })
+}
+
+/// A speed word, deferring to the extended dword when it reads 0xFFFF
+fn speed(device: &Structure, offset: usize, extended: usize) -> Option<u32> {
+    match device.word(offset)? {
+        0 => None,
+        0xFFFF => device.dword(extended).filter(|&speed| speed > 0),
+        speed => Some(speed as u32),
+    }
+}
+
+fn memory_type_name(code: u8) -> Option<&'static str> {
+    Some(match code {
+        0x0F => "SDRAM",
+        0x12 => "DDR",
+        0x13 => "DDR2",
+        0x14 => "DDR2 FB-DIMM",
+        0x18 => "DDR3",
+        0x1A => "DDR4",
+        0x1B => "LPDDR",
+        0x1C => "LPDDR2",
+        0x1D => "LPDDR3",
+        0x1E => "LPDDR4",
+        0x20 => "HBM",
+        0x21 => "HBM2",
+        0x22 => "DDR5",
+        0x23 => "LPDDR5",
+        0x24 => "HBM3",
+        _ => return None,
+    })
+}
+
+/// Channels with a module installed, from slot and bank labels
+///
+/// Firmware labels slots like "ChannelA-DIMM0", "P0 CHANNEL A" or "DIMM_A1",
+/// where the letter is the channel. None if any module's label doesn't say.
+pub fn populated_channels(modules: &[MemoryModule]) -> Option<u32> {
+    if modules.len() == 1 {
+        return Some(1);
+    }
+
+    let channels: Option<HashSet<String>> = modules.iter().map(channel_of).collect();
+    channels.filter(|c| !c.is_empty()).map(|c| c.len() as u32)
+}
+
+fn channel_of(module: &MemoryModule) -> Option<String> {
+    let label = format!("{} {}", module.bank.as_deref().unwrap_or_default(), module.slot).to_uppercase();
+
+    // Channel letters repeat per memory controller on some laptops
+    let controller = token_after(&label, "CONTROLLER").unwrap_or_default();
+    let channel = token_after(&label, "CHANNEL").or_else(|| dimm_channel(&label))?;
+    Some(format!("{}{}", controller, channel))
+}
+
+/// Alphanumeric token following `key`, past any separators
+fn token_after(label: &str, key: &str) -> Option<String> {
+    let rest = &label[label.find(key)? + key.len()..];
+    let token: String = rest
+        .trim_start_matches([' ', '_', '-'])
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    (!token.is_empty()).then_some(token)
+}
+
+/// Channel letter of a "DIMM_A1"-style label
+fn dimm_channel(label: &str) -> Option<String> {
+    let mut chars = label[label.find("DIMM")? + 4..].trim_start_matches([' ', '_', '-']).chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some(digit)) if letter.is_ascii_alphabetic() && digit.is_ascii_digit() => {
+            Some(letter.to_string())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An SMBIOS 3.3 memory device record with strings slot, bank, manufacturer, part number
+    fn memory_device(size: u16, extended_size: u32, memory_type: u8, speed: u16, strings: [&str; 4]) -> Vec<u8> {
+        let mut data = vec![0u8; 0x5C];
+        data[0] = MEMORY_DEVICE;
+        data[1] = data.len() as u8;
+        data[0x0C..0x0E].copy_from_slice(&size.to_le_bytes());
+        data[0x10] = 1;
+        data[0x11] = 2;
+        data[0x12] = memory_type;
+        data[0x15..0x17].copy_from_slice(&speed.to_le_bytes());
+        data[0x17] = 3;
+        data[0x1A] = 4;
+        data[0x1C..0x20].copy_from_slice(&extended_size.to_le_bytes());
+        data[0x20..0x22].copy_from_slice(&speed.to_le_bytes());
+
+        for string in strings {
+            data.extend_from_slice(string.as_bytes());
+            data.push(0);
+        }
+        data.push(0);
+        data
+    }
+
+    fn module(bank: &str, slot: &str) -> MemoryModule {
+        MemoryModule {
+            slot: slot.to_string(),
+            bank: Some(bank.to_string()),
+            size_bytes: 8 * 1024 * MB,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parses_memory_devices() {
+        let mut table = Vec::new();
+        // BIOS information record with no strings
+        table.extend_from_slice(&[0, 4, 0, 0, 0, 0]);
+        table.extend(memory_device(16384, 0, 0x1A, 3200, ["ChannelA-DIMM0", "BANK 0", "Kingston", "KF432C16BB/16 "]));
+        table.extend(memory_device(0, 0, 0x02, 0, ["ChannelA-DIMM1", "BANK 1", "Not Specified", "Not Specified"]));
+        table.extend(memory_device(0x7FFF, 65536, 0x22, 0xFFFF, ["DIMM_B1", "P0 CHANNEL B", "Samsung", "M321R8GA0BB0"]));
+        table.extend_from_slice(&[END_OF_TABLE, 4, 0, 0, 0, 0]);
+        // Anything past the end-of-table record is ignored
+        table.extend(memory_device(8192, 0, 0x1A, 2666, ["DIMM_C1", "", "", ""]));
+
+        let modules = memory_modules(&table);
+        assert_eq!(modules.len(), 2);
+
+        assert_eq!(modules[0].slot, "ChannelA-DIMM0");
+        assert_eq!(modules[0].size_bytes, 16 * 1024 * MB);
+        assert_eq!(modules[0].memory_type.as_deref(), Some("DDR4"));
+        assert_eq!(modules[0].speed_mhz, Some(3200));
+        assert_eq!(modules[0].configured_speed_mhz, Some(3200));
+        assert_eq!(modules[0].part_number.as_deref(), Some("KF432C16BB/16"));
+
+        // Extended size, and a speed word deferring to the missing extended speed
+        assert_eq!(modules[1].size_bytes, 64 * 1024 * MB);
+        assert_eq!(modules[1].memory_type.as_deref(), Some("DDR5"));
+        assert_eq!(modules[1].speed_mhz, None);
+
+        assert_eq!(populated_channels(&modules), Some(2));
+    }
+
+    #[test]
+    fn test_populated_channels() {
+        let single = [module("BANK 0", "DIMM 0")];
+        assert_eq!(populated_channels(&single), Some(1));
+
+        let same_channel = [module("", "DIMM_A1"), module("", "DIMM_A2")];
+        assert_eq!(populated_channels(&same_channel), Some(1));
+
+        let dual = [module("P0 CHANNEL A", "DIMM 0"), module("P0 CHANNEL B", "DIMM 0")];
+        assert_eq!(populated_channels(&dual), Some(2));
+
+        let soldered = [
+            module("BANK 0", "Controller0-ChannelA"),
+            module("BANK 1", "Controller0-ChannelB"),
+            module("BANK 0", "Controller1-ChannelA"),
+            module("BANK 1", "Controller1-ChannelB"),
+        ];
+        assert_eq!(populated_channels(&soldered), Some(4));
+
+        let unlabeled = [module("BANK 0", "DIMM 0"), module("BANK 1", "DIMM 1")];
+        assert_eq!(populated_channels(&unlabeled), None);
+    }
+}
@@ -0,0 +1,260 @@
+//! Linux storage detection: mounts mapped to the block devices behind them
+//!
+//! A mount's device is followed through device-mapper (LVM, LUKS) and
+//! partitions down to whole disks, whose queue and device attributes tell
+//! NVMe, SSD and HDD apart without guessing from names.
+
+use std::collections::HashSet;
+
+use super::fs_tree::FsTree;
+use crate::models::{StorageInfo, StorageType};
+
+/// Filesystems that never sit on a local disk
+const VIRTUAL_FILESYSTEMS: &[&str] = &["tmpfs", "devtmpfs", "ramfs", "overlay", "squashfs", "zram"];
+
+/// Block devices that aren't storage hardware
+const VIRTUAL_DEVICES: &[&str] = &["loop", "ram", "zram"];
+
+/// Deepest device-mapper stack followed
+const MAX_STACK_DEPTH: usize = 8;
+
+/// A mounted filesystem as the OS reports it
+pub struct Mount {
+    /// e.g. "/dev/nvme0n1p2" or "/dev/mapper/vg-root"
+    pub device: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+}
+
+/// One entry per disk-backed device, root filesystem first
+pub fn detect_storage(sysfs: &FsTree, mounts: &[Mount]) -> Vec<StorageInfo> {
+    let mut mounts: Vec<&Mount> = mounts
+        .iter()
+        .filter(|m| m.device.starts_with("/dev/") && !VIRTUAL_FILESYSTEMS.contains(&m.file_system.as_str()))
+        .collect();
+
+    // A device mounted in several places (bind mounts, subvolumes) is listed at its shortest mount point
+    mounts.sort_by(|a, b| {
+        a.mount_point
+            .len()
+            .cmp(&b.mount_point.len())
+            .then_with(|| a.mount_point.cmp(&b.mount_point))
+    });
+    let mut seen = HashSet::new();
+
+    mounts
+        .into_iter()
+        .filter(|m| seen.insert(m.device.as_str()))
+        .filter_map(|m| describe_mount(sysfs, m))
+        .collect()
+}
+
+fn describe_mount(sysfs: &FsTree, mount: &Mount) -> Option<StorageInfo> {
+    let block = block_name(sysfs, &mount.device)?;
+    if VIRTUAL_DEVICES.iter().any(|prefix| block.starts_with(prefix)) {
+        return None;
+    }
+
+    let disks = backing_disks(sysfs, &block, 0);
+    let disk = disks.first()?;
+
+    // A volume spanning different kinds of disk has no single type
+    let types: Vec<StorageType> = disks.iter().map(|d| classify(sysfs, d)).collect();
+    let device_type = if types.iter().all(|t| *t == types[0]) {
+        types[0]
+    } else {
+        StorageType::Unknown
+    };
+
+    Some(StorageInfo {
+        name: mount.device.clone(),
+        device_type,
+        capacity_bytes: mount.total_bytes,
+        mount_point: Some(mount.mount_point.clone()),
+        file_system: Some(mount.file_system.clone()),
+        model: sysfs.read(format!("block/{}/device/model", disk)),
+        firmware: sysfs
+            .read(format!("block/{}/device/firmware_rev", disk))
+            .or_else(|| sysfs.read(format!("block/{}/device/rev", disk))),
+        transport: transport(sysfs, disk),
+        logical_block_size: sysfs
+            .read_u64(format!("block/{}/queue/logical_block_size", disk))
+            .map(|size| size as u32),
+        physical_block_size: sysfs
+            .read_u64(format!("block/{}/queue/physical_block_size", disk))
+            .map(|size| size as u32),
+    })
+}
+
+/// Kernel name of the block device behind a `/dev` path
+fn block_name(sysfs: &FsTree, device: &str) -> Option<String> {
+    let path = device.strip_prefix("/dev/")?;
+
+    // Device-mapper nodes are named after the volume; sysfs knows them as dm-N
+    if let Some(volume) = path.strip_prefix("mapper/") {
+        return sysfs
+            .list("block")
+            .into_iter()
+            .find(|dm| sysfs.read(format!("block/{}/dm/name", dm)).as_deref() == Some(volume));
+    }
+
+    path.rsplit('/').next().map(str::to_string)
+}
+
+/// Whole disks under a block device, following device-mapper slaves and partitions
+fn backing_disks(sysfs: &FsTree, name: &str, depth: usize) -> Vec<String> {
+    if depth > MAX_STACK_DEPTH {
+        return Vec::new();
+    }
+
+    // Disks and device-mapper devices are top-level entries of /sys/block
+    if sysfs.exists(format!("block/{}", name)) {
+        let slaves = sysfs.list(format!("block/{}/slaves", name));
+        if slaves.is_empty() {
+            return vec![name.to_string()];
+        }
+
+        let mut disks: Vec<String> = Vec::new();
+        for disk in slaves.iter().flat_map(|slave| backing_disks(sysfs, slave, depth + 1)) {
+            if !disks.contains(&disk) {
+                disks.push(disk);
+            }
+        }
+        return disks;
+    }
+
+    // Partitions are listed inside their disk
+    sysfs
+        .list("block")
+        .into_iter()
+        .find(|disk| sysfs.exists(format!("block/{}/{}/partition", disk, name)))
+        .map(|disk| backing_disks(sysfs, &disk, depth + 1))
+        .unwrap_or_default()
+}
+
+/// How a disk is attached
+fn transport(sysfs: &FsTree, disk: &str) -> Option<String> {
+    let transport = if disk.starts_with("nvme") {
+        "nvme"
+    } else if disk.starts_with("vd") {
+        "virtio"
+    } else if disk.starts_with("mmcblk") {
+        "mmc"
+    } else if sysfs
+        .canonical(format!("block/{}", disk))
+        .is_some_and(|path| path.components().any(|c| c.as_os_str().to_string_lossy().starts_with("usb")))
+    {
+        "usb"
+    } else if sysfs.read(format!("block/{}/device/vendor", disk)).as_deref() == Some("ATA") {
+        // libata presents SATA disks as SCSI devices from vendor "ATA"
+        "sata"
+    } else if disk.starts_with("sd") {
+        "scsi"
+    } else {
+        return None;
+    };
+    Some(transport.to_string())
+}
+
+fn classify(sysfs: &FsTree, disk: &str) -> StorageType {
+    if disk.starts_with("nvme") {
+        return StorageType::NVMe;
+    }
+
+    match sysfs.read_u64(format!("block/{}/queue/rotational", disk)) {
+        Some(0) => StorageType::SSD,
+        // Virtual disks report rotational by default, whatever backs them
+        Some(1) if !disk.starts_with("vd") => StorageType::HDD,
+        _ => StorageType::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    const USB_DISK: &str = "devices/pci0000:00/0000:00:14.0/usb2/2-1/2-1:1.0/host6/target6:0:0/6:0:0:0/block/sdb";
+
+    fn mount(device: &str, mount_point: &str, file_system: &str) -> Mount {
+        Mount {
+            device: device.to_string(),
+            mount_point: mount_point.to_string(),
+            file_system: file_system.to_string(),
+            total_bytes: 1 << 30,
+        }
+    }
+
+    #[test]
+    fn test_maps_mounts_to_disks() {
+        let fake = FakeFsTree::new(&[
+            // NVMe system disk
+            ("block/nvme0n1/nvme0n1p2/partition", "2"),
+            ("block/nvme0n1/queue/rotational", "0"),
+            ("block/nvme0n1/queue/logical_block_size", "512"),
+            ("block/nvme0n1/queue/physical_block_size", "4096"),
+            ("block/nvme0n1/device/model", "Samsung SSD 990 PRO 2TB"),
+            ("block/nvme0n1/device/firmware_rev", "4B2QJXD7"),
+            // LVM volume on a SATA hard disk partition
+            ("block/dm-0/dm/name", "vg-home"),
+            ("block/dm-0/slaves/sda1", ""),
+            ("block/sda/sda1/partition", "1"),
+            ("block/sda/queue/rotational", "1"),
+            ("block/sda/device/vendor", "ATA"),
+            ("block/sda/device/model", "WDC WD40EFRX-68N32N0"),
+            ("block/sda/device/rev", "0A82"),
+            // USB flash drive
+            (&format!("{}/sdb1/partition", USB_DISK), "1"),
+            (&format!("{}/queue/rotational", USB_DISK), "0"),
+        ]);
+        fake.link("block/sdb", USB_DISK);
+
+        let storage = detect_storage(
+            &fake.tree(),
+            &[
+                mount("/dev/mapper/vg-home", "/home", "xfs"),
+                mount("/dev/nvme0n1p2", "/var/lib/docker", "ext4"),
+                mount("/dev/nvme0n1p2", "/", "ext4"),
+                mount("tmpfs", "/tmp", "tmpfs"),
+                mount("/dev/loop3", "/snap/core22/1380", "squashfs"),
+                mount("/dev/sdb1", "/media/usb", "exfat"),
+            ],
+        );
+
+        let mount_points: Vec<_> = storage.iter().map(|s| s.mount_point.as_deref().unwrap()).collect();
+        assert_eq!(mount_points, ["/", "/home", "/media/usb"]);
+
+        let root = &storage[0];
+        assert_eq!(root.device_type, StorageType::NVMe);
+        assert_eq!(root.transport.as_deref(), Some("nvme"));
+        assert_eq!(root.model.as_deref(), Some("Samsung SSD 990 PRO 2TB"));
+        assert_eq!(root.firmware.as_deref(), Some("4B2QJXD7"));
+        assert_eq!((root.logical_block_size, root.physical_block_size), (Some(512), Some(4096)));
+        assert_eq!(root.file_system.as_deref(), Some("ext4"));
+
+        let home = &storage[1];
+        assert_eq!(home.device_type, StorageType::HDD);
+        assert_eq!(home.transport.as_deref(), Some("sata"));
+        assert_eq!(home.firmware.as_deref(), Some("0A82"));
+
+        let usb = &storage[2];
+        assert_eq!(usb.device_type, StorageType::SSD);
+        assert_eq!(usb.transport.as_deref(), Some("usb"));
+    }
+
+    #[test]
+    fn test_mixed_volume_is_unknown() {
+        let fake = FakeFsTree::new(&[
+            ("block/dm-1/dm/name", "vg-data"),
+            ("block/dm-1/slaves/sda", ""),
+            ("block/dm-1/slaves/sdb", ""),
+            ("block/sda/queue/rotational", "0"),
+            ("block/sdb/queue/rotational", "1"),
+        ]);
+
+        let storage = detect_storage(&fake.tree(), &[mount("/dev/mapper/vg-data", "/data", "ext4")]);
+        assert_eq!(storage[0].device_type, StorageType::Unknown);
+        assert_eq!(storage[0].transport.as_deref(), Some("scsi"));
+    }
+}
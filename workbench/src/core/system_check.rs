@@ -2,12 +2,62 @@
 //!
 //! Checks system readiness before running benchmarks to ensure accurate results.
 
+use std::path::{Path, PathBuf};
+#[cfg(windows)]
 use std::process::Command;
-use sysinfo::{CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind, RefreshKind, System};
-
+use std::time::{Duration, Instant};
+use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, ProcessRefreshKind, RefreshKind, System};
+
+use super::thermal::read_thermal;
+/// CPU temperature before the run, °C; re-exported with the other warning thresholds
+pub use super::thermal::{HOT_TEMP_C, WARM_TEMP_C};
+use crate::benchmarks::BenchmarkConfig;
+
+#[cfg(target_os = "linux")]
+use super::activity::{read_pressure, ActivitySnapshot};
+#[cfg(target_os = "linux")]
+use super::power::{detect_power_plan, detect_power_state};
+#[cfg(target_os = "linux")]
+use super::fs_tree::FsTree;
 #[cfg(windows)]
 use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
 
+/// Where to change the power mode, for warning remediations
+#[cfg(windows)]
+const POWER_MODE_SETTING: &str = "Settings > System > Power & battery > Power mode";
+#[cfg(not(windows))]
+const POWER_MODE_SETTING: &str = "`powerprofilesctl set performance` or your desktop's power settings";
+
+/// Free space to leave on the test volume beyond what the tests write
+const SCRATCH_HEADROOM_GB: f64 = 0.5;
+
+pub const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+// Thresholds for warnings, shared with the pre-check view so its colours agree.
+// Each pair is (warning, critical).
+
+/// Overall CPU usage, percent
+pub const CPU_USAGE_WARNING: f32 = 20.0;
+pub const CPU_USAGE_CRITICAL: f32 = 50.0;
+/// Available memory, GB; a warning below the first, critical below the second
+pub const LOW_MEMORY_WARNING_GB: f64 = 4.0;
+pub const LOW_MEMORY_CRITICAL_GB: f64 = 2.0;
+/// Share of time the busiest disk was busy, percent
+pub const DISK_BUSY_WARNING: f32 = 15.0;
+pub const DISK_BUSY_CRITICAL: f32 = 50.0;
+/// Pages moved to and from swap per second
+pub const SWAP_PAGES_WARNING: f64 = 50.0;
+pub const SWAP_PAGES_CRITICAL: f64 = 1000.0;
+/// Share of time tasks stalled on a resource, percent
+pub const CPU_PRESSURE_WARNING: f32 = 20.0;
+pub const CPU_PRESSURE_CRITICAL: f32 = 50.0;
+pub const IO_PRESSURE_WARNING: f32 = 5.0;
+pub const IO_PRESSURE_CRITICAL: f32 = 20.0;
+pub const MEMORY_PRESSURE_WARNING: f32 = 1.0;
+pub const MEMORY_PRESSURE_CRITICAL: f32 = 10.0;
+/// Free space left on the test volume after the scratch files, GB
+pub const LOW_DISK_SPACE_GB: f64 = 10.0;
+
 /// Severity level for system warnings
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WarningSeverity {
@@ -56,7 +106,7 @@ impl PowerState {
     }
 }
 
-/// Windows power plan
+/// Windows power plan, or the Linux platform profile and CPU frequency policy
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PowerPlan {
     /// High Performance mode
@@ -86,6 +136,56 @@ impl PowerPlan {
     }
 }
 
+/// Other work competing for the disks and memory during the check
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackgroundActivity {
+    /// Share of the sampling window the busiest disk spent doing I/O
+    pub disk_busy_percent: Option<f32>,
+    /// Name of the busiest disk
+    pub busiest_disk: Option<String>,
+    /// Pages swapped in and out per second
+    pub swap_pages_per_sec: Option<f64>,
+}
+
+/// Scratch space the queued tests need on the test volume, summed over the
+/// tests' `Benchmark::scratch_bytes`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScratchRequirement {
+    /// Bytes for the tests as selected
+    pub selected_bytes: u64,
+    /// Bytes with Safe Mode's smaller Lite tests
+    pub lite_bytes: u64,
+}
+
+/// One reading of load on the system, for deciding whether it is quiet
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadSample {
+    /// Average CPU usage across all cores
+    pub cpu_percent: f32,
+    pub disk_busy_percent: Option<f32>,
+    pub pressure: Option<PressureStall>,
+}
+
+impl LoadSample {
+    /// Short description such as "CPU 4%, disk 12%"
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("CPU {:.0}%", self.cpu_percent)];
+        if let Some(busy) = self.disk_busy_percent {
+            parts.push(format!("disk {:.0}%", busy));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Linux pressure stall information: the percentage of the last 10 seconds in
+/// which some task was waiting on each resource
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PressureStall {
+    pub cpu: Option<f32>,
+    pub io: Option<f32>,
+    pub memory: Option<f32>,
+}
+
 /// Information about a high-CPU process
 #[derive(Debug, Clone)]
 pub struct ProcessInfo {
@@ -117,6 +217,20 @@ pub struct SystemCheckResult {
     pub cpu_usage_percent: f32,
     /// Available memory in GB
     pub available_memory_gb: f64,
+    /// CPU package temperature, where the platform exposes it
+    pub cpu_temperature_c: Option<f64>,
+    /// Disk and swap activity measured while sampling CPU usage
+    pub activity: BackgroundActivity,
+    /// Resource pressure, on Linux kernels that report it
+    pub pressure: Option<PressureStall>,
+    /// Swap in use in GB
+    pub swap_used_gb: f64,
+    /// Total swap in GB
+    pub swap_total_gb: f64,
+    /// Free space in GB on the volume benchmarks write their test files to
+    pub test_volume_free_gb: Option<f64>,
+    /// Space the tests will write to that volume
+    pub scratch: ScratchRequirement,
     /// Current power state
     pub power_state: PowerState,
     /// Current power plan
@@ -129,12 +243,74 @@ pub struct SystemCheckResult {
     pub ready_to_benchmark: bool,
 }
 
+/// Disk and swap counters at the start of a measurement window
+#[cfg(target_os = "linux")]
+type ActivityStart = ActivitySnapshot;
+#[cfg(not(target_os = "linux"))]
+type ActivityStart = ();
+
+impl SystemCheckResult {
+    /// Whether the test volume has room for the selected tests; true when free space is unknown
+    pub fn scratch_fits(&self) -> bool {
+        self.test_volume_free_gb
+            .is_none_or(|free_gb| fits_scratch(free_gb, self.scratch.selected_bytes))
+    }
+
+    /// Whether Safe Mode's smaller tests would fit where the selected ones don't
+    pub fn lite_scratch_fits(&self) -> bool {
+        self.test_volume_free_gb
+            .is_none_or(|free_gb| fits_scratch(free_gb, self.scratch.lite_bytes))
+    }
+}
+
 /// System checker that performs pre-benchmark readiness checks
 pub struct SystemChecker;
 
+/// Repeated CPU, disk and pressure readings, each covering the time since the previous one
+pub struct LoadSampler {
+    sys: System,
+    activity: ActivityStart,
+    last_sample: Instant,
+}
+
+impl LoadSampler {
+    pub fn new() -> Self {
+        let mut sys = System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
+        sys.refresh_cpu_usage();
+
+        Self {
+            sys,
+            activity: SystemChecker::snapshot_activity(),
+            last_sample: Instant::now(),
+        }
+    }
+
+    /// Load since the previous sample; call at intervals well above 200ms
+    pub fn sample(&mut self) -> LoadSample {
+        self.sys.refresh_cpu_usage();
+        let activity = SystemChecker::snapshot_activity();
+        let background = SystemChecker::activity_between(&self.activity, &activity, self.last_sample.elapsed());
+        self.activity = activity;
+        self.last_sample = Instant::now();
+
+        LoadSample {
+            cpu_percent: SystemChecker::get_cpu_usage(&self.sys),
+            disk_busy_percent: background.disk_busy_percent,
+            pressure: SystemChecker::get_pressure(),
+        }
+    }
+}
+
+impl Default for LoadSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SystemChecker {
-    /// Perform all system checks and return the result
-    pub fn check() -> SystemCheckResult {
+    /// Perform all system checks and return the result.
+    /// `scratch` is what the queued tests will write to the test volume.
+    pub fn check(scratch: ScratchRequirement) -> SystemCheckResult {
         let mut sys = System::new_with_specifics(
             RefreshKind::new()
                 .with_cpu(CpuRefreshKind::everything())
@@ -145,6 +321,8 @@ impl SystemChecker {
         // Sample CPU usage over 3 seconds for accurate average
         // First refresh to initialize
         sys.refresh_all();
+        let activity_start = Self::snapshot_activity();
+        let sampling_started = Instant::now();
         std::thread::sleep(std::time::Duration::from_millis(500));
 
         // Collect CPU samples every 500ms for 2.5 seconds (5 samples)
@@ -157,10 +335,17 @@ impl SystemChecker {
 
         // Final refresh for memory and processes
         sys.refresh_all();
+        let activity =
+            Self::activity_between(&activity_start, &Self::snapshot_activity(), sampling_started.elapsed());
 
         // Calculate average CPU usage
         let cpu_usage_percent = cpu_samples.iter().sum::<f32>() / cpu_samples.len() as f32;
         let available_memory_gb = Self::get_available_memory(&sys);
+        let cpu_temperature_c = read_thermal().temp_c;
+        let pressure = Self::get_pressure();
+        let swap_used_gb = sys.used_swap() as f64 / BYTES_PER_GB;
+        let swap_total_gb = sys.total_swap() as f64 / BYTES_PER_GB;
+        let test_volume_free_gb = Self::get_free_space_gb(&BenchmarkConfig::default().test_dir());
         let power_state = Self::get_power_state();
         let power_plan = Self::get_power_plan();
         let high_cpu_processes = Self::get_high_cpu_processes(&sys);
@@ -168,7 +353,7 @@ impl SystemChecker {
         let mut warnings = Vec::new();
 
         // Check CPU usage
-        if cpu_usage_percent > 50.0 {
+        if cpu_usage_percent > CPU_USAGE_CRITICAL {
             warnings.push(SystemWarning {
                 severity: WarningSeverity::Critical,
                 title: "High CPU Usage".to_string(),
@@ -181,7 +366,7 @@ impl SystemChecker {
                         .to_string(),
                 ),
             });
-        } else if cpu_usage_percent > 20.0 {
+        } else if cpu_usage_percent > CPU_USAGE_WARNING {
             warnings.push(SystemWarning {
                 severity: WarningSeverity::Warning,
                 title: "Elevated CPU Usage".to_string(),
@@ -196,7 +381,7 @@ impl SystemChecker {
         }
 
         // Check available memory
-        if available_memory_gb < 2.0 {
+        if available_memory_gb < LOW_MEMORY_CRITICAL_GB {
             warnings.push(SystemWarning {
                 severity: WarningSeverity::Critical,
                 title: "Very Low Memory".to_string(),
@@ -206,7 +391,7 @@ impl SystemChecker {
                 ),
                 remediation: Some("Close memory-intensive applications before benchmarking.".to_string()),
             });
-        } else if available_memory_gb < 4.0 {
+        } else if available_memory_gb < LOW_MEMORY_WARNING_GB {
             warnings.push(SystemWarning {
                 severity: WarningSeverity::Warning,
                 title: "Low Memory".to_string(),
@@ -218,6 +403,161 @@ impl SystemChecker {
             });
         }
 
+        // Check CPU temperature; a CPU that is hot before the run throttles early in it
+        if let Some(temp) = cpu_temperature_c {
+            if temp >= HOT_TEMP_C {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Critical,
+                    title: "CPU Is Hot".to_string(),
+                    description: format!(
+                        "CPU is at {:.0}°C before the run. It will throttle under load.",
+                        temp
+                    ),
+                    remediation: Some(
+                        "Let the system cool down and check that fans and vents are clear.".to_string(),
+                    ),
+                });
+            } else if temp >= WARM_TEMP_C {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Warning,
+                    title: "CPU Is Warm".to_string(),
+                    description: format!(
+                        "CPU is at {:.0}°C before the run. Sustained tests may throttle.",
+                        temp
+                    ),
+                    remediation: Some("Wait for the CPU to cool down after recent heavy work.".to_string()),
+                });
+            }
+        }
+
+        // Check disk activity; other I/O competes directly with the storage tests
+        if let Some(busy) = activity.disk_busy_percent {
+            let disk = activity.busiest_disk.as_deref().unwrap_or("A disk");
+            if busy >= DISK_BUSY_CRITICAL {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Critical,
+                    title: "Heavy Disk Activity".to_string(),
+                    description: format!(
+                        "{} was busy {:.0}% of the time. Storage results will be unreliable.",
+                        disk, busy
+                    ),
+                    remediation: Some(
+                        "Wait for downloads, updates, backups or indexing to finish.".to_string(),
+                    ),
+                });
+            } else if busy >= DISK_BUSY_WARNING {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Warning,
+                    title: "Disk Activity".to_string(),
+                    description: format!(
+                        "{} was busy {:.0}% of the time. Storage results may be affected.",
+                        disk, busy
+                    ),
+                    remediation: Some("Pause background downloads, syncs or backups.".to_string()),
+                });
+            }
+        }
+
+        // Check swap; swap that is merely in use is harmless, swapping while testing is not
+        match activity.swap_pages_per_sec {
+            Some(pages) if pages >= SWAP_PAGES_CRITICAL => {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Critical,
+                    title: "System Is Swapping".to_string(),
+                    description: format!(
+                        "{:.0} pages per second are moving to and from swap. Memory is overcommitted.",
+                        pages
+                    ),
+                    remediation: Some("Close memory-intensive applications before benchmarking.".to_string()),
+                });
+            }
+            Some(pages) if pages >= SWAP_PAGES_WARNING => {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Warning,
+                    title: "Swap Activity".to_string(),
+                    description: format!(
+                        "{:.0} pages per second are moving to and from swap.",
+                        pages
+                    ),
+                    remediation: Some("Consider closing some applications to free memory.".to_string()),
+                });
+            }
+            _ if swap_total_gb > 0.0 && swap_used_gb / swap_total_gb >= 0.5 => {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Info,
+                    title: "Swap In Use".to_string(),
+                    description: format!(
+                        "{:.1} of {:.1}GB of swap is in use. Memory ran short recently.",
+                        swap_used_gb, swap_total_gb
+                    ),
+                    remediation: None,
+                });
+            }
+            _ => {}
+        }
+
+        // Check resource pressure
+        if let Some(pressure) = pressure {
+            let resources = [
+                ("CPU", pressure.cpu, CPU_PRESSURE_WARNING, CPU_PRESSURE_CRITICAL),
+                ("I/O", pressure.io, IO_PRESSURE_WARNING, IO_PRESSURE_CRITICAL),
+                ("Memory", pressure.memory, MEMORY_PRESSURE_WARNING, MEMORY_PRESSURE_CRITICAL),
+            ];
+            for (resource, stall, warning_at, critical_at) in resources {
+                let Some(stall) = stall else { continue };
+                let severity = if stall >= critical_at {
+                    WarningSeverity::Critical
+                } else if stall >= warning_at {
+                    WarningSeverity::Warning
+                } else {
+                    continue;
+                };
+                warnings.push(SystemWarning {
+                    severity,
+                    title: format!("{} Pressure", resource),
+                    description: format!(
+                        "Tasks were stalled waiting for {} {:.0}% of the last 10 seconds.",
+                        resource, stall
+                    ),
+                    remediation: Some("Wait for other workloads on this system to finish.".to_string()),
+                });
+            }
+        }
+
+        // Check free space where test files are written; a full disk fails tests mid-run
+        if let Some(free_gb) = test_volume_free_gb {
+            let required_gb = scratch.selected_bytes as f64 / BYTES_PER_GB;
+            if !fits_scratch(free_gb, scratch.selected_bytes) {
+                let remediation = if fits_scratch(free_gb, scratch.lite_bytes) {
+                    format!(
+                        "Free up disk space, or run in Safe Mode, whose smaller tests need {:.1}GB.",
+                        scratch.lite_bytes as f64 / BYTES_PER_GB
+                    )
+                } else {
+                    "Free up disk space before benchmarking.".to_string()
+                };
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Critical,
+                    title: "Not Enough Disk Space".to_string(),
+                    description: format!(
+                        "Tests write up to {:.1}GB of scratch files but only {:.1}GB is free on the test volume.",
+                        required_gb, free_gb
+                    ),
+                    remediation: Some(remediation),
+                });
+            } else if free_gb - required_gb < LOW_DISK_SPACE_GB {
+                warnings.push(SystemWarning {
+                    severity: WarningSeverity::Warning,
+                    title: "Low Disk Space".to_string(),
+                    description: format!(
+                        "Only {:.1}GB free on the test volume. Nearly full SSDs write more slowly.",
+                        free_gb
+                    ),
+                    remediation: Some("Consider freeing up disk space for representative results.".to_string()),
+                });
+            }
+        }
+
         // Check power state
         match power_state {
             PowerState::OnBattery(pct) if pct < 50 => {
@@ -264,10 +604,10 @@ impl SystemChecker {
                     severity: WarningSeverity::Critical,
                     title: "Power Saver Mode".to_string(),
                     description: "Power Saver mode limits CPU frequency significantly.".to_string(),
-                    remediation: Some(
-                        "Switch to High Performance or Balanced power plan: \
-                         Settings > System > Power & battery > Power mode".to_string(),
-                    ),
+                    remediation: Some(format!(
+                        "Switch to High Performance or Balanced power plan: {}",
+                        POWER_MODE_SETTING
+                    )),
                 });
             }
             PowerPlan::Balanced => {
@@ -275,10 +615,10 @@ impl SystemChecker {
                     severity: WarningSeverity::Info,
                     title: "Balanced Power Plan".to_string(),
                     description: "Balanced mode may limit peak CPU performance.".to_string(),
-                    remediation: Some(
-                        "For maximum performance, consider switching to High Performance mode."
-                            .to_string(),
-                    ),
+                    remediation: Some(format!(
+                        "For maximum performance, consider switching to High Performance mode: {}",
+                        POWER_MODE_SETTING
+                    )),
                 });
             }
             _ => {}
@@ -321,6 +661,13 @@ impl SystemChecker {
         SystemCheckResult {
             cpu_usage_percent,
             available_memory_gb,
+            cpu_temperature_c,
+            activity,
+            pressure,
+            swap_used_gb,
+            swap_total_gb,
+            test_volume_free_gb,
+            scratch,
             power_state,
             power_plan,
             high_cpu_processes,
@@ -341,6 +688,44 @@ impl SystemChecker {
         sys.available_memory() as f64 / (1024.0 * 1024.0 * 1024.0)
     }
 
+    #[cfg(target_os = "linux")]
+    fn snapshot_activity() -> ActivityStart {
+        ActivitySnapshot::read(&FsTree::host())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn snapshot_activity() -> ActivityStart {}
+
+    #[cfg(target_os = "linux")]
+    fn activity_between(start: &ActivityStart, end: &ActivityStart, elapsed: Duration) -> BackgroundActivity {
+        end.since(start, elapsed)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn activity_between(_start: &ActivityStart, _end: &ActivityStart, _elapsed: Duration) -> BackgroundActivity {
+        BackgroundActivity::default()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn get_pressure() -> Option<PressureStall> {
+        read_pressure(&FsTree::host())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn get_pressure() -> Option<PressureStall> {
+        None
+    }
+
+    fn get_free_space_gb(path: &Path) -> Option<f64> {
+        let volumes: Vec<(PathBuf, u64)> = Disks::new_with_refreshed_list()
+            .iter()
+            .map(|disk| (disk.mount_point().to_path_buf(), disk.available_space()))
+            .collect();
+        // Resolve symlinks such as macOS's /var -> /private/var before matching mounts
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        free_space_on(&path, &volumes).map(|bytes| bytes as f64 / BYTES_PER_GB)
+    }
+
     #[cfg(windows)]
     fn get_power_state() -> PowerState {
         let mut status = SYSTEM_POWER_STATUS::default();
@@ -363,7 +748,12 @@ impl SystemChecker {
         PowerState::Unknown
     }
 
-    #[cfg(not(windows))]
+    #[cfg(target_os = "linux")]
+    fn get_power_state() -> PowerState {
+        detect_power_state(&FsTree::sysfs())
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
     fn get_power_state() -> PowerState {
         PowerState::Unknown
     }
@@ -400,7 +790,12 @@ impl SystemChecker {
         PowerPlan::Unknown("Unknown".to_string())
     }
 
-    #[cfg(not(windows))]
+    #[cfg(target_os = "linux")]
+    fn get_power_plan() -> PowerPlan {
+        detect_power_plan(&FsTree::sysfs())
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
     fn get_power_plan() -> PowerPlan {
         PowerPlan::Unknown("N/A".to_string())
     }
@@ -441,13 +836,27 @@ impl SystemChecker {
     }
 }
 
+/// Whether `free_gb` holds `scratch_bytes` with headroom to spare
+fn fits_scratch(free_gb: f64, scratch_bytes: u64) -> bool {
+    free_gb >= scratch_bytes as f64 / BYTES_PER_GB + SCRATCH_HEADROOM_GB
+}
+
+/// Available bytes on the volume mounted deepest along `path`
+fn free_space_on(path: &Path, volumes: &[(PathBuf, u64)]) -> Option<u64> {
+    volumes
+        .iter()
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())
+        .map(|(_, available)| *available)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_system_check() {
-        let result = SystemChecker::check();
+        let result = SystemChecker::check(ScratchRequirement::default());
 
         // Basic sanity checks
         assert!(result.cpu_usage_percent >= 0.0);
@@ -455,6 +864,26 @@ mod tests {
         assert!(result.available_memory_gb >= 0.0);
     }
 
+    #[test]
+    fn test_free_space_on_deepest_mount() {
+        let volumes = [
+            (PathBuf::from("/"), 50),
+            (PathBuf::from("/tmp"), 8),
+            (PathBuf::from("/tmpfs"), 1),
+        ];
+        assert_eq!(free_space_on(Path::new("/tmp/bench"), &volumes), Some(8));
+        assert_eq!(free_space_on(Path::new("/home/user"), &volumes), Some(50));
+        assert_eq!(free_space_on(Path::new("/home/user"), &[]), None);
+    }
+
+    #[test]
+    fn test_scratch_space() {
+        // Exactly the requirement leaves no headroom
+        assert!(!fits_scratch(1.0, 1 << 30));
+        assert!(fits_scratch(1.5, 1 << 30));
+        assert!(fits_scratch(0.5, 0));
+    }
+
     #[test]
     fn test_warning_severity() {
         assert_eq!(WarningSeverity::Info.label(), "Info");
@@ -1,7 +1,23 @@
-use raw_cpuid::CpuId;
+use raw_cpuid::{CacheType, CpuId, CpuIdReaderNative, ExtendedFeatures, FeatureInfo, Hypervisor};
 use sysinfo::System;
 
-use crate::models::{CpuInfo, GpuInfo, MemoryInfo, OsInfo, StorageInfo, StorageType, SystemInfo};
+#[cfg(target_os = "linux")]
+use super::cpu_topology::detect_topology;
+#[cfg(target_os = "linux")]
+use super::gpu::detect_gpu;
+#[cfg(target_os = "linux")]
+use super::smbios::{memory_modules, populated_channels};
+#[cfg(target_os = "linux")]
+use super::storage::{detect_storage, Mount};
+#[cfg(target_os = "linux")]
+use super::fs_tree::FsTree;
+#[cfg(target_os = "linux")]
+use super::virtualization::detect_virtualization;
+#[cfg(not(target_os = "linux"))]
+use super::hypervisor::identify_hypervisor;
+#[cfg(not(target_os = "linux"))]
+use crate::models::StorageType;
+use crate::models::{CpuInfo, GpuInfo, MemoryInfo, OsInfo, StorageInfo, SystemInfo, VirtualizationInfo};
 
 pub struct SystemInfoCollector;
 
@@ -17,6 +33,7 @@ impl SystemInfoCollector {
             storage: Self::collect_storage_info(&sys),
             gpu: Self::collect_gpu_info(),
             os: Self::collect_os_info(),
+            virtualization: Self::collect_virtualization(),
         }
     }
 
@@ -41,63 +58,226 @@ impl SystemInfoCollector {
         let cores = sys.physical_core_count().unwrap_or(1) as u32;
         let threads = sys.cpus().len() as u32;
 
-        let base_frequency_mhz = sys
-            .cpus()
-            .first()
-            .map(|c| c.frequency() as u32)
-            .unwrap_or(0);
+        // Leaf 0x16 reports nominal and boost clocks on recent Intel CPUs; sysinfo only has the current clock
+        let frequency = cpuid.get_processor_frequency_info();
+        let base_frequency_mhz = frequency
+            .as_ref()
+            .map(|f| f.processor_base_frequency() as u32)
+            .filter(|&mhz| mhz > 0)
+            .unwrap_or_else(|| sys.cpus().first().map(|c| c.frequency() as u32).unwrap_or(0));
+        let max_frequency_mhz = frequency
+            .map(|f| f.processor_max_frequency() as u32)
+            .filter(|&mhz| mhz > 0);
 
-        let cache_l3_kb = cpuid
-            .get_cache_parameters()
-            .and_then(|mut caches| {
-                caches.find(|c| c.level() == 3).map(|c| {
+        let mut cpu = CpuInfo {
+            name,
+            vendor,
+            cores,
+            threads,
+            base_frequency_mhz,
+            max_frequency_mhz,
+            cache_l3_kb: Self::cache_size_kb(&cpuid, 3, CacheType::Unified),
+            cache_l1d_kb: Self::cache_size_kb(&cpuid, 1, CacheType::Data),
+            cache_l2_kb: Self::cache_size_kb(&cpuid, 2, CacheType::Unified),
+            extensions: Self::collect_extensions(&cpuid),
+            smt_enabled: Some(threads > cores),
+            core_groups: Vec::new(),
+        };
+        Self::collect_cpu_topology(&mut cpu);
+        cpu
+    }
+
+    fn cache_size_kb(cpuid: &CpuId<CpuIdReaderNative>, level: u8, cache_type: CacheType) -> Option<u32> {
+        cpuid.get_cache_parameters().and_then(|mut caches| {
+            caches
+                .find(|c| c.level() == level && c.cache_type() == cache_type)
+                .map(|c| {
                     let ways = c.associativity();
                     let partitions = c.physical_line_partitions();
                     let line_size = c.coherency_line_size();
                     let sets = c.sets();
                     ((ways * partitions * line_size * sets) / 1024) as u32
                 })
-            });
+        })
+    }
 
-        CpuInfo {
-            name,
-            vendor,
-            cores,
-            threads,
-            base_frequency_mhz,
-            max_frequency_mhz: None,
-            cache_l3_kb,
+    /// Extensions that compilers, compressors and hashing code pick at runtime
+    fn collect_extensions(cpuid: &CpuId<CpuIdReaderNative>) -> Vec<String> {
+        let features = cpuid.get_feature_info();
+        let extended = cpuid.get_extended_feature_info();
+        let has = |f: fn(&FeatureInfo) -> bool| features.as_ref().is_some_and(f);
+        let has_extended = |f: fn(&ExtendedFeatures) -> bool| extended.as_ref().is_some_and(f);
+
+        [
+            ("SSE4.2", has(FeatureInfo::has_sse42)),
+            ("AES", has(FeatureInfo::has_aesni)),
+            ("AVX", has(FeatureInfo::has_avx)),
+            ("FMA", has(FeatureInfo::has_fma)),
+            ("AVX2", has_extended(ExtendedFeatures::has_avx2)),
+            ("BMI2", has_extended(ExtendedFeatures::has_bmi2)),
+            ("SHA", has_extended(ExtendedFeatures::has_sha)),
+            ("AVX-512F", has_extended(ExtendedFeatures::has_avx512f)),
+            ("AVX-512BW", has_extended(ExtendedFeatures::has_avx512bw)),
+            ("AVX-512VNNI", has_extended(ExtendedFeatures::has_avx512vnni)),
+            ("AVX-VNNI", has_extended(ExtendedFeatures::has_avx_vnni)),
+        ]
+        .into_iter()
+        .filter(|(_, supported)| *supported)
+        .map(|(name, _)| name.to_string())
+        .collect()
+    }
+
+    /// Hypervisor named in the CPUID hypervisor leaf
+    fn cpuid_hypervisor() -> Option<String> {
+        let info = CpuId::new().get_hypervisor_info()?;
+        let name = match info.identify() {
+            Hypervisor::KVM => "KVM",
+            Hypervisor::HyperV => "Hyper-V",
+            Hypervisor::VMware => "VMware",
+            Hypervisor::Xen => "Xen",
+            Hypervisor::QEMU => "QEMU",
+            Hypervisor::Bhyve => "bhyve",
+            Hypervisor::QNX => "QNX",
+            Hypervisor::ACRN => "ACRN",
+            Hypervisor::Unknown(..) => "Unknown",
+        };
+        Some(name.to_string())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn collect_virtualization() -> VirtualizationInfo {
+        detect_virtualization(&FsTree::host(), Self::cpuid_hypervisor(), std::env::var("container").ok())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn collect_virtualization() -> VirtualizationInfo {
+        let (vendor, product) = Self::smbios_system();
+        VirtualizationInfo {
+            hypervisor: identify_hypervisor(Self::cpuid_hypervisor(), &vendor, &product),
+            ..Default::default()
         }
     }
 
+    /// SMBIOS system manufacturer and product name, as Windows keeps them in the registry
+    #[cfg(windows)]
+    fn smbios_system() -> (String, String) {
+        use windows::core::{w, PCWSTR};
+        use windows::Win32::Foundation::ERROR_SUCCESS;
+        use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ};
+
+        let read = |value: PCWSTR| {
+            let mut buffer = [0u16; 256];
+            let mut size = std::mem::size_of_val(&buffer) as u32;
+            let status = unsafe {
+                RegGetValueW(
+                    HKEY_LOCAL_MACHINE,
+                    w!(r"HARDWARE\DESCRIPTION\System\BIOS"),
+                    value,
+                    RRF_RT_REG_SZ,
+                    None,
+                    Some(buffer.as_mut_ptr().cast()),
+                    Some(&mut size),
+                )
+            };
+            if status != ERROR_SUCCESS {
+                return String::new();
+            }
+            // The size includes the terminating null
+            let len = (size as usize / 2).saturating_sub(1);
+            String::from_utf16_lossy(&buffer[..len]).trim().to_string()
+        };
+        (read(w!("SystemManufacturer")), read(w!("SystemProductName")))
+    }
+
+    #[cfg(not(any(windows, target_os = "linux")))]
+    fn smbios_system() -> (String, String) {
+        (String::new(), String::new())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn collect_cpu_topology(cpu: &mut CpuInfo) {
+        let topology = detect_topology(&FsTree::sysfs());
+        if let Some(base) = topology.base_frequency_mhz {
+            cpu.base_frequency_mhz = base;
+        }
+        cpu.max_frequency_mhz = topology.max_frequency_mhz.or(cpu.max_frequency_mhz);
+        cpu.smt_enabled = topology.smt_enabled.or(cpu.smt_enabled);
+        cpu.core_groups = topology.core_groups;
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn collect_cpu_topology(_cpu: &mut CpuInfo) {}
+
+    #[cfg(target_os = "linux")]
+    fn collect_memory_info(sys: &System) -> MemoryInfo {
+        // The raw table is readable by root only; without it only the total is known
+        let modules = FsTree::sysfs()
+            .read_bytes("firmware/dmi/tables/DMI")
+            .map(|table| memory_modules(&table))
+            .unwrap_or_default();
+
+        MemoryInfo {
+            total_bytes: sys.total_memory(),
+            // Every module runs at the speed of the slowest
+            speed_mhz: modules.iter().filter_map(|m| m.configured_speed_mhz.or(m.speed_mhz)).min(),
+            memory_type: modules.iter().find_map(|m| m.memory_type.clone()),
+            channels: populated_channels(&modules),
+            modules,
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
     fn collect_memory_info(sys: &System) -> MemoryInfo {
         MemoryInfo {
             total_bytes: sys.total_memory(),
             speed_mhz: None,
             memory_type: None,
+            modules: Vec::new(),
+            channels: None,
         }
     }
 
+    #[cfg(target_os = "linux")]
+    fn collect_storage_info(_sys: &System) -> Vec<StorageInfo> {
+        let mounts: Vec<Mount> = sysinfo::Disks::new_with_refreshed_list()
+            .iter()
+            .map(|disk| Mount {
+                device: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                total_bytes: disk.total_space(),
+            })
+            .collect();
+
+        detect_storage(&FsTree::sysfs(), &mounts)
+    }
+
+    #[cfg(not(target_os = "linux"))]
     fn collect_storage_info(sys: &System) -> Vec<StorageInfo> {
         sysinfo::Disks::new_with_refreshed_list()
             .iter()
             .map(|disk| {
                 let name = disk.name().to_string_lossy().to_string();
                 let device_type = Self::detect_storage_type(&name, disk);
+                let mount_point = disk.mount_point().to_string_lossy().to_string();
 
                 StorageInfo {
                     name: if name.is_empty() {
-                        disk.mount_point().to_string_lossy().to_string()
+                        mount_point.clone()
                     } else {
                         name
                     },
                     device_type,
                     capacity_bytes: disk.total_space(),
+                    mount_point: Some(mount_point),
+                    file_system: Some(disk.file_system().to_string_lossy().to_string()),
+                    ..Default::default()
                 }
             })
             .collect()
     }
 
+    #[cfg(not(target_os = "linux"))]
     fn detect_storage_type(name: &str, disk: &sysinfo::Disk) -> StorageType {
         let name_lower = name.to_lowercase();
 
@@ -121,9 +301,14 @@ impl SystemInfoCollector {
         }
     }
 
+    #[cfg(target_os = "linux")]
+    fn collect_gpu_info() -> Option<GpuInfo> {
+        detect_gpu(&FsTree::sysfs())
+    }
+
+    #[cfg(not(target_os = "linux"))]
     fn collect_gpu_info() -> Option<GpuInfo> {
-        // GPU detection is platform-specific and complex
-        // For now, return None - will be implemented in Phase 5
+        // Only Linux detection is implemented so far
         None
     }
 
@@ -0,0 +1,241 @@
+//! CPU temperature and clock sampling
+//!
+//! A `ThermalMonitor` samples in the background while a test runs and sums the
+//! readings up as throttling evidence. Readings come from sysfs on Linux; other
+//! platforms don't expose them without elevated rights, so they read nothing.
+
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+#[cfg(target_os = "linux")]
+use super::fs_tree::FsTree;
+use crate::models::ThermalSummary;
+
+/// Idle temperature above which results will suffer from the start
+pub const WARM_TEMP_C: f64 = 80.0;
+pub const HOT_TEMP_C: f64 = 90.0;
+
+/// Temperature from which a sub-base clock counts as thermal throttling
+const THROTTLE_TEMP_C: f64 = 85.0;
+
+/// Each sample reads a clock file per CPU, so keep them sparse; throttling that
+/// matters lasts far longer than this
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Thermal zones that measure the CPU package, in order of preference
+#[cfg(target_os = "linux")]
+const CPU_ZONES: &[&str] = &["x86_pkg_temp", "cpu-thermal", "cpu_thermal", "soc_thermal", "TCPU"];
+
+/// hwmon drivers for CPU sensors, used when no thermal zone matches (e.g. AMD)
+#[cfg(target_os = "linux")]
+const CPU_HWMONS: &[&str] = &["k10temp", "zenpower", "coretemp"];
+
+/// One sample of CPU temperature and clocks
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThermalReading {
+    pub temp_c: Option<f64>,
+    /// Clock of the fastest core
+    pub max_core_mhz: Option<u32>,
+    /// Throttle events counted by the CPU since boot
+    pub throttle_count: Option<u64>,
+}
+
+impl ThermalReading {
+    /// A reading with every value present
+    #[cfg(test)]
+    pub(crate) fn test_fixture(temp_c: f64, max_core_mhz: u32, throttle_count: u64) -> Self {
+        Self {
+            temp_c: Some(temp_c),
+            max_core_mhz: Some(max_core_mhz),
+            throttle_count: Some(throttle_count),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn read_thermal() -> ThermalReading {
+    read_sysfs(&FsTree::sysfs())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_thermal() -> ThermalReading {
+    ThermalReading::default()
+}
+
+#[cfg(target_os = "linux")]
+fn read_sysfs(sysfs: &FsTree) -> ThermalReading {
+    let millidegrees = |path: String| sysfs.read(path)?.parse::<f64>().ok().map(|m| m / 1000.0);
+
+    let zones = sysfs.list("class/thermal");
+    let zone_temp = CPU_ZONES.iter().find_map(|wanted| {
+        zones
+            .iter()
+            .filter(|zone| zone.starts_with("thermal_zone"))
+            .find(|zone| sysfs.read(format!("class/thermal/{}/type", zone)).as_deref() == Some(*wanted))
+            .and_then(|zone| millidegrees(format!("class/thermal/{}/temp", zone)))
+    });
+    let temp_c = zone_temp.or_else(|| {
+        let hwmons = sysfs.list("class/hwmon");
+        CPU_HWMONS.iter().find_map(|wanted| {
+            hwmons
+                .iter()
+                .find(|hwmon| sysfs.read(format!("class/hwmon/{}/name", hwmon)).as_deref() == Some(*wanted))
+                .and_then(|hwmon| millidegrees(format!("class/hwmon/{}/temp1_input", hwmon)))
+        })
+    });
+
+    let cpus: Vec<String> = sysfs
+        .list("devices/system/cpu")
+        .into_iter()
+        .filter(|cpu| cpu.strip_prefix("cpu").is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit())))
+        .collect();
+    let max_core_mhz = cpus
+        .iter()
+        .filter_map(|cpu| sysfs.read_u64(format!("devices/system/cpu/{}/cpufreq/scaling_cur_freq", cpu)))
+        .max()
+        .map(|khz| (khz / 1000) as u32);
+    let throttle_counts: Vec<u64> = cpus
+        .iter()
+        .filter_map(|cpu| sysfs.read_u64(format!("devices/system/cpu/{}/thermal_throttle/core_throttle_count", cpu)))
+        .collect();
+
+    ThermalReading {
+        temp_c,
+        max_core_mhz,
+        throttle_count: (!throttle_counts.is_empty()).then(|| throttle_counts.iter().sum()),
+    }
+}
+
+/// Sum up readings taken during a test; None when nothing could be read
+pub fn summarize(readings: &[ThermalReading], base_frequency_mhz: u32) -> Option<ThermalSummary> {
+    let temps: Vec<f64> = readings.iter().filter_map(|r| r.temp_c).collect();
+    let clocks: Vec<u32> = readings.iter().filter_map(|r| r.max_core_mhz).collect();
+    if temps.is_empty() && clocks.is_empty() {
+        return None;
+    }
+
+    // Running below the guaranteed base clock while hot is throttling by definition
+    let throttled = readings
+        .iter()
+        .filter(|r| {
+            let hot = r.temp_c.is_some_and(|t| t >= THROTTLE_TEMP_C);
+            let slow = r.max_core_mhz.is_some_and(|mhz| (mhz as f64) < base_frequency_mhz as f64 * 0.95);
+            hot && slow
+        })
+        .count();
+
+    let counts: Vec<u64> = readings.iter().filter_map(|r| r.throttle_count).collect();
+    let throttle_events = match (counts.first(), counts.last()) {
+        (Some(first), Some(last)) => Some(last.saturating_sub(*first)),
+        _ => None,
+    };
+
+    Some(ThermalSummary {
+        samples: readings.len() as u32,
+        max_temp_c: temps.iter().copied().reduce(f64::max),
+        avg_temp_c: (!temps.is_empty()).then(|| temps.iter().sum::<f64>() / temps.len() as f64),
+        min_frequency_mhz: clocks.iter().copied().min(),
+        avg_frequency_mhz: (!clocks.is_empty())
+            .then(|| (clocks.iter().map(|&c| c as u64).sum::<u64>() / clocks.len() as u64) as u32),
+        throttled_fraction: throttled as f64 / readings.len() as f64,
+        throttle_events,
+    })
+}
+
+/// Background sampler for the duration of one test
+pub struct ThermalMonitor {
+    stop: Sender<()>,
+    handle: JoinHandle<Vec<ThermalReading>>,
+    base_frequency_mhz: u32,
+}
+
+impl ThermalMonitor {
+    pub fn start(base_frequency_mhz: u32) -> Self {
+        let (stop, stopped) = channel();
+
+        let handle = thread::spawn(move || {
+            let mut readings = vec![read_thermal()];
+            // Sleeps on the channel, so stopping doesn't wait out an interval
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(SAMPLE_INTERVAL) {
+                readings.push(read_thermal());
+            }
+            readings.push(read_thermal());
+            readings
+        });
+
+        Self {
+            stop,
+            handle,
+            base_frequency_mhz,
+        }
+    }
+
+    pub fn finish(self) -> Option<ThermalSummary> {
+        let _ = self.stop.send(());
+        let readings = self.handle.join().ok()?;
+        summarize(&readings, self.base_frequency_mhz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn test_summarize_throttling() {
+        let readings = [
+            ThermalReading::test_fixture(70.0, 4800, 10),
+            ThermalReading::test_fixture(92.0, 2200, 10),
+            ThermalReading::test_fixture(96.0, 1900, 14),
+            ThermalReading::test_fixture(94.0, 3600, 14),
+        ];
+
+        let summary = summarize(&readings, 3000).unwrap();
+        assert_eq!(summary.samples, 4);
+        assert_eq!(summary.max_temp_c, Some(96.0));
+        assert_eq!(summary.avg_temp_c, Some(88.0));
+        assert_eq!(summary.min_frequency_mhz, Some(1900));
+        assert_eq!(summary.avg_frequency_mhz, Some(3125));
+        assert_eq!(summary.throttled_fraction, 0.5);
+        assert_eq!(summary.throttle_events, Some(4));
+        assert!(summary.is_throttled());
+    }
+
+    #[test]
+    fn test_summarize_cool_run() {
+        let readings = [ThermalReading::test_fixture(55.0, 4500, 0), ThermalReading::test_fixture(62.0, 4400, 0)];
+        let summary = summarize(&readings, 3000).unwrap();
+        assert!(!summary.is_throttled());
+
+        assert!(summarize(&[ThermalReading::default()], 3000).is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_sysfs() {
+        use crate::core::fs_tree::FakeFsTree;
+
+        let fake = FakeFsTree::new(&[
+            ("class/thermal/thermal_zone0/type", "acpitz\n"),
+            ("class/thermal/thermal_zone0/temp", "27800\n"),
+            ("class/thermal/thermal_zone1/type", "x86_pkg_temp\n"),
+            ("class/thermal/thermal_zone1/temp", "81000\n"),
+            ("devices/system/cpu/cpu0/cpufreq/scaling_cur_freq", "3400000\n"),
+            ("devices/system/cpu/cpu0/thermal_throttle/core_throttle_count", "3\n"),
+            ("devices/system/cpu/cpu1/cpufreq/scaling_cur_freq", "4100000\n"),
+            ("devices/system/cpu/cpu1/thermal_throttle/core_throttle_count", "2\n"),
+            ("devices/system/cpu/cpufreq/boost", "1\n"),
+        ]);
+        assert_eq!(read_sysfs(&fake.tree()), ThermalReading::test_fixture(81.0, 4100, 5));
+
+        // AMD CPUs report through hwmon instead
+        let amd = FakeFsTree::new(&[
+            ("class/hwmon/hwmon0/name", "nvme\n"),
+            ("class/hwmon/hwmon1/name", "k10temp\n"),
+            ("class/hwmon/hwmon1/temp1_input", "45250\n"),
+        ]);
+        assert_eq!(read_sysfs(&amd.tree()).temp_c, Some(45.25));
+    }
+}
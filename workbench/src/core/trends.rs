@@ -0,0 +1,196 @@
+//! Performance trends across benchmark history.
+//!
+//! Groups historic results per test and per machine so they can be plotted over time.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::models::BenchmarkRun;
+
+/// A single measurement of a test in a historic run
+#[derive(Debug, Clone)]
+pub struct TrendPoint {
+    /// When the run was recorded
+    pub timestamp: DateTime<Utc>,
+    /// Primary result value
+    pub value: f64,
+    /// Lowest value observed across iterations
+    pub min: f64,
+    /// Highest value observed across iterations
+    pub max: f64,
+    /// Notes and tags attached to the run, if any
+    pub annotation: Option<String>,
+}
+
+impl TrendPoint {
+    /// Whether this point carries a usable min-max range
+    pub fn has_range(&self) -> bool {
+        self.max > self.min
+    }
+}
+
+/// All measurements of one test on one machine, oldest first
+#[derive(Debug, Clone)]
+pub struct TrendSeries {
+    pub machine_name: String,
+    pub points: Vec<TrendPoint>,
+}
+
+/// Trend of a single test across all machines in the history
+#[derive(Debug, Clone)]
+pub struct TestTrend {
+    pub test_id: String,
+    pub name: String,
+    pub unit: String,
+    pub series: Vec<TrendSeries>,
+}
+
+/// Builds plottable trends from saved benchmark runs
+pub struct TrendAnalyzer;
+
+impl TrendAnalyzer {
+    /// List the tests present in the history as (test_id, name), sorted by name
+    pub fn available_tests(runs: &[BenchmarkRun]) -> Vec<(String, String)> {
+        let mut tests: BTreeMap<String, String> = BTreeMap::new();
+        for run in runs {
+            for result in run.results.iter() {
+                tests
+                    .entry(result.test_id.clone())
+                    .or_insert_with(|| result.name.clone());
+            }
+        }
+
+        let mut tests: Vec<(String, String)> = tests.into_iter().collect();
+        tests.sort_by(|a, b| a.1.cmp(&b.1));
+        tests
+    }
+
+    /// Build the trend for a test, with one series per machine.
+    /// Returns None if no run contains the test.
+    pub fn build(runs: &[BenchmarkRun], test_id: &str) -> Option<TestTrend> {
+        let mut name = None;
+        let mut unit = String::new();
+        let mut by_machine: BTreeMap<String, Vec<TrendPoint>> = BTreeMap::new();
+
+        for run in runs {
+            let Some(result) = run.results.iter().find(|r| r.test_id == test_id) else {
+                continue;
+            };
+
+            if name.is_none() {
+                name = Some(result.name.clone());
+                unit = result.unit.clone();
+            }
+
+            by_machine
+                .entry(run.machine_name.clone())
+                .or_default()
+                .push(TrendPoint {
+                    timestamp: run.timestamp,
+                    value: result.value,
+                    min: result.details.min,
+                    max: result.details.max,
+                    annotation: Self::annotation(run),
+                });
+        }
+
+        let series = by_machine
+            .into_iter()
+            .map(|(machine_name, mut points)| {
+                points.sort_by_key(|p| p.timestamp);
+                TrendSeries { machine_name, points }
+            })
+            .collect();
+
+        Some(TestTrend {
+            test_id: test_id.to_string(),
+            name: name?,
+            unit,
+            series,
+        })
+    }
+
+    /// Combine tags and notes into a single marker label
+    fn annotation(run: &BenchmarkRun) -> Option<String> {
+        let notes = run
+            .notes
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty());
+
+        match (run.tags.is_empty(), notes) {
+            (true, None) => None,
+            (true, Some(notes)) => Some(notes.to_string()),
+            (false, None) => Some(format!("[{}]", run.tags.join(", "))),
+            (false, Some(notes)) => Some(format!("[{}] {}", run.tags.join(", "), notes)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SystemInfo, TestDetails, TestResult};
+    use chrono::Duration;
+
+    fn create_run(machine: &str, days_ago: i64, value: f64) -> BenchmarkRun {
+        let mut run = BenchmarkRun::new(
+            machine.to_string(),
+            SystemInfo::test_fixture(machine),
+        );
+        run.timestamp = Utc::now() - Duration::days(days_ago);
+        run.results.project_operations.push(TestResult {
+            test_id: "random_read".to_string(),
+            name: "Random Read (4KB)".to_string(),
+            description: String::new(),
+            value,
+            unit: "ms".to_string(),
+            details: TestDetails {
+                min: value * 0.5,
+                max: value * 2.0,
+                ..Default::default()
+            },
+        });
+        run
+    }
+
+    #[test]
+    fn test_series_grouped_by_machine_and_sorted() {
+        // History is stored newest first
+        let runs = vec![
+            create_run("PC-A", 1, 3.0),
+            create_run("PC-B", 2, 5.0),
+            create_run("PC-A", 3, 1.0),
+        ];
+
+        let trend = TrendAnalyzer::build(&runs, "random_read").unwrap();
+        assert_eq!(trend.unit, "ms");
+        assert_eq!(trend.series.len(), 2);
+
+        let pc_a = &trend.series[0];
+        assert_eq!(pc_a.machine_name, "PC-A");
+        assert_eq!(pc_a.points.len(), 2);
+        assert!(pc_a.points[0].timestamp < pc_a.points[1].timestamp);
+        assert_eq!(pc_a.points[0].value, 1.0);
+        assert!(pc_a.points[0].has_range());
+
+        assert!(TrendAnalyzer::build(&runs, "missing_test").is_none());
+        assert_eq!(TrendAnalyzer::available_tests(&runs).len(), 1);
+    }
+
+    #[test]
+    fn test_annotation() {
+        let mut run = create_run("PC-A", 0, 1.0);
+        assert_eq!(TrendAnalyzer::annotation(&run), None);
+
+        run.notes = Some("New NVMe driver".to_string());
+        assert_eq!(TrendAnalyzer::annotation(&run).as_deref(), Some("New NVMe driver"));
+
+        run.tags = vec!["driver".to_string()];
+        assert_eq!(
+            TrendAnalyzer::annotation(&run).as_deref(),
+            Some("[driver] New NVMe driver")
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Linux hypervisor, container and cgroup limit detection
+//!
+//! Paths are read relative to the filesystem root (`/proc`, `/sys`, marker
+//! files such as `/.dockerenv`), so tests can point it at a fake tree.
+
+use super::fs_tree::FsTree;
+use super::hypervisor::identify_hypervisor;
+use crate::models::VirtualizationInfo;
+
+/// cgroup v1 reports "no limit" as a page-aligned i64::MAX
+const UNLIMITED_MEMORY: u64 = 1 << 60;
+
+/// `cpuid_hypervisor` comes from CPUID and `container_env` from the `container`
+/// variable that systemd-nspawn, podman and LXC set for init
+pub fn detect_virtualization(
+    root: &FsTree,
+    cpuid_hypervisor: Option<String>,
+    container_env: Option<String>,
+) -> VirtualizationInfo {
+    let (cpu_quota, memory_limit_bytes) = cgroup_limits(root);
+
+    VirtualizationInfo {
+        hypervisor: detect_hypervisor(root, cpuid_hypervisor),
+        container: detect_container(root, container_env),
+        cpu_quota,
+        memory_limit_bytes,
+    }
+}
+
+fn detect_hypervisor(root: &FsTree, cpuid_hypervisor: Option<String>) -> Option<String> {
+    // WSL2 is a Hyper-V VM, but one that shares a physical desktop
+    if root
+        .read("proc/sys/kernel/osrelease")
+        .is_some_and(|release| release.to_lowercase().contains("microsoft"))
+    {
+        return Some("WSL2".to_string());
+    }
+
+    // Xen PV guests have no DMI; the control domain names itself in its capabilities
+    if cpuid_hypervisor.as_deref() == Some("Xen")
+        && root.exists("proc/xen/capabilities")
+        && !root.read("proc/xen/capabilities").unwrap_or_default().contains("control_d")
+    {
+        return cpuid_hypervisor;
+    }
+
+    let vendor = root.read("sys/class/dmi/id/sys_vendor").unwrap_or_default();
+    let product = root.read("sys/class/dmi/id/product_name").unwrap_or_default();
+    identify_hypervisor(cpuid_hypervisor, &vendor, &product)
+}
+
+fn detect_container(root: &FsTree, container_env: Option<String>) -> Option<String> {
+    if root.exists(".dockerenv") {
+        return Some("docker".to_string());
+    }
+    if root.exists("run/.containerenv") {
+        return Some("podman".to_string());
+    }
+    if let Some(runtime) = container_env.filter(|c| !c.is_empty()) {
+        return Some(runtime);
+    }
+
+    // Runtimes that leave no marker file still name the cgroup
+    let cgroup = root.read("proc/1/cgroup").unwrap_or_default();
+    ["kubepods", "docker", "lxc"]
+        .iter()
+        .find(|runtime| cgroup.contains(*runtime))
+        .map(|runtime| match *runtime {
+            "kubepods" => "kubernetes".to_string(),
+            other => other.to_string(),
+        })
+}
+
+/// CPU quota and memory limit of this process's cgroup and its ancestors
+fn cgroup_limits(root: &FsTree) -> (Option<f64>, Option<u64>) {
+    let membership = root.read("proc/self/cgroup").unwrap_or_default();
+
+    // cgroup v2 has a single "0::/path" line
+    if let Some(path) = membership.lines().find_map(|line| line.strip_prefix("0::")) {
+        let mut cpu_quota: Option<f64> = None;
+        let mut memory_limit: Option<u64> = None;
+        for dir in ancestors(path) {
+            let dir = format!("sys/fs/cgroup{}", dir);
+            if let Some(quota) = root.read(format!("{}/cpu.max", dir)).and_then(|max| parse_cpu_max(&max)) {
+                cpu_quota = Some(cpu_quota.map_or(quota, |q| q.min(quota)));
+            }
+            if let Some(limit) = root.read_u64(format!("{}/memory.max", dir)) {
+                memory_limit = Some(memory_limit.map_or(limit, |l| l.min(limit)));
+            }
+        }
+        return (cpu_quota, memory_limit);
+    }
+
+    // cgroup v1 has a line per controller hierarchy, e.g. "4:cpu,cpuacct:/docker/abc"
+    let controller_dir = |controller: &str, mount: &str| {
+        let path = membership.lines().find_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let controllers = fields.nth(1)?;
+            controllers.split(',').any(|c| c == controller).then(|| fields.next())?
+        })?;
+        // Inside a container the hierarchy is usually mounted at the container's own cgroup
+        [format!("sys/fs/cgroup/{}{}", mount, path), format!("sys/fs/cgroup/{}", mount)]
+            .into_iter()
+            .find(|dir| root.exists(dir))
+    };
+
+    let cpu_quota = controller_dir("cpu", "cpu,cpuacct")
+        .or_else(|| controller_dir("cpu", "cpu"))
+        .and_then(|dir| {
+            let quota = root.read(format!("{}/cpu.cfs_quota_us", dir))?.parse::<i64>().ok()?;
+            let period = root.read_u64(format!("{}/cpu.cfs_period_us", dir))?;
+            (quota > 0 && period > 0).then(|| quota as f64 / period as f64)
+        });
+    let memory_limit = controller_dir("memory", "memory")
+        .and_then(|dir| root.read_u64(format!("{}/memory.limit_in_bytes", dir)))
+        .filter(|&limit| limit < UNLIMITED_MEMORY);
+
+    (cpu_quota, memory_limit)
+}
+
+/// "/a/b" -> ["/a/b", "/a", ""], the last being the hierarchy root
+fn ancestors(path: &str) -> Vec<String> {
+    let mut dirs = vec![path.trim_end_matches('/').to_string()];
+    while let Some((parent, _)) = dirs.last().unwrap().rsplit_once('/') {
+        dirs.push(parent.to_string());
+    }
+    dirs.dedup();
+    dirs
+}
+
+/// "max 100000" is unlimited; "150000 100000" is 1.5 CPUs
+fn parse_cpu_max(cpu_max: &str) -> Option<f64> {
+    let (quota, period) = cpu_max.split_once(' ')?;
+    let quota: f64 = quota.parse().ok()?;
+    let period: f64 = period.trim().parse().ok()?;
+    (period > 0.0).then(|| quota / period)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fs_tree::FakeFsTree;
+
+    #[test]
+    fn test_docker_with_cgroup_v2_limits() {
+        let fake = FakeFsTree::new(&[
+            (".dockerenv", ""),
+            ("proc/self/cgroup", "0::/\n"),
+            ("sys/fs/cgroup/cpu.max", "250000 100000\n"),
+            ("sys/fs/cgroup/memory.max", "4294967296\n"),
+            ("sys/class/dmi/id/sys_vendor", "QEMU\n"),
+        ]);
+
+        let info = detect_virtualization(&fake.tree(), None, None);
+        assert_eq!(info.hypervisor.as_deref(), Some("KVM"));
+        assert_eq!(info.container.as_deref(), Some("docker"));
+        assert_eq!(info.cpu_quota, Some(2.5));
+        assert_eq!(info.memory_limit_bytes, Some(4294967296));
+    }
+
+    #[test]
+    fn test_nested_cgroup_v2_limits_take_the_tightest() {
+        let fake = FakeFsTree::new(&[
+            ("proc/self/cgroup", "0::/user.slice/build.scope\n"),
+            ("sys/fs/cgroup/user.slice/cpu.max", "400000 100000\n"),
+            ("sys/fs/cgroup/user.slice/build.scope/cpu.max", "max 100000\n"),
+            ("sys/fs/cgroup/user.slice/build.scope/memory.max", "max\n"),
+            ("sys/class/dmi/id/sys_vendor", "Microsoft Corporation\n"),
+            ("sys/class/dmi/id/product_name", "Virtual Machine\n"),
+        ]);
+
+        let info = detect_virtualization(&fake.tree(), Some("Hyper-V".to_string()), None);
+        assert_eq!(info.hypervisor.as_deref(), Some("Hyper-V"));
+        assert_eq!(info.container, None);
+        assert_eq!(info.cpu_quota, Some(4.0));
+        assert_eq!(info.memory_limit_bytes, None);
+    }
+
+    #[test]
+    fn test_cgroup_v1_limits_and_kubernetes() {
+        let fake = FakeFsTree::new(&[
+            ("proc/1/cgroup", "4:cpu,cpuacct:/kubepods/burstable/pod1\n"),
+            (
+                "proc/self/cgroup",
+                "4:cpu,cpuacct:/kubepods/burstable/pod1\n3:memory:/kubepods/burstable/pod1\n",
+            ),
+            ("sys/fs/cgroup/cpu,cpuacct/cpu.cfs_quota_us", "50000\n"),
+            ("sys/fs/cgroup/cpu,cpuacct/cpu.cfs_period_us", "100000\n"),
+            ("sys/fs/cgroup/memory/memory.limit_in_bytes", "9223372036854771712\n"),
+        ]);
+
+        let info = detect_virtualization(&fake.tree(), None, None);
+        assert_eq!(info.container.as_deref(), Some("kubernetes"));
+        assert_eq!(info.cpu_quota, Some(0.5));
+        assert_eq!(info.memory_limit_bytes, None);
+    }
+
+    #[test]
+    fn test_wsl_and_bare_metal() {
+        let wsl = FakeFsTree::new(&[("proc/sys/kernel/osrelease", "5.15.153.1-microsoft-standard-WSL2\n")]);
+        let info = detect_virtualization(&wsl.tree(), Some("Hyper-V".to_string()), None);
+        assert_eq!(info.hypervisor.as_deref(), Some("WSL2"));
+
+        let bare = FakeFsTree::new(&[("sys/class/dmi/id/sys_vendor", "Dell Inc.\n")]);
+        assert!(!detect_virtualization(&bare.tree(), None, None).is_virtualized());
+
+        // Hyper-V root partition or Xen dom0: CPUID names a hypervisor, nothing else does
+        let host = detect_virtualization(&bare.tree(), Some("Hyper-V".to_string()), None);
+        assert_eq!(host.hypervisor, None);
+        let dom0 = FakeFsTree::new(&[("proc/xen/capabilities", "control_d\n")]);
+        assert_eq!(detect_virtualization(&dom0.tree(), Some("Xen".to_string()), None).hypervisor, None);
+        let pv_guest = FakeFsTree::new(&[("proc/xen/capabilities", "")]);
+        let info = detect_virtualization(&pv_guest.tree(), Some("Xen".to_string()), None);
+        assert_eq!(info.hypervisor.as_deref(), Some("Xen"));
+    }
+}
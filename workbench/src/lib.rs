@@ -0,0 +1,13 @@
+//! WorkBench-Pro - Developer Workstation Benchmark
+//!
+//! The desktop app (`src/main.rs`) and the self-hosted results server
+//! (`src/server/main.rs`) are thin binaries over this library, so models,
+//! the results backend types and the statistics code are compiled once.
+
+pub mod app;
+mod benchmarks;
+pub mod cloud;
+mod core;
+pub mod models;
+pub mod storage;
+pub mod ui;
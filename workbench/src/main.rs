@@ -1,12 +1,6 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod app;
-mod benchmarks;
-mod cloud;
-mod core;
-mod models;
-mod storage;
-mod ui;
+use workbench_pro::{app, ui};
 
 use anyhow::Result;
 use eframe::egui;
@@ -174,7 +168,7 @@ fn main() -> Result<()> {
         "WorkBench-Pro",
         options,
         Box::new(|cc| {
-            crate::ui::Theme::apply(&cc.egui_ctx);
+            ui::Theme::apply(&cc.egui_ctx);
             Ok(Box::new(app::WorkBenchProApp::new(cc)))
         }),
     )
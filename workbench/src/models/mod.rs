@@ -1,7 +1,9 @@
 pub mod report;
 pub mod results;
+pub mod signature;
 pub mod system_info;
 
 pub use report::*;
 pub use results::*;
+pub use signature::*;
 pub use system_info::*;
@@ -19,6 +19,32 @@ pub struct MetricDifference {
     pub is_improvement: bool,
 }
 
+impl ComparisonReport {
+    /// Compare every test that both runs completed, in the baseline's order
+    pub fn new(baseline: BenchmarkRun, comparison: BenchmarkRun) -> Self {
+        let differences = baseline
+            .results
+            .iter()
+            .filter_map(|base| {
+                let other = comparison.results.iter().find(|r| r.test_id == base.test_id)?;
+                Some(MetricDifference::new(
+                    base.test_id.clone(),
+                    base.name.clone(),
+                    base.value,
+                    other.value,
+                    base.higher_is_better(),
+                ))
+            })
+            .collect();
+
+        Self {
+            baseline,
+            comparison,
+            differences,
+        }
+    }
+}
+
 impl MetricDifference {
     pub fn new(
         test_id: String,
@@ -57,3 +83,45 @@ impl MetricDifference {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SystemInfo, TestDetails, TestResult};
+
+    fn create_run(results: &[(&str, f64, &str)]) -> BenchmarkRun {
+        let mut run = BenchmarkRun::new(
+            "PC-A".to_string(),
+            SystemInfo::test_fixture("PC-A"),
+        );
+        run.results.project_operations = results
+            .iter()
+            .map(|(test_id, value, unit)| TestResult {
+                test_id: test_id.to_string(),
+                name: test_id.to_string(),
+                description: String::new(),
+                value: *value,
+                unit: unit.to_string(),
+                details: TestDetails::default(),
+            })
+            .collect();
+        run
+    }
+
+    #[test]
+    fn test_report_matches_tests_and_direction() {
+        let baseline = create_run(&[("large_file_read", 1000.0, "MB/s"), ("storage_latency", 2.0, "ms"), ("wmic", 5.0, "ms")]);
+        let comparison = create_run(&[("large_file_read", 1500.0, "MB/s"), ("storage_latency", 3.0, "ms")]);
+
+        let report = ComparisonReport::new(baseline, comparison);
+        assert_eq!(report.differences.len(), 2);
+
+        let read = &report.differences[0];
+        assert!(read.is_improvement);
+        assert_eq!(read.difference_percent, 50.0);
+        assert_eq!(read.multiplier(), 1.5);
+
+        // Higher latency is a regression
+        assert!(!report.differences[1].is_improvement);
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use super::signature::{RunSignature, SignatureStatus};
 use super::system_info::SystemInfo;
 
 /// Deserialize f64, treating null as 0.0 (handles NaN serialized as null)
@@ -27,6 +30,21 @@ pub struct BenchmarkRun {
     /// Timestamp when results were uploaded to remote service
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uploaded_at: Option<DateTime<Utc>>,
+    /// Secret returned by the upload that authorises deleting it again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_token: Option<String>,
+    /// Signature over the results and system info by the machine that ran them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<RunSignature>,
+    /// Outcome of the last `verify_signature`, not stored
+    #[serde(skip)]
+    pub signature_status: SignatureStatus,
+    /// Wall-clock seconds each test took, keyed by test_id (used for ETA estimates)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub test_durations: BTreeMap<String, f64>,
+    /// Seconds the runner cooled down after each test, keyed by test_id (used for ETA estimates)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub cool_down_durations: BTreeMap<String, f64>,
 }
 
 impl BenchmarkRun {
@@ -41,6 +59,11 @@ impl BenchmarkRun {
             results: CategoryResults::default(),
             remote_id: None,
             uploaded_at: None,
+            delete_token: None,
+            signature: None,
+            signature_status: SignatureStatus::Unsigned,
+            test_durations: BTreeMap::new(),
+            cool_down_durations: BTreeMap::new(),
         }
     }
 }
@@ -52,6 +75,16 @@ pub struct CategoryResults {
     pub responsiveness: Vec<TestResult>,
 }
 
+impl CategoryResults {
+    /// Iterate over all results regardless of category
+    pub fn iter(&self) -> impl Iterator<Item = &TestResult> {
+        self.project_operations
+            .iter()
+            .chain(self.build_performance.iter())
+            .chain(self.responsiveness.iter())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestResult {
     pub test_id: String,
@@ -63,6 +96,21 @@ pub struct TestResult {
     pub details: TestDetails,
 }
 
+impl TestResult {
+    /// Whether a larger value is better, inferred from the unit.
+    /// Rates ("MB/s", "files/sec") are higher-is-better unless the numerator is a
+    /// time ("ms/op"); plain times and latencies are lower-is-better.
+    pub fn higher_is_better(&self) -> bool {
+        let unit = self.unit.split(" (").next().unwrap_or(&self.unit).trim().to_lowercase();
+        let is_time = |u: &str| matches!(u, "ns" | "us" | "µs" | "ms" | "s" | "sec" | "secs" | "min");
+
+        match unit.split_once('/') {
+            Some((numerator, _)) => !is_time(numerator.trim()),
+            None => !(is_time(&unit) || unit == "%"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestDetails {
     pub iterations: u32,
@@ -79,6 +127,59 @@ pub struct TestDetails {
     #[serde(deserialize_with = "deserialize_f64_or_null")]
     pub std_dev: f64,
     pub percentiles: Option<Percentiles>,
+    /// Individual measurements (same unit as min/max), downsampled for storage
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub samples: Vec<f64>,
+    /// CPU temperature and clocks while the test ran, where the platform exposes them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thermal: Option<ThermalSummary>,
+}
+
+/// Temperature and clock evidence gathered in the background during a test
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThermalSummary {
+    pub samples: u32,
+    #[serde(default)]
+    pub max_temp_c: Option<f64>,
+    #[serde(default)]
+    pub avg_temp_c: Option<f64>,
+    /// Clock of the fastest core, lowest and average over the samples
+    #[serde(default)]
+    pub min_frequency_mhz: Option<u32>,
+    #[serde(default)]
+    pub avg_frequency_mhz: Option<u32>,
+    /// Share of samples taken hot and below base clock
+    pub throttled_fraction: f64,
+    /// Throttle events the CPU itself counted during the test
+    #[serde(default)]
+    pub throttle_events: Option<u64>,
+}
+
+impl ThermalSummary {
+    pub fn is_throttled(&self) -> bool {
+        self.throttle_events.is_some_and(|events| events > 0) || self.throttled_fraction >= 0.1
+    }
+}
+
+/// Maximum number of individual samples kept per test result
+pub const MAX_STORED_SAMPLES: usize = 1000;
+
+impl TestDetails {
+    /// Reduce sorted measurements to at most MAX_STORED_SAMPLES evenly spaced
+    /// quantiles, preserving the shape of the distribution
+    pub fn downsample(sorted_values: &[f64]) -> Vec<f64> {
+        let len = sorted_values.len();
+        if len <= MAX_STORED_SAMPLES {
+            return sorted_values.to_vec();
+        }
+
+        (0..MAX_STORED_SAMPLES)
+            .map(|i| {
+                let idx = (i as f64 / (MAX_STORED_SAMPLES - 1) as f64 * (len - 1) as f64).round() as usize;
+                sorted_values[idx]
+            })
+            .collect()
+    }
 }
 
 impl Default for TestDetails {
@@ -92,6 +193,8 @@ impl Default for TestDetails {
             median: 0.0,
             std_dev: 0.0,
             percentiles: None,
+            samples: Vec::new(),
+            thermal: None,
         }
     }
 }
@@ -0,0 +1,399 @@
+//! Tamper-evident signatures over benchmark results
+//!
+//! A run is signed with the Ed25519 key of the machine that produced it. The
+//! signature covers a SHA-256 hash of the timestamp, results and system info,
+//! so editing any of them afterwards breaks verification.
+//!
+//! The hashed form is versioned. Version 1 hashed the plain serde form, which
+//! changed whenever a field was added. Version 2 hashes a canonical form without
+//! nulls and empty collections, so fields added later as `Option`, `Vec` or
+//! structs of them leave earlier signatures intact.
+
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::results::{BenchmarkRun, CategoryResults};
+use super::system_info::{SystemInfo, VirtualizationInfo};
+
+/// Version of the hashed form that new signatures use
+pub const SIGNATURE_VERSION: u32 = 2;
+
+/// Hash and Ed25519 signature of a run's content, all hex encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSignature {
+    /// Version of the hashed form; signatures from before versioning are 1
+    #[serde(default = "first_version")]
+    pub version: u32,
+    pub content_hash: String,
+    pub public_key: String,
+    pub signature: String,
+}
+
+/// Outcome of checking a run's signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureStatus {
+    #[default]
+    Unsigned,
+    Valid,
+    /// The content or the signature was changed after signing
+    Invalid,
+}
+
+impl SignatureStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SignatureStatus::Unsigned => "Unsigned",
+            SignatureStatus::Valid => "Signed",
+            SignatureStatus::Invalid => "Signature mismatch",
+        }
+    }
+}
+
+/// The signed fields, in a fixed order
+#[derive(Serialize)]
+struct SignedContent<'a> {
+    timestamp: &'a DateTime<Utc>,
+    results: &'a CategoryResults,
+    system_info: &'a SystemInfo,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+/// Hex SHA-256 of the signed fields in the hashed form of `version`. None for
+/// versions this build doesn't know, and for version 1 when the system info
+/// holds fields version 1 couldn't have signed.
+pub fn content_hash(
+    version: u32,
+    timestamp: &DateTime<Utc>,
+    results: &CategoryResults,
+    system_info: &SystemInfo,
+) -> Option<String> {
+    // Hash what a reload would produce: NaN is stored as null and read back as 0.0
+    let results: CategoryResults = serde_json::to_value(results)
+        .and_then(serde_json::from_value)
+        .unwrap_or_else(|_| results.clone());
+
+    let json = match version {
+        1 => serde_json::to_vec(&v1::SignedContent::of(timestamp, &results, system_info)?),
+        2 => {
+            let content = SignedContent {
+                timestamp,
+                results: &results,
+                system_info,
+            };
+            let value = serde_json::to_value(&content).ok()?;
+            serde_json::to_vec(&canonical(value).unwrap_or(Value::Null))
+        }
+        _ => return None,
+    };
+    Some(to_hex(&Sha256::digest(json.ok()?)))
+}
+
+/// `value` without nulls and empty arrays or objects, so that a defaulted field
+/// hashes the same as a missing one. Object keys come out sorted.
+fn canonical(value: Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::Array(items) if items.is_empty() => None,
+        // Keep positions: an emptied element still takes its place
+        Value::Array(items) => Some(Value::Array(
+            items.into_iter().map(|item| canonical(item).unwrap_or(Value::Null)).collect(),
+        )),
+        Value::Object(fields) => {
+            let fields: serde_json::Map<String, Value> = fields
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, canonical(value)?)))
+                .collect();
+            (!fields.is_empty()).then_some(Value::Object(fields))
+        }
+        other => Some(other),
+    }
+}
+
+impl RunSignature {
+    /// Sign a content hash of the current version
+    pub fn create(content_hash: String, key: &SigningKey) -> Self {
+        let signature = key.sign(content_hash.as_bytes());
+        Self {
+            version: SIGNATURE_VERSION,
+            public_key: to_hex(key.verifying_key().as_bytes()),
+            signature: to_hex(&signature.to_bytes()),
+            content_hash,
+        }
+    }
+
+    /// Check the signature against the content it claims to cover, hashed the way it was signed
+    pub fn verify(&self, timestamp: &DateTime<Utc>, results: &CategoryResults, system_info: &SystemInfo) -> SignatureStatus {
+        let content_hash = content_hash(self.version, timestamp, results, system_info);
+        let valid = content_hash.as_deref() == Some(self.content_hash.as_str())
+            && (|| {
+                let public_key: [u8; 32] = from_hex(&self.public_key)?.try_into().ok()?;
+                let signature: [u8; 64] = from_hex(&self.signature)?.try_into().ok()?;
+                let key = VerifyingKey::from_bytes(&public_key).ok()?;
+                key.verify(self.content_hash.as_bytes(), &Signature::from_bytes(&signature))
+                    .ok()
+            })()
+            .is_some();
+
+        if valid {
+            SignatureStatus::Valid
+        } else {
+            SignatureStatus::Invalid
+        }
+    }
+}
+
+impl BenchmarkRun {
+    /// Hash of the current content in the current version
+    pub fn content_hash(&self) -> String {
+        content_hash(SIGNATURE_VERSION, &self.timestamp, &self.results, &self.system_info).unwrap_or_default()
+    }
+
+    /// Sign the current content, replacing any earlier signature
+    pub fn sign(&mut self, key: &SigningKey) {
+        self.signature = Some(RunSignature::create(self.content_hash(), key));
+        self.signature_status = SignatureStatus::Valid;
+    }
+
+    /// Verify the signature and remember the outcome in `signature_status`
+    pub fn verify_signature(&mut self) -> SignatureStatus {
+        self.signature_status = match &self.signature {
+            Some(signature) => signature.verify(&self.timestamp, &self.results, &self.system_info),
+            None => SignatureStatus::Unsigned,
+        };
+        self.signature_status
+    }
+}
+
+/// The signed content as version 1 serialized it, in field order, with only the
+/// fields that existed then
+mod v1 {
+    use chrono::{DateTime, Utc};
+    use serde::Serialize;
+
+    use super::VirtualizationInfo;
+    use crate::models::{CategoryResults, OsInfo, StorageType};
+
+    #[derive(Serialize)]
+    pub struct SignedContent<'a> {
+        timestamp: &'a DateTime<Utc>,
+        results: &'a CategoryResults,
+        system_info: SystemInfo<'a>,
+    }
+
+    #[derive(Serialize)]
+    struct SystemInfo<'a> {
+        hostname: &'a str,
+        cpu: CpuInfo<'a>,
+        memory: MemoryInfo<'a>,
+        storage: Vec<StorageInfo<'a>>,
+        gpu: Option<GpuInfo<'a>>,
+        os: &'a OsInfo,
+    }
+
+    #[derive(Serialize)]
+    struct CpuInfo<'a> {
+        name: &'a str,
+        vendor: &'a str,
+        cores: u32,
+        threads: u32,
+        base_frequency_mhz: u32,
+        max_frequency_mhz: Option<u32>,
+        cache_l3_kb: Option<u32>,
+    }
+
+    #[derive(Serialize)]
+    struct MemoryInfo<'a> {
+        total_bytes: u64,
+        speed_mhz: Option<u32>,
+        memory_type: &'a Option<String>,
+    }
+
+    #[derive(Serialize)]
+    struct StorageInfo<'a> {
+        name: &'a str,
+        device_type: StorageType,
+        capacity_bytes: u64,
+    }
+
+    #[derive(Serialize)]
+    struct GpuInfo<'a> {
+        name: &'a str,
+        vendor: &'a str,
+        vram_bytes: Option<u64>,
+        driver_version: &'a Option<String>,
+    }
+
+    impl<'a> SignedContent<'a> {
+        /// None when `info` holds something version 1 didn't cover, which it then can't vouch for
+        pub fn of(timestamp: &'a DateTime<Utc>, results: &'a CategoryResults, info: &'a crate::models::SystemInfo) -> Option<Self> {
+            let cpu = &info.cpu;
+            let memory = &info.memory;
+            let later_fields = info.virtualization != VirtualizationInfo::default()
+                || cpu.cache_l1d_kb.is_some()
+                || cpu.cache_l2_kb.is_some()
+                || !cpu.extensions.is_empty()
+                || cpu.smt_enabled.is_some()
+                || !cpu.core_groups.is_empty()
+                || !memory.modules.is_empty()
+                || memory.channels.is_some()
+                || info.storage.iter().any(|s| {
+                    s.mount_point.is_some()
+                        || s.file_system.is_some()
+                        || s.model.is_some()
+                        || s.firmware.is_some()
+                        || s.transport.is_some()
+                        || s.logical_block_size.is_some()
+                        || s.physical_block_size.is_some()
+                })
+                || info.gpu.as_ref().is_some_and(|g| g.driver.is_some());
+            if later_fields {
+                return None;
+            }
+
+            Some(Self {
+                timestamp,
+                results,
+                system_info: SystemInfo {
+                    hostname: &info.hostname,
+                    cpu: CpuInfo {
+                        name: &cpu.name,
+                        vendor: &cpu.vendor,
+                        cores: cpu.cores,
+                        threads: cpu.threads,
+                        base_frequency_mhz: cpu.base_frequency_mhz,
+                        max_frequency_mhz: cpu.max_frequency_mhz,
+                        cache_l3_kb: cpu.cache_l3_kb,
+                    },
+                    memory: MemoryInfo {
+                        total_bytes: memory.total_bytes,
+                        speed_mhz: memory.speed_mhz,
+                        memory_type: &memory.memory_type,
+                    },
+                    storage: info
+                        .storage
+                        .iter()
+                        .map(|s| StorageInfo {
+                            name: &s.name,
+                            device_type: s.device_type,
+                            capacity_bytes: s.capacity_bytes,
+                        })
+                        .collect(),
+                    gpu: info.gpu.as_ref().map(|g| GpuInfo {
+                        name: &g.name,
+                        vendor: &g.vendor,
+                        vram_bytes: g.vram_bytes,
+                        driver_version: &g.driver_version,
+                    }),
+                    os: &info.os,
+                },
+            })
+        }
+    }
+}
+
+/// Lowercase hex encoding of `bytes`
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Bytes of a hex string, or None unless it is an even number of hex digits
+pub(crate) fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TestDetails, TestResult};
+
+    fn create_run() -> BenchmarkRun {
+        let mut run = BenchmarkRun::new(
+            "PC-A".to_string(),
+            SystemInfo::test_fixture("PC-A"),
+        );
+        run.results.project_operations.push(TestResult {
+            test_id: "large_file_read".to_string(),
+            name: "Large File Read".to_string(),
+            description: String::new(),
+            value: 1500.0,
+            unit: "MB/s".to_string(),
+            details: TestDetails {
+                std_dev: f64::NAN,
+                ..TestDetails::default()
+            },
+        });
+        run
+    }
+
+    #[test]
+    fn test_signature_survives_save_and_load() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut run = create_run();
+        run.sign(&key);
+
+        let mut loaded: BenchmarkRun = serde_json::from_str(&serde_json::to_string(&run).unwrap()).unwrap();
+        assert_eq!(loaded.signature_status, SignatureStatus::Unsigned);
+        assert_eq!(loaded.verify_signature(), SignatureStatus::Valid);
+    }
+
+    #[test]
+    fn test_edits_break_the_signature() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut run = create_run();
+        assert_eq!(run.verify_signature(), SignatureStatus::Unsigned);
+        run.sign(&key);
+
+        run.results.project_operations[0].value = 3000.0;
+        assert_eq!(run.verify_signature(), SignatureStatus::Invalid);
+
+        // Re-hashing the edited content doesn't help without the private key
+        let edited_hash = run.content_hash();
+        run.signature.as_mut().unwrap().content_hash = edited_hash;
+        assert_eq!(run.verify_signature(), SignatureStatus::Invalid);
+    }
+
+    #[test]
+    fn test_fields_added_later_keep_the_signature() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut run = create_run();
+        run.sign(&key);
+
+        // A build from before these fields existed wrote the run without them
+        let mut json = serde_json::to_value(&run).unwrap();
+        let info = json["system_info"].as_object_mut().unwrap();
+        info.remove("virtualization");
+        info["cpu"].as_object_mut().unwrap().remove("extensions");
+        info["memory"].as_object_mut().unwrap().remove("channels");
+
+        let mut loaded: BenchmarkRun = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.verify_signature(), SignatureStatus::Valid);
+    }
+
+    #[test]
+    fn test_version_1_signatures_verify() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut run = create_run();
+        let hash = content_hash(1, &run.timestamp, &run.results, &run.system_info).unwrap();
+        let mut signature = serde_json::to_value(RunSignature::create(hash, &key)).unwrap();
+        signature.as_object_mut().unwrap().remove("version");
+        run.signature = Some(serde_json::from_value(signature).unwrap());
+
+        assert_eq!(run.verify_signature(), SignatureStatus::Valid);
+
+        // Version 1 never covered the cache sizes, so it can't vouch for one
+        run.system_info.cpu.cache_l2_kb = Some(2048);
+        assert_eq!(run.verify_signature(), SignatureStatus::Invalid);
+    }
+}
@@ -8,9 +8,72 @@ pub struct SystemInfo {
     pub storage: Vec<StorageInfo>,
     pub gpu: Option<GpuInfo>,
     pub os: OsInfo,
+    #[serde(default)]
+    pub virtualization: VirtualizationInfo,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl SystemInfo {
+    /// Threads multi-threaded work can actually keep busy, given any CPU quota
+    pub fn effective_threads(&self) -> u32 {
+        let threads = self.cpu.threads.max(1);
+        match self.virtualization.cpu_quota {
+            Some(quota) => (quota.ceil() as u32).clamp(1, threads),
+            None => threads,
+        }
+    }
+
+    /// A plain 8-core, 16GB Windows machine for tests
+    #[cfg(test)]
+    pub fn test_fixture(hostname: &str) -> Self {
+        Self {
+            hostname: hostname.to_string(),
+            cpu: CpuInfo {
+                name: "Test CPU".to_string(),
+                vendor: "Test".to_string(),
+                cores: 8,
+                threads: 16,
+                base_frequency_mhz: 3000,
+                ..Default::default()
+            },
+            memory: MemoryInfo {
+                total_bytes: 16 * 1024 * 1024 * 1024,
+                ..Default::default()
+            },
+            storage: vec![],
+            gpu: None,
+            os: OsInfo {
+                name: "Windows".to_string(),
+                version: "11".to_string(),
+                build: None,
+            },
+            virtualization: Default::default(),
+        }
+    }
+}
+
+/// Hypervisor, container and resource limits the benchmark runs under
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VirtualizationInfo {
+    /// e.g. "KVM", "Hyper-V", "VMware" or "WSL2"; None on bare metal
+    #[serde(default)]
+    pub hypervisor: Option<String>,
+    /// Container runtime, e.g. "docker", "podman", "kubernetes" or "lxc"
+    #[serde(default)]
+    pub container: Option<String>,
+    /// CPUs the cgroup quota allows, e.g. 1.5 for 150ms per 100ms period
+    #[serde(default)]
+    pub cpu_quota: Option<f64>,
+    #[serde(default)]
+    pub memory_limit_bytes: Option<u64>,
+}
+
+impl VirtualizationInfo {
+    pub fn is_virtualized(&self) -> bool {
+        self.hypervisor.is_some() || self.container.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CpuInfo {
     pub name: String,
     pub vendor: String,
@@ -19,13 +82,62 @@ pub struct CpuInfo {
     pub base_frequency_mhz: u32,
     pub max_frequency_mhz: Option<u32>,
     pub cache_l3_kb: Option<u32>,
+    /// L1 data cache per core
+    #[serde(default)]
+    pub cache_l1d_kb: Option<u32>,
+    /// L2 cache per core, or per cluster of efficiency cores
+    #[serde(default)]
+    pub cache_l2_kb: Option<u32>,
+    /// Instruction set extensions that change benchmark results, e.g. "AVX2", "AVX-512F", "SHA"
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Whether simultaneous multithreading (Hyper-Threading) is active
+    #[serde(default)]
+    pub smt_enabled: Option<bool>,
+    /// Groups of like cores on hybrid CPUs; empty when all cores are the same
+    #[serde(default)]
+    pub core_groups: Vec<CoreGroup>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl CpuInfo {
+    pub fn is_hybrid(&self) -> bool {
+        self.core_groups.len() > 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoreKind {
+    Performance,
+    Efficiency,
+}
+
+impl CoreKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoreKind::Performance => "P-core",
+            CoreKind::Efficiency => "E-core",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreGroup {
+    pub kind: CoreKind,
+    pub cores: u32,
+    pub threads: u32,
+    pub max_frequency_mhz: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MemoryInfo {
     pub total_bytes: u64,
     pub speed_mhz: Option<u32>,
     pub memory_type: Option<String>,
+    #[serde(default)]
+    pub modules: Vec<MemoryModule>,
+    /// Memory channels with at least one module installed
+    #[serde(default)]
+    pub channels: Option<u32>,
 }
 
 impl MemoryInfo {
@@ -34,11 +146,49 @@ impl MemoryInfo {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// One installed memory module, as described by SMBIOS
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryModule {
+    /// Slot label, e.g. "DIMM_A1" or "ChannelA-DIMM0"
+    pub slot: String,
+    #[serde(default)]
+    pub bank: Option<String>,
+    pub size_bytes: u64,
+    #[serde(default)]
+    pub memory_type: Option<String>,
+    /// Rated transfer rate in MT/s, which vendors quote as MHz
+    #[serde(default)]
+    pub speed_mhz: Option<u32>,
+    /// Transfer rate the firmware actually configured
+    #[serde(default)]
+    pub configured_speed_mhz: Option<u32>,
+    #[serde(default)]
+    pub manufacturer: Option<String>,
+    #[serde(default)]
+    pub part_number: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StorageInfo {
     pub name: String,
     pub device_type: StorageType,
     pub capacity_bytes: u64,
+    #[serde(default)]
+    pub mount_point: Option<String>,
+    #[serde(default)]
+    pub file_system: Option<String>,
+    /// Model of the backing device, e.g. "Samsung SSD 990 PRO 2TB"
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub firmware: Option<String>,
+    /// How the backing device is attached: "nvme", "sata", "usb", "virtio", ...
+    #[serde(default)]
+    pub transport: Option<String>,
+    #[serde(default)]
+    pub logical_block_size: Option<u32>,
+    #[serde(default)]
+    pub physical_block_size: Option<u32>,
 }
 
 impl StorageInfo {
@@ -47,11 +197,12 @@ impl StorageInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub enum StorageType {
     NVMe,
     SSD,
     HDD,
+    #[default]
     Unknown,
 }
 
@@ -71,6 +222,9 @@ pub struct GpuInfo {
     pub name: String,
     pub vendor: String,
     pub vram_bytes: Option<u64>,
+    /// Kernel or display driver, e.g. "amdgpu" or "nvidia"
+    #[serde(default)]
+    pub driver: Option<String>,
     pub driver_version: Option<String>,
 }
 
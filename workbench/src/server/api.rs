@@ -0,0 +1,493 @@
+//! The subset of the PostgREST API that `CloudClient` uses
+//!
+//! - `GET /rest/v1/benchmark_runs` with `select`, `order`, `limit`, `offset` and
+//!   `column=op.value` filters (`eq`, `ilike`, `gte`, `lte`)
+//! - `POST /rest/v1/benchmark_runs` to upload, `DELETE` with filters to remove
+//! - `POST /rest/v1/rpc/get_test_statistics` and `/rpc/get_percentile_rank`; the
+//!   statistics body may hold `HardwareClass` fields to slice by hardware
+//!
+//! Every upload gets a random deletion token that is returned once, in the
+//! response to the upload. Only its hash is stored, and a `DELETE` must send the
+//! token of every row it matches in the `x-delete-token` header.
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use serde_json::{json, Value};
+
+use workbench_pro::cloud::{statistics, HardwareClass};
+use workbench_pro::models::SignatureStatus;
+
+use crate::store::{self, RunRow, RunStore};
+
+const RUNS_PATH: &str = "/rest/v1/benchmark_runs";
+const STATISTICS_PATH: &str = "/rest/v1/rpc/get_test_statistics";
+const PERCENTILE_RANK_PATH: &str = "/rest/v1/rpc/get_percentile_rank";
+
+/// Column holding the hashed deletion token, hidden from clients
+const TOKEN_HASH_COLUMN: &str = "delete_token_hash";
+
+/// Incoming request, independent of the HTTP library
+pub struct ApiRequest<'a> {
+    pub method: &'a str,
+    /// Path and query string, e.g. "/rest/v1/benchmark_runs?limit=10"
+    pub url: &'a str,
+    /// Value of the `apikey` header, if sent
+    pub api_key: Option<&'a str>,
+    /// Value of the `x-delete-token` header, if sent
+    pub delete_token: Option<&'a str>,
+    pub body: &'a str,
+}
+
+/// Status code and JSON body to send back
+#[derive(Debug)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl ApiResponse {
+    fn json(status: u16, value: Value) -> Self {
+        Self {
+            status,
+            body: value.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::json(status, json!({ "message": message.into() }))
+    }
+}
+
+/// Route a request against the store. `api_key` of None leaves the server open.
+pub fn handle(store: &RunStore, api_key: Option<&str>, request: &ApiRequest) -> ApiResponse {
+    if let Some(expected) = api_key {
+        if request.api_key != Some(expected) {
+            return ApiResponse::error(401, "Invalid API key");
+        }
+    }
+
+    let (path, query) = request.url.split_once('?').unwrap_or((request.url, ""));
+    let params = parse_query(query);
+
+    match (request.method, path) {
+        ("GET", RUNS_PATH) => list_runs(store, &params),
+        ("POST", RUNS_PATH) => upload_run(store, request.body),
+        ("DELETE", RUNS_PATH) => delete_runs(store, &params, request.delete_token),
+        ("POST", STATISTICS_PATH) => test_statistics(store, request.body),
+        ("POST", PERCENTILE_RANK_PATH) => percentile_rank(store, request.body),
+        _ => ApiResponse::error(404, format!("No route for {} {}", request.method, path)),
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let decode = |s: &str| urlencoding::decode(s).map(|d| d.into_owned()).unwrap_or_else(|_| s.to_string());
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+/// Rows matching all `column=op.value` filters, as JSON objects
+fn filtered_rows(store: &RunStore, params: &[(String, String)]) -> Result<Vec<Value>, ApiResponse> {
+    let filters: Vec<(&str, &str, &str)> = params
+        .iter()
+        .filter(|(key, _)| !matches!(key.as_str(), "select" | "order" | "limit" | "offset"))
+        .map(|(column, filter)| {
+            let (op, value) = filter
+                .split_once('.')
+                .ok_or_else(|| ApiResponse::error(400, format!("Invalid filter on {}", column)))?;
+            Ok((column.as_str(), op, value))
+        })
+        .collect::<Result<_, ApiResponse>>()?;
+
+    let mut rows = Vec::new();
+    for row in store.rows() {
+        let mut row = serde_json::to_value(row).unwrap_or_default();
+        if let Value::Object(map) = &mut row {
+            map.remove(TOKEN_HASH_COLUMN);
+        }
+        let mut keep = true;
+        for (column, op, value) in &filters {
+            keep &= matches_filter(row.get(*column).unwrap_or(&Value::Null), op, value)?;
+        }
+        if keep {
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+fn matches_filter(field: &Value, op: &str, value: &str) -> Result<bool, ApiResponse> {
+    let ordering = || compare(field, value);
+
+    Ok(match op {
+        "eq" => ordering() == Some(Ordering::Equal),
+        "gte" => matches!(ordering(), Some(Ordering::Greater | Ordering::Equal)),
+        "lte" => matches!(ordering(), Some(Ordering::Less | Ordering::Equal)),
+        "ilike" => field.as_str().is_some_and(|s| ilike(s, value)),
+        _ => return Err(ApiResponse::error(400, format!("Unsupported operator: {}", op))),
+    })
+}
+
+/// Compare a column with a filter value: numerically, as timestamps, or as text
+fn compare(field: &Value, value: &str) -> Option<Ordering> {
+    match field {
+        Value::Number(n) => n.as_f64()?.partial_cmp(&value.parse::<f64>().ok()?),
+        Value::String(s) => {
+            let timestamp = |t: &str| DateTime::<FixedOffset>::parse_from_rfc3339(t).ok();
+            match (timestamp(s), timestamp(value)) {
+                (Some(a), Some(b)) => Some(a.cmp(&b)),
+                _ => Some(s.as_str().cmp(value)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Case-insensitive match where `*` stands for any run of characters
+fn ilike(text: &str, pattern: &str) -> bool {
+    let text = text.to_lowercase();
+    let pattern = pattern.to_lowercase();
+    let parts: Vec<&str> = pattern.split('*').collect();
+
+    let mut rest = text.as_str();
+    for (i, part) in parts.iter().enumerate() {
+        if i == 0 {
+            let Some(stripped) = rest.strip_prefix(part) else {
+                return false;
+            };
+            rest = stripped;
+        } else if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        } else {
+            let Some(pos) = rest.find(part) else {
+                return false;
+            };
+            rest = &rest[pos + part.len()..];
+        }
+    }
+    rest.is_empty()
+}
+
+fn list_runs(store: &RunStore, params: &[(String, String)]) -> ApiResponse {
+    let mut rows = match filtered_rows(store, params) {
+        Ok(rows) => rows,
+        Err(response) => return response,
+    };
+
+    let param = |name: &str| params.iter().find(|(key, _)| key == name).map(|(_, v)| v.as_str());
+
+    if let Some(order) = param("order") {
+        let (column, direction) = order.split_once('.').unwrap_or((order, "asc"));
+        rows.sort_by(|a, b| {
+            let a = a.get(column).unwrap_or(&Value::Null);
+            let b = b.get(column).unwrap_or(&Value::Null);
+            let ordering = match (a, b) {
+                (Value::Number(x), Value::Number(y)) => {
+                    x.as_f64().unwrap_or(0.0).total_cmp(&y.as_f64().unwrap_or(0.0))
+                }
+                (Value::String(x), Value::String(y)) => compare(a, y).unwrap_or_else(|| x.cmp(y)),
+                _ => Ordering::Equal,
+            };
+            if direction == "desc" {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    if let Some(offset) = param("offset").and_then(|o| o.parse::<usize>().ok()) {
+        rows.drain(..offset.min(rows.len()));
+    }
+    if let Some(limit) = param("limit").and_then(|l| l.parse::<usize>().ok()) {
+        rows.truncate(limit);
+    }
+
+    if let Some(select) = param("select").filter(|s| *s != "*") {
+        let columns: Vec<&str> = select.split(',').map(str::trim).collect();
+        for row in &mut rows {
+            if let Value::Object(map) = row {
+                map.retain(|key, _| columns.contains(&key.as_str()));
+            }
+        }
+    }
+
+    ApiResponse::json(200, Value::Array(rows))
+}
+
+fn upload_run(store: &RunStore, body: &str) -> ApiResponse {
+    let Ok(Value::Object(mut payload)) = serde_json::from_str::<Value>(body) else {
+        return ApiResponse::error(400, "Expected a JSON object");
+    };
+    payload.insert("id".to_string(), json!(uuid::Uuid::new_v4().to_string()));
+    payload.insert("uploaded_at".to_string(), json!(Utc::now()));
+
+    let delete_token = format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple());
+    payload.insert(TOKEN_HASH_COLUMN.to_string(), json!(store::hash_token(&delete_token)));
+
+    let row: RunRow = match serde_json::from_value(Value::Object(payload)) {
+        Ok(row) => row,
+        Err(e) => return ApiResponse::error(400, format!("Invalid benchmark run: {}", e)),
+    };
+    if row.category_results().is_none() {
+        return ApiResponse::error(400, "Invalid results");
+    }
+    // Unsigned uploads from older clients are accepted; edited signed ones are not
+    if row.signature_status() == SignatureStatus::Invalid {
+        return ApiResponse::error(400, "Signature does not match the uploaded results");
+    }
+
+    // The only time the token leaves the server
+    let mut created = serde_json::to_value(&row).unwrap_or_default();
+    if let Value::Object(map) = &mut created {
+        map.remove(TOKEN_HASH_COLUMN);
+        map.insert("delete_token".to_string(), json!(delete_token));
+    }
+
+    match store.insert(row) {
+        Ok(()) => ApiResponse::json(201, json!([created])),
+        Err(e) => ApiResponse::error(500, format!("Failed to store run: {}", e)),
+    }
+}
+
+fn delete_runs(store: &RunStore, params: &[(String, String)], delete_token: Option<&str>) -> ApiResponse {
+    let ids: Vec<String> = match filtered_rows(store, params) {
+        Ok(rows) => rows
+            .iter()
+            .filter_map(|row| row.get("id").and_then(Value::as_str).map(str::to_string))
+            .collect(),
+        Err(response) => return response,
+    };
+
+    // Like PostgREST, refuse to delete every row by accident
+    if params.iter().all(|(key, _)| matches!(key.as_str(), "select" | "order" | "limit" | "offset")) {
+        return ApiResponse::error(400, "DELETE requires a filter");
+    }
+
+    let Some(delete_token) = delete_token else {
+        return ApiResponse::error(401, "DELETE requires the upload's delete token");
+    };
+    let rows = store.rows();
+    if rows.iter().any(|row| ids.contains(&row.id) && !row.is_owned_by(delete_token)) {
+        return ApiResponse::error(403, "Invalid delete token");
+    }
+
+    match store.remove_where(|row| ids.contains(&row.id)) {
+        Ok(_) => ApiResponse::json(200, json!([])),
+        Err(e) => ApiResponse::error(500, format!("Failed to delete: {}", e)),
+    }
+}
+
+fn test_statistics(store: &RunStore, body: &str) -> ApiResponse {
+    let class = if body.trim().is_empty() {
+        HardwareClass::default()
+    } else {
+        match serde_json::from_str::<HardwareClass>(body) {
+            Ok(class) => class,
+            Err(e) => return ApiResponse::error(400, format!("Invalid hardware class: {}", e)),
+        }
+    };
+
+    // Rows whose system info can't be read only count towards the whole community
+    let everyone = class == HardwareClass::default();
+    let results: Vec<_> = store
+        .rows()
+        .iter()
+        .filter(|row| everyone || row.parsed_system_info().is_some_and(|info| class.matches(&info)))
+        .filter_map(RunRow::category_results)
+        .collect();
+    ApiResponse::json(200, json!(statistics::test_statistics(&results)))
+}
+
+fn percentile_rank(store: &RunStore, body: &str) -> ApiResponse {
+    let run_id = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("run_id").and_then(Value::as_str).map(str::to_string));
+    let Some(run_id) = run_id else {
+        return ApiResponse::error(400, "Expected {\"run_id\": ...}");
+    };
+
+    let rows = store.rows();
+    let Some(run) = rows.iter().find(|row| row.id == run_id).and_then(RunRow::category_results) else {
+        return ApiResponse::error(404, "Run not found");
+    };
+
+    let results: Vec<_> = rows.iter().filter_map(RunRow::category_results).collect();
+    ApiResponse::json(200, json!(statistics::percentile_ranks(&run, &results)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request<'a>(method: &'a str, url: &'a str, body: &'a str) -> ApiRequest<'a> {
+        ApiRequest {
+            method,
+            url,
+            api_key: Some("key"),
+            delete_token: None,
+            body,
+        }
+    }
+
+    fn upload_body(machine: &str, cpu: &str, read_mbps: f64) -> String {
+        json!({
+            "display_name": machine,
+            "machine_name": machine,
+            "run_timestamp": "2026-01-02T03:04:05+00:00",
+            "cpu_name": cpu,
+            "cpu_cores": 8,
+            "cpu_threads": 16,
+            "memory_gb": 32.0,
+            "os_name": "Windows 11",
+            "storage_type": "NVMe",
+            "results": {
+                "project_operations": [{
+                    "test_id": "large_file_read",
+                    "name": "Large File Read",
+                    "description": "",
+                    "value": read_mbps,
+                    "unit": "MB/s",
+                    "details": {
+                        "iterations": 1, "duration_secs": 1.0, "min": 0.0, "max": 0.0,
+                        "mean": 0.0, "median": 0.0, "std_dev": 0.0, "percentiles": null
+                    }
+                }],
+                "build_performance": [],
+                "responsiveness": []
+            },
+            "system_info": {}
+        })
+        .to_string()
+    }
+
+    fn store() -> RunStore {
+        let path = std::env::temp_dir().join(format!("workbench_server_{}.json", uuid::Uuid::new_v4()));
+        RunStore::open(path).unwrap()
+    }
+
+    #[test]
+    fn test_ilike() {
+        assert!(ilike("AMD Ryzen 9 7950X", "*ryzen*"));
+        assert!(ilike("Intel Core i7", "intel*"));
+        assert!(!ilike("Intel Core i7", "*ryzen*"));
+        assert!(!ilike("Intel Core i7", "core*"));
+    }
+
+    #[test]
+    fn test_rejects_wrong_key() {
+        let store = store();
+        let mut req = request("GET", RUNS_PATH, "");
+        req.api_key = None;
+        assert_eq!(handle(&store, Some("key"), &req).status, 401);
+        assert_eq!(handle(&store, None, &req).status, 200);
+    }
+
+    #[test]
+    fn test_rejects_broken_signature() {
+        let store = store();
+        let mut body: Value = serde_json::from_str(&upload_body("A", "AMD Ryzen", 1000.0)).unwrap();
+        body["signature"] = json!({ "content_hash": "00", "public_key": "00", "signature": "00" });
+
+        let response = handle(&store, Some("key"), &request("POST", RUNS_PATH, &body.to_string()));
+        assert_eq!(response.status, 400);
+        assert!(store.rows().is_empty());
+    }
+
+    #[test]
+    fn test_statistics_by_hardware_class() {
+        let store = store();
+        for (machine, cores, value) in [("A", 8, 1000.0), ("B", 8, 1200.0), ("C", 32, 4000.0)] {
+            let mut body: Value = serde_json::from_str(&upload_body(machine, "AMD Ryzen", value)).unwrap();
+            body["system_info"] = json!({
+                "hostname": machine,
+                "cpu": {
+                    "name": "AMD Ryzen", "vendor": "AuthenticAMD", "cores": cores, "threads": cores * 2,
+                    "base_frequency_mhz": 3000, "max_frequency_mhz": null, "cache_l3_kb": null
+                },
+                "memory": { "total_bytes": 32u64 << 30, "speed_mhz": null, "memory_type": null },
+                "storage": [],
+                "gpu": null,
+                "os": { "name": "Windows", "version": "11", "build": null }
+            });
+            let response = handle(&store, Some("key"), &request("POST", RUNS_PATH, &body.to_string()));
+            assert_eq!(response.status, 201);
+        }
+        handle(&store, Some("key"), &request("POST", RUNS_PATH, &upload_body("D", "Unknown", 500.0)));
+
+        let stats = |body: &str| -> Value {
+            serde_json::from_str(&handle(&store, Some("key"), &request("POST", STATISTICS_PATH, body)).body).unwrap()
+        };
+        assert_eq!(stats("{}")[0]["sample_count"], 4);
+        assert_eq!(stats(r#"{"cpu_vendor": "AMD", "core_band": "5-8"}"#)[0]["sample_count"], 2);
+        assert_eq!(stats(r#"{"core_band": "17+", "memory_band": "32"}"#)[0]["max_value"], 4000.0);
+        assert_eq!(handle(&store, Some("key"), &request("POST", STATISTICS_PATH, r#"{"core_band": 8}"#)).status, 400);
+    }
+
+    #[test]
+    fn test_upload_list_rank_delete() {
+        let store = store();
+        let mut tokens = Vec::new();
+        for (machine, cpu, value) in [("A", "AMD Ryzen", 1000.0), ("B", "Intel Core", 2000.0)] {
+            let response = handle(&store, Some("key"), &request("POST", RUNS_PATH, &upload_body(machine, cpu, value)));
+            assert_eq!(response.status, 201);
+            let created: Value = serde_json::from_str(&response.body).unwrap();
+            assert!(created[0].get(TOKEN_HASH_COLUMN).is_none());
+            tokens.push(created[0]["delete_token"].as_str().unwrap().to_string());
+        }
+
+        let url = format!("{}?select=id,cpu_name&cpu_name=ilike.*ryzen*&order=uploaded_at.desc&limit=5", RUNS_PATH);
+        let listed: Value = serde_json::from_str(&handle(&store, Some("key"), &request("GET", &url, "")).body).unwrap();
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+        assert_eq!(listed[0].as_object().unwrap().len(), 2);
+
+        // Second page of one run per page
+        let url = format!("{}?select=machine_name&order=machine_name.asc&limit=1&offset=1", RUNS_PATH);
+        let page: Value = serde_json::from_str(&handle(&store, Some("key"), &request("GET", &url, "")).body).unwrap();
+        assert_eq!(page[0]["machine_name"], "B");
+        let id = listed[0]["id"].as_str().unwrap().to_string();
+
+        // Timestamps match regardless of RFC 3339 formatting
+        let url = format!("{}?select=id&machine_name=eq.A&run_timestamp=eq.2026-01-02T03%3A04%3A05Z", RUNS_PATH);
+        let found: Value = serde_json::from_str(&handle(&store, Some("key"), &request("GET", &url, "")).body).unwrap();
+        assert_eq!(found[0]["id"], id.as_str());
+
+        let body = json!({ "run_id": id }).to_string();
+        let ranks: Value = serde_json::from_str(&handle(&store, Some("key"), &request("POST", PERCENTILE_RANK_PATH, &body)).body).unwrap();
+        assert_eq!(ranks[0]["beats_count"], 0);
+        assert_eq!(ranks[0]["total_count"], 2);
+
+        assert_eq!(handle(&store, Some("key"), &request("DELETE", RUNS_PATH, "")).status, 400);
+        let url = format!("{}?id=eq.{}", RUNS_PATH, id);
+        let mut delete = request("DELETE", &url, "");
+        assert_eq!(handle(&store, Some("key"), &delete).status, 401);
+        delete.delete_token = Some(&tokens[1]);
+        assert_eq!(handle(&store, Some("key"), &delete).status, 403);
+        delete.delete_token = Some(&tokens[0]);
+        assert_eq!(handle(&store, Some("key"), &delete).status, 200);
+        assert_eq!(store.rows().len(), 1);
+
+        // The stored hash never leaves the server
+        let listed = handle(&store, Some("key"), &request("GET", RUNS_PATH, "")).body;
+        assert!(!listed.contains(TOKEN_HASH_COLUMN));
+    }
+
+    #[test]
+    fn test_failed_write_stores_nothing() {
+        // A file where the store's directory should be makes every write fail
+        let blocker = std::env::temp_dir().join(format!("workbench_server_{}", uuid::Uuid::new_v4()));
+        std::fs::write(&blocker, "").unwrap();
+        let store = RunStore::open(blocker.join("runs.json")).unwrap();
+
+        let response = handle(&store, Some("key"), &request("POST", RUNS_PATH, &upload_body("A", "AMD Ryzen", 1000.0)));
+        assert_eq!(response.status, 500);
+        assert!(store.rows().is_empty());
+        let _ = std::fs::remove_file(&blocker);
+    }
+}
@@ -0,0 +1,108 @@
+//! WorkBench-Pro results server
+//!
+//! Self-hosted replacement for the community database: serves the API that
+//! `CloudClient` talks to, storing uploads in a local JSON file. Point the app
+//! at it with `WORKBENCH_PRO_CLOUD_URL` (and `WORKBENCH_PRO_CLOUD_KEY`).
+//!
+//! Configuration (environment):
+//! - `WORKBENCH_PRO_SERVER_ADDR` - listen address (default `0.0.0.0:8080`)
+//! - `WORKBENCH_PRO_SERVER_DATA` - data file (default `workbench-runs.json`)
+//! - `WORKBENCH_PRO_CLOUD_KEY` - API key clients must send (default: none, open server)
+
+mod api;
+mod store;
+
+use std::io::Read;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use tiny_http::{Header, Request, Response, Server};
+
+use api::{ApiRequest, ApiResponse};
+use store::RunStore;
+
+const DEFAULT_ADDR: &str = "0.0.0.0:8080";
+const DEFAULT_DATA: &str = "workbench-runs.json";
+/// Largest request body accepted; an upload with full system info is well below 1 MB
+const MAX_BODY_BYTES: u64 = 4 * 1024 * 1024;
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    let addr = std::env::var("WORKBENCH_PRO_SERVER_ADDR").unwrap_or_else(|_| DEFAULT_ADDR.to_string());
+    let data = std::env::var("WORKBENCH_PRO_SERVER_DATA").unwrap_or_else(|_| DEFAULT_DATA.to_string());
+    let api_key = std::env::var("WORKBENCH_PRO_CLOUD_KEY").ok().filter(|k| !k.is_empty());
+
+    let store = RunStore::open(PathBuf::from(&data))?;
+    let server = Server::http(&addr).map_err(|e| anyhow::anyhow!("Failed to listen on {}: {}", addr, e))?;
+
+    tracing::info!("Serving {} runs from {} on http://{}", store.rows().len(), data, addr);
+    if api_key.is_none() {
+        tracing::warn!("WORKBENCH_PRO_CLOUD_KEY is not set; the server accepts requests from anyone");
+    }
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let declared_too_large = request.body_length().is_some_and(|len| len as u64 > MAX_BODY_BYTES);
+        let read = if declared_too_large {
+            Ok(0)
+        } else {
+            // One byte past the limit tells an oversized body from one that just fits
+            request.as_reader().take(MAX_BODY_BYTES + 1).read_to_string(&mut body)
+        };
+
+        let response = if declared_too_large || body.len() as u64 > MAX_BODY_BYTES {
+            ApiResponse {
+                status: 413,
+                body: format!("{{\"message\": \"Request body exceeds {} bytes\"}}", MAX_BODY_BYTES),
+            }
+        } else if let Err(e) = read {
+            ApiResponse {
+                status: 400,
+                body: format!("{{\"message\": \"Unreadable body: {}\"}}", e),
+            }
+        } else {
+            let method = request.method().as_str().to_string();
+            let url = request.url().to_string();
+            let key = header(&request, "apikey");
+            let delete_token = header(&request, "x-delete-token");
+
+            api::handle(
+                &store,
+                api_key.as_deref(),
+                &ApiRequest {
+                    method: &method,
+                    url: &url,
+                    api_key: key.as_deref(),
+                    delete_token: delete_token.as_deref(),
+                    body: &body,
+                },
+            )
+        };
+
+        tracing::info!("{} {} -> {}", request.method(), request.url(), response.status);
+
+        let content_type = Header::from_bytes("Content-Type", "application/json").expect("valid header");
+        let reply = Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(content_type);
+        if let Err(e) = request.respond(reply) {
+            tracing::warn!("Failed to send response: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+fn header(request: &Request, name: &'static str) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv(name))
+        .map(|h| h.value.as_str().to_string())
+}
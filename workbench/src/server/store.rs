@@ -0,0 +1,134 @@
+//! File-backed storage of uploaded benchmark runs
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use workbench_pro::models::{CategoryResults, RunSignature, SignatureStatus, SystemInfo};
+
+/// One uploaded run, in the column layout of the `benchmark_runs` table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRow {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub machine_name: String,
+    pub run_timestamp: DateTime<Utc>,
+    pub cpu_name: String,
+    pub cpu_cores: i32,
+    pub cpu_threads: i32,
+    pub memory_gb: f64,
+    pub os_name: String,
+    pub storage_type: Option<String>,
+    pub results: serde_json::Value,
+    pub system_info: serde_json::Value,
+    pub uploaded_at: DateTime<Utc>,
+    #[serde(default)]
+    pub signature: Option<RunSignature>,
+    /// SHA-256 of the deletion token handed to the uploader; never sent to clients
+    #[serde(default)]
+    pub delete_token_hash: Option<String>,
+}
+
+impl RunRow {
+    /// Parsed results, or None if the row holds results of an unknown shape
+    pub fn category_results(&self) -> Option<CategoryResults> {
+        serde_json::from_value(self.results.clone()).ok()
+    }
+
+    /// Parsed system info, or None if the row holds system info of an unknown shape
+    pub fn parsed_system_info(&self) -> Option<SystemInfo> {
+        serde_json::from_value(self.system_info.clone()).ok()
+    }
+
+    /// Check the signature against the row's timestamp, results and system info
+    pub fn signature_status(&self) -> SignatureStatus {
+        let Some(signature) = &self.signature else {
+            return SignatureStatus::Unsigned;
+        };
+        let content = self.category_results().zip(self.parsed_system_info());
+        match content {
+            Some((results, system_info)) => {
+                signature.verify(&self.run_timestamp, &results, &system_info)
+            }
+            None => SignatureStatus::Invalid,
+        }
+    }
+
+    /// Whether `token` is the deletion token of this row
+    pub fn is_owned_by(&self, token: &str) -> bool {
+        self.delete_token_hash.as_deref() == Some(hash_token(token).as_str())
+    }
+}
+
+/// Hex SHA-256 of a deletion token, as stored in `delete_token_hash`
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// All uploaded runs, kept in memory and rewritten to a JSON file on every change
+pub struct RunStore {
+    path: PathBuf,
+    rows: Mutex<Vec<RunRow>>,
+}
+
+impl RunStore {
+    /// Open the store at `path`, starting empty if the file does not exist yet
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let rows = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            path,
+            rows: Mutex::new(rows),
+        })
+    }
+
+    /// Snapshot of all rows, oldest upload first
+    pub fn rows(&self) -> Vec<RunRow> {
+        self.rows.lock().unwrap().clone()
+    }
+
+    pub fn insert(&self, row: RunRow) -> Result<()> {
+        let mut rows = self.rows.lock().unwrap();
+        // Only keep in memory what made it to disk
+        let updated: Vec<&RunRow> = rows.iter().chain(std::iter::once(&row)).collect();
+        self.persist(&updated)?;
+        rows.push(row);
+        Ok(())
+    }
+
+    /// Remove rows matching `predicate`, returning how many were removed
+    pub fn remove_where(&self, predicate: impl Fn(&RunRow) -> bool) -> Result<usize> {
+        let mut rows = self.rows.lock().unwrap();
+        let kept: Vec<&RunRow> = rows.iter().filter(|row| !predicate(row)).collect();
+        let removed = rows.len() - kept.len();
+        if removed > 0 {
+            self.persist(&kept)?;
+            rows.retain(|row| !predicate(row));
+        }
+        Ok(removed)
+    }
+
+    /// Write atomically via a temporary file so a crash never leaves a truncated store
+    fn persist(&self, rows: &[&RunRow]) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(rows)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
@@ -3,7 +3,29 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use crate::models::BenchmarkRun;
+use crate::models::{BenchmarkRun, SignatureStatus};
+
+/// Platform-appropriate WorkBench-Pro data directory (history, settings)
+pub fn app_data_dir() -> PathBuf {
+    // Use platform-appropriate app data directory via environment variables only
+    let base = if cfg!(target_os = "windows") {
+        // Use LOCALAPPDATA or APPDATA environment variables
+        std::env::var_os("LOCALAPPDATA")
+            .or_else(|| std::env::var_os("APPDATA"))
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir)
+    } else if cfg!(target_os = "macos") {
+        dirs::home_dir()
+            .map(|h| h.join("Library/Application Support"))
+            .unwrap_or_else(std::env::temp_dir)
+    } else {
+        dirs::home_dir()
+            .map(|h| h.join(".local/share"))
+            .unwrap_or_else(std::env::temp_dir)
+    };
+
+    base.join("WorkBench-Pro")
+}
 
 /// Manages storage and retrieval of benchmark history
 pub struct HistoryStorage {
@@ -24,24 +46,7 @@ impl HistoryStorage {
 
     /// Get the storage directory path
     fn get_storage_dir() -> PathBuf {
-        // Use platform-appropriate app data directory via environment variables only
-        let base = if cfg!(target_os = "windows") {
-            // Use LOCALAPPDATA or APPDATA environment variables
-            std::env::var_os("LOCALAPPDATA")
-                .or_else(|| std::env::var_os("APPDATA"))
-                .map(PathBuf::from)
-                .unwrap_or_else(std::env::temp_dir)
-        } else if cfg!(target_os = "macos") {
-            dirs::home_dir()
-                .map(|h| h.join("Library/Application Support"))
-                .unwrap_or_else(std::env::temp_dir)
-        } else {
-            dirs::home_dir()
-                .map(|h| h.join(".local/share"))
-                .unwrap_or_else(std::env::temp_dir)
-        };
-
-        base.join("WorkBench-Pro").join("history")
+        app_data_dir().join("history")
     }
 
     /// Get the storage directory path (for debugging)
@@ -90,7 +95,13 @@ impl HistoryStorage {
                         match fs::read_to_string(&path) {
                             Ok(contents) => {
                                 match serde_json::from_str::<BenchmarkRun>(&contents) {
-                                    Ok(run) => runs.push(run),
+                                    Ok(mut run) => {
+                                        // Files may have been copied in from elsewhere; check they weren't edited
+                                        if run.verify_signature() == SignatureStatus::Invalid {
+                                            tracing::warn!("Signature mismatch in {}", path.display());
+                                        }
+                                        runs.push(run);
+                                    }
                                     Err(e) => {
                                         last_error = Some(format!(
                                             "Parse error in {}: {}",
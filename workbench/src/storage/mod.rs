@@ -1,3 +1,9 @@
 mod history;
+mod signing_key;
+mod statistics_cache;
+mod upload_queue;
 
-pub use history::HistoryStorage;
+pub use history::{app_data_dir, HistoryStorage};
+pub use signing_key::load_or_create_signing_key;
+pub use statistics_cache::StatisticsCache;
+pub use upload_queue::{PendingUpload, UploadQueue};
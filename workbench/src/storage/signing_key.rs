@@ -0,0 +1,96 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use super::history::app_data_dir;
+use crate::models::signature::{from_hex, to_hex};
+
+/// Location of this machine's result signing key
+fn key_path() -> PathBuf {
+    app_data_dir().join("signing_key")
+}
+
+/// Load the key that signs this machine's runs, generating it on first use
+pub fn load_or_create_signing_key() -> SigningKey {
+    let path = key_path();
+    match read_key(&path) {
+        Ok(key) => return key,
+        Err(e) if path.exists() => tracing::warn!("Replacing unreadable signing key {}: {}", path.display(), e),
+        Err(_) => {}
+    }
+
+    let key = SigningKey::generate(&mut OsRng);
+    if let Err(e) = write_key(&path, &key) {
+        // Runs are still signed this session, just with a key that won't be reused
+        tracing::error!("Failed to save signing key: {}", e);
+    }
+    key
+}
+
+fn read_key(path: &PathBuf) -> Result<SigningKey> {
+    let hex = fs::read_to_string(path)?;
+    let seed: [u8; 32] = from_hex(hex.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("expected 64 hex characters"))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn write_key(path: &PathBuf, key: &SigningKey) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let hex = to_hex(&key.to_bytes());
+
+    // Readable by this user only, from the moment the file exists
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+
+    // The mode only applies to new files; a key being replaced may be older
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+
+    file.write_all(hex.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupt_key_is_unreadable() {
+        let path = std::env::temp_dir().join(format!("workbench_signing_key_{}", uuid::Uuid::new_v4()));
+
+        // 64 bytes, but 32 two-byte characters
+        fs::write(&path, "é".repeat(32)).unwrap();
+        assert!(read_key(&path).is_err());
+        fs::write(&path, format!("+{}", "a".repeat(63))).unwrap();
+        assert!(read_key(&path).is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_key_file_is_private() {
+        let path = std::env::temp_dir().join(format!("workbench_signing_key_{}", uuid::Uuid::new_v4()));
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::write(&path, "stale").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        write_key(&path, &key).unwrap();
+
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        assert_eq!(read_key(&path).unwrap().to_bytes(), key.to_bytes());
+        let _ = fs::remove_file(&path);
+    }
+}
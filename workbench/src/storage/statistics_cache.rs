@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::history::app_data_dir;
+use crate::cloud::CommunityStatistics;
+
+/// Cached statistics older than this are refreshed when online
+const MAX_AGE_HOURS: i64 = 24;
+
+/// Community statistics as last downloaded, so percentile ranks work offline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatisticsCache {
+    pub fetched_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub community: CommunityStatistics,
+}
+
+impl StatisticsCache {
+    pub fn new(community: CommunityStatistics) -> Self {
+        Self {
+            fetched_at: Utc::now(),
+            community,
+        }
+    }
+
+    /// Load the cache from the app data directory, if one was saved and is readable
+    pub fn load() -> Option<Self> {
+        Self::load_from(&Self::cache_path())
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::cache_path())
+    }
+
+    /// Whether the statistics are old enough to download again
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at > Duration::hours(MAX_AGE_HOURS)
+    }
+
+    fn cache_path() -> PathBuf {
+        app_data_dir().join("community_statistics.json")
+    }
+
+    fn load_from(path: &PathBuf) -> Option<Self> {
+        let json = fs::read_to_string(path).ok()?;
+        match serde_json::from_str(&json) {
+            Ok(cache) => Some(cache),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable statistics cache {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    fn save_to(&self, path: &PathBuf) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_round_trip_and_staleness() {
+        let path = std::env::temp_dir().join(format!("workbench_statistics_cache_{}.json", uuid::Uuid::new_v4()));
+        assert!(StatisticsCache::load_from(&path).is_none());
+
+        let cache = StatisticsCache::new(CommunityStatistics::default());
+        cache.save_to(&path).unwrap();
+        let loaded = StatisticsCache::load_from(&path).unwrap();
+        assert_eq!(loaded.fetched_at, cache.fetched_at);
+
+        assert!(!loaded.is_stale(cache.fetched_at + Duration::hours(1)));
+        assert!(loaded.is_stale(cache.fetched_at + Duration::hours(MAX_AGE_HOURS + 1)));
+
+        let _ = fs::remove_file(&path);
+    }
+}
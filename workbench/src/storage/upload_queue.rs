@@ -0,0 +1,212 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::history::app_data_dir;
+use crate::models::BenchmarkRun;
+
+/// Delay before the first retry; doubles with every failed attempt
+const INITIAL_BACKOFF_SECS: i64 = 30;
+
+/// Longest delay between two attempts
+const MAX_BACKOFF_SECS: i64 = 60 * 60;
+
+/// A community upload waiting to be sent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingUpload {
+    /// Snapshot of the run as it was when queued
+    pub run: BenchmarkRun,
+    pub display_name: String,
+    pub user_name: Option<String>,
+    pub description: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    /// Failed attempts so far
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+}
+
+impl PendingUpload {
+    /// Whether the next attempt is due at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_attempt_at <= now
+    }
+}
+
+/// Persistent queue of community uploads, stored next to the history directory
+pub struct UploadQueue {
+    path: PathBuf,
+    entries: Vec<PendingUpload>,
+}
+
+impl UploadQueue {
+    /// Load the queue from the app data directory
+    pub fn new() -> Self {
+        Self::with_path(app_data_dir().join("upload_queue.json"))
+    }
+
+    /// Load the queue from `path`; a missing or unreadable file yields an empty queue
+    pub fn with_path(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| match serde_json::from_str(&json) {
+                Ok(entries) => Some(entries),
+                Err(e) => {
+                    tracing::warn!("Ignoring unreadable upload queue {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self { path, entries }
+    }
+
+    /// Queued uploads, oldest first
+    pub fn entries(&self) -> &[PendingUpload] {
+        &self.entries
+    }
+
+    /// Queue a run for upload, due immediately. A run already queued is replaced.
+    pub fn enqueue(
+        &mut self,
+        run: &BenchmarkRun,
+        display_name: &str,
+        user_name: Option<String>,
+        description: Option<String>,
+    ) -> Result<()> {
+        let now = Utc::now();
+        self.entries.retain(|e| e.run.id != run.id);
+        self.entries.push(PendingUpload {
+            run: run.clone(),
+            display_name: display_name.to_string(),
+            user_name,
+            description,
+            queued_at: now,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+        });
+        self.save()
+    }
+
+    /// The oldest upload that is due at `now`
+    pub fn next_due(&self, now: DateTime<Utc>) -> Option<&PendingUpload> {
+        self.entries.iter().find(|e| e.is_due(now))
+    }
+
+    /// Earliest time any queued upload becomes due
+    pub fn next_attempt_at(&self) -> Option<DateTime<Utc>> {
+        self.entries.iter().map(|e| e.next_attempt_at).min()
+    }
+
+    /// Record a failed attempt and schedule the next one with exponential backoff
+    pub fn record_failure(&mut self, run_id: Uuid, error: &str, now: DateTime<Utc>) -> Result<()> {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.run.id == run_id) {
+            entry.attempts += 1;
+            entry.next_attempt_at = now + Self::backoff(entry.attempts);
+            entry.last_error = Some(error.to_string());
+        }
+        self.save()
+    }
+
+    /// Make every queued upload due immediately
+    pub fn retry_now(&mut self) -> Result<()> {
+        let now = Utc::now();
+        for entry in &mut self.entries {
+            entry.next_attempt_at = now;
+        }
+        self.save()
+    }
+
+    /// Drop a run from the queue (uploaded, or deleted locally)
+    pub fn remove(&mut self, run_id: Uuid) -> Result<()> {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.run.id != run_id);
+        if self.entries.len() != before {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Delay after `attempts` consecutive failures
+    pub fn backoff(attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(16);
+        Duration::seconds((INITIAL_BACKOFF_SECS << exponent).min(MAX_BACKOFF_SECS))
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if self.entries.is_empty() {
+            if self.path.exists() {
+                fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+
+        fs::write(&self.path, serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+}
+
+impl Default for UploadQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SystemInfo;
+
+    fn create_run() -> BenchmarkRun {
+        BenchmarkRun::new(
+            "PC-A".to_string(),
+            SystemInfo::test_fixture("PC-A"),
+        )
+    }
+
+    fn queue_path() -> PathBuf {
+        std::env::temp_dir().join(format!("workbench_upload_queue_{}.json", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        assert_eq!(UploadQueue::backoff(1), Duration::seconds(30));
+        assert_eq!(UploadQueue::backoff(2), Duration::seconds(60));
+        assert_eq!(UploadQueue::backoff(4), Duration::seconds(240));
+        assert_eq!(UploadQueue::backoff(50), Duration::seconds(MAX_BACKOFF_SECS));
+    }
+
+    #[test]
+    fn test_queue_persists_and_schedules_retries() {
+        let path = queue_path();
+        let run = create_run();
+
+        let mut queue = UploadQueue::with_path(path.clone());
+        queue.enqueue(&run, "Desk", None, None).unwrap();
+        queue.enqueue(&run, "Desk", None, None).unwrap();
+        assert_eq!(queue.entries().len(), 1);
+
+        let now = Utc::now();
+        queue.record_failure(run.id, "offline", now).unwrap();
+        assert!(queue.next_due(now).is_none());
+        assert!(queue.next_due(now + Duration::seconds(30)).is_some());
+
+        // Survives a restart
+        let mut reloaded = UploadQueue::with_path(path.clone());
+        let entry = &reloaded.entries()[0];
+        assert_eq!(entry.attempts, 1);
+        assert_eq!(entry.last_error.as_deref(), Some("offline"));
+
+        reloaded.remove(run.id).unwrap();
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,245 @@
+//! Community browser - lists uploaded runs with filters and paging.
+
+use egui::{Align, Layout, RichText, Ui};
+
+use crate::cloud::{BrowseFilter, CommunityRun};
+use crate::models::BenchmarkRun;
+use crate::ui::Theme;
+
+/// Runs shown per page
+const PAGE_SIZE: usize = 20;
+
+/// Actions that can be triggered from the community view
+pub enum CommunityAction {
+    None,
+    Back,
+    Search,                     // Reload from the first page with the current filters
+    PreviousPage,
+    NextPage,
+    Open(String),               // Fetch and show the run with this remote ID
+}
+
+/// Filter inputs as typed by the user, and the current page
+#[derive(Debug, Clone, Default)]
+pub struct CommunityFilters {
+    pub cpu: String,
+    pub os: String,
+    pub min_memory_gb: String,
+    pub page: usize,
+}
+
+impl CommunityFilters {
+    /// Filter for the current page. Asks for one extra run to tell whether a next page exists.
+    pub fn browse_filter(&self) -> BrowseFilter {
+        let mut filter = BrowseFilter::new()
+            .with_limit(PAGE_SIZE + 1)
+            .with_offset(self.page * PAGE_SIZE);
+
+        if !self.cpu.trim().is_empty() {
+            filter = filter.with_cpu(self.cpu.trim());
+        }
+        if !self.os.trim().is_empty() {
+            filter = filter.with_os(self.os.trim());
+        }
+        if let Ok(gb) = self.min_memory_gb.trim().parse::<f64>() {
+            filter = filter.with_min_memory(gb);
+        }
+        filter
+    }
+}
+
+pub struct CommunityView;
+
+impl CommunityView {
+    /// `runs` is the last page as loaded, possibly with the extra look-ahead run
+    pub fn show(
+        ui: &mut Ui,
+        filters: &mut CommunityFilters,
+        runs: &[CommunityRun],
+        loading: Option<&str>,
+        error: Option<&str>,
+    ) -> CommunityAction {
+        let mut action = CommunityAction::None;
+        let has_next = runs.len() > PAGE_SIZE;
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.with_layout(Layout::top_down(Align::Center), |ui| {
+                ui.add_space(12.0);
+
+                // Title
+                ui.label(
+                    RichText::new("Community Results")
+                        .size(Theme::SIZE_SECTION)
+                        .strong()
+                        .color(Theme::ACCENT),
+                );
+                ui.label(
+                    RichText::new("Runs uploaded by other WorkBench-Pro users")
+                        .size(Theme::SIZE_CAPTION)
+                        .color(Theme::TEXT_SECONDARY),
+                );
+
+                ui.add_space(8.0);
+
+                // Filters
+                egui::Frame::none()
+                    .fill(Theme::BG_CARD)
+                    .stroke(egui::Stroke::new(1.0, Theme::BORDER))
+                    .rounding(Theme::CARD_ROUNDING)
+                    .inner_margin(8.0)
+                    .show(ui, |ui| {
+                        ui.set_min_width(550.0);
+                        ui.horizontal(|ui| {
+                            let mut submitted = false;
+                            let mut field = |ui: &mut Ui, label: &str, value: &mut String, hint: &str, width: f32| {
+                                ui.label(RichText::new(label).size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+                                let response = ui.add(egui::TextEdit::singleline(value).hint_text(hint).desired_width(width));
+                                submitted |= response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            };
+                            field(ui, "CPU:", &mut filters.cpu, "e.g. Ryzen", 110.0);
+                            field(ui, "OS:", &mut filters.os, "e.g. Windows 11", 100.0);
+                            field(ui, "Min RAM (GB):", &mut filters.min_memory_gb, "any", 40.0);
+
+                            if ui.button("Search").clicked() || submitted {
+                                action = CommunityAction::Search;
+                            }
+                        });
+                    });
+
+                ui.add_space(8.0);
+
+                if let Some(status) = loading {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(RichText::new(status).size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+                    });
+                    ui.add_space(4.0);
+                }
+
+                if let Some(err) = error {
+                    ui.label(RichText::new(err).size(Theme::SIZE_CAPTION).color(Theme::ERROR));
+                    ui.add_space(4.0);
+                }
+
+                if runs.is_empty() && loading.is_none() && error.is_none() {
+                    ui.label(
+                        RichText::new("No uploaded runs match these filters")
+                            .size(Theme::SIZE_BODY)
+                            .color(Theme::TEXT_SECONDARY),
+                    );
+                }
+
+                for run in runs.iter().take(PAGE_SIZE) {
+                    egui::Frame::none()
+                        .fill(Theme::BG_CARD)
+                        .stroke(egui::Stroke::new(1.0, Theme::BORDER))
+                        .rounding(Theme::CARD_ROUNDING)
+                        .inner_margin(8.0)
+                        .show(ui, |ui| {
+                            ui.set_min_width(550.0);
+                            ui.horizontal(|ui| {
+                                ui.vertical(|ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.label(
+                                            RichText::new(&run.display_name)
+                                                .size(Theme::SIZE_BODY)
+                                                .strong()
+                                                .color(Theme::TEXT_PRIMARY),
+                                        );
+                                        ui.add_space(8.0);
+                                        ui.label(
+                                            RichText::new(run.uploaded_at.format("%Y-%m-%d").to_string())
+                                                .size(Theme::SIZE_CAPTION)
+                                                .color(Theme::TEXT_SECONDARY),
+                                        );
+                                    });
+                                    ui.label(
+                                        RichText::new(Self::hardware_summary(run))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                });
+
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    if ui.add_enabled(loading.is_none(), egui::Button::new("View")).clicked() {
+                                        action = CommunityAction::Open(run.id.clone());
+                                    }
+                                });
+                            });
+                        });
+                    ui.add_space(4.0);
+                }
+
+                // Paging
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let idle = loading.is_none();
+                    if ui.add_enabled(idle && filters.page > 0, egui::Button::new("< Previous")).clicked() {
+                        action = CommunityAction::PreviousPage;
+                    }
+                    ui.label(
+                        RichText::new(format!("Page {}", filters.page + 1))
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::TEXT_SECONDARY),
+                    );
+                    if ui.add_enabled(idle && has_next, egui::Button::new("Next >")).clicked() {
+                        action = CommunityAction::NextPage;
+                    }
+                });
+
+                ui.add_space(12.0);
+
+                if ui.button("Back to Home").clicked() {
+                    action = CommunityAction::Back;
+                }
+
+                ui.add_space(12.0);
+            });
+        });
+
+        action
+    }
+
+    /// Picker for the local run to compare a community run against.
+    /// Returns true when Compare is clicked with a run selected.
+    pub fn show_compare_bar(ui: &mut Ui, local_runs: &[BenchmarkRun], selected: &mut Option<usize>) -> bool {
+        let mut compare = false;
+        let label = |run: &BenchmarkRun| format!("{} - {}", run.machine_name, run.timestamp.format("%Y-%m-%d %H:%M"));
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Compare with local run:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+
+            if local_runs.is_empty() {
+                ui.label(RichText::new("no saved runs").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+                return;
+            }
+
+            let current = selected.and_then(|idx| local_runs.get(idx)).map(label).unwrap_or_else(|| "Select a run".to_string());
+            egui::ComboBox::from_id_salt("community_compare_run")
+                .selected_text(current)
+                .width(240.0)
+                .show_ui(ui, |ui| {
+                    for (idx, run) in local_runs.iter().enumerate() {
+                        ui.selectable_value(selected, Some(idx), label(run));
+                    }
+                });
+
+            if ui.add_enabled(selected.is_some(), egui::Button::new("Compare")).clicked() {
+                compare = true;
+            }
+        });
+
+        compare
+    }
+
+    fn hardware_summary(run: &CommunityRun) -> String {
+        let mut summary = format!(
+            "{} ({}C/{}T) | {:.0} GB | {}",
+            run.cpu_name, run.cpu_cores, run.cpu_threads, run.memory_gb, run.os_name
+        );
+        if let Some(storage) = &run.storage_type {
+            summary.push_str(&format!(" | {}", storage));
+        }
+        summary
+    }
+}
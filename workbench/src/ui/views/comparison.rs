@@ -0,0 +1,123 @@
+//! Side-by-side comparison of a local run and a community run.
+
+use egui::{Align, Layout, RichText, Ui};
+
+use crate::models::{BenchmarkRun, ComparisonReport};
+use crate::ui::widgets::SignatureBadge;
+use crate::ui::Theme;
+
+/// Comparison View
+/// Layout:
+/// - Both machines with their signature status
+/// - One row per test both runs completed: values and relative change
+pub struct ComparisonView;
+
+impl ComparisonView {
+    /// Returns back_clicked
+    pub fn show(ui: &mut Ui, report: &ComparisonReport) -> bool {
+        let mut back_clicked = false;
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.with_layout(Layout::top_down(Align::Center), |ui| {
+                ui.add_space(12.0);
+
+                ui.label(
+                    RichText::new("Comparison")
+                        .size(Theme::SIZE_SECTION)
+                        .strong()
+                        .color(Theme::ACCENT),
+                );
+                Self::show_machine(ui, "Yours", &report.baseline);
+                Self::show_machine(ui, "Community", &report.comparison);
+
+                ui.add_space(8.0);
+
+                egui::Frame::none()
+                    .fill(Theme::BG_CARD)
+                    .stroke(egui::Stroke::new(1.0, Theme::BORDER))
+                    .rounding(Theme::CARD_ROUNDING)
+                    .inner_margin(12.0)
+                    .show(ui, |ui| {
+                        ui.set_min_width(600.0);
+
+                        if report.differences.is_empty() {
+                            ui.label(
+                                RichText::new("The runs have no tests in common")
+                                    .size(Theme::SIZE_BODY)
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
+                            return;
+                        }
+
+                        egui::Grid::new("comparison_grid")
+                            .num_columns(4)
+                            .striped(true)
+                            .spacing([16.0, 6.0])
+                            .show(ui, |ui| {
+                                for header in ["Test", "Yours", "Community", "Community vs yours"] {
+                                    ui.label(RichText::new(header).size(Theme::SIZE_CAPTION).strong());
+                                }
+                                ui.end_row();
+
+                                for diff in &report.differences {
+                                    let unit = Self::unit(&report.baseline, &diff.test_id);
+                                    ui.label(RichText::new(&diff.name).size(Theme::SIZE_CAPTION));
+                                    ui.label(RichText::new(format!("{:.2} {}", diff.baseline_value, unit)).size(Theme::SIZE_CAPTION));
+                                    ui.label(RichText::new(format!("{:.2} {}", diff.comparison_value, unit)).size(Theme::SIZE_CAPTION));
+
+                                    // Green when the community machine does better
+                                    let color = if diff.difference_percent.abs() < 1.0 {
+                                        Theme::NEUTRAL
+                                    } else if diff.is_improvement {
+                                        Theme::BETTER
+                                    } else {
+                                        Theme::WORSE
+                                    };
+                                    ui.label(
+                                        RichText::new(format!("{:+.1}% ({:.2}x)", diff.difference_percent, diff.multiplier()))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(color),
+                                    );
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                ui.add_space(12.0);
+
+                if ui.button("Back").clicked() {
+                    back_clicked = true;
+                }
+
+                ui.add_space(12.0);
+            });
+        });
+
+        back_clicked
+    }
+
+    fn show_machine(ui: &mut Ui, role: &str, run: &BenchmarkRun) {
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!(
+                    "{}: {} - {} ({})",
+                    role,
+                    run.machine_name,
+                    run.system_info.cpu.name,
+                    run.timestamp.format("%Y-%m-%d")
+                ))
+                .size(Theme::SIZE_CAPTION)
+                .color(Theme::TEXT_SECONDARY),
+            );
+            ui.add(SignatureBadge::new(run.signature_status));
+        });
+    }
+
+    fn unit<'a>(run: &'a BenchmarkRun, test_id: &str) -> &'a str {
+        run.results
+            .iter()
+            .find(|r| r.test_id == test_id)
+            .map(|r| r.unit.as_str())
+            .unwrap_or("")
+    }
+}
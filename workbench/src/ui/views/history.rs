@@ -1,6 +1,11 @@
 use egui::{Align, Layout, RichText, Ui};
 
+use chrono::Utc;
+
+use crate::core::EtaEstimator;
 use crate::models::BenchmarkRun;
+use crate::storage::PendingUpload;
+use crate::ui::widgets::SignatureBadge;
 use crate::ui::Theme;
 
 /// Actions that can be triggered from the history view
@@ -11,6 +16,8 @@ pub enum HistoryAction {
     Upload(usize),              // Upload run at index to community
     RemoveUpload(usize),        // Remove uploaded run from community
     DeleteRun(usize),
+    Trends,                     // Open performance trends over all runs
+    RetryUploads,               // Retry all queued uploads now
 }
 
 pub struct HistoryView;
@@ -22,6 +29,7 @@ impl HistoryView {
         storage_path: Option<&std::path::Path>,
         save_error: Option<&str>,
         load_stats: Option<&(usize, usize, Option<String>)>,
+        pending_uploads: &[PendingUpload],
     ) -> HistoryAction {
         let mut action = HistoryAction::None;
 
@@ -79,6 +87,21 @@ impl HistoryView {
                     );
                 }
 
+                // Upload queue summary
+                if !pending_uploads.is_empty() {
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!("{} upload(s) queued", pending_uploads.len()))
+                                .size(Theme::SIZE_CAPTION)
+                                .color(Theme::WARNING),
+                        );
+                        if ui.small_button("Retry now").clicked() {
+                            action = HistoryAction::RetryUploads;
+                        }
+                    });
+                }
+
                 ui.add_space(8.0);
 
                 // History list
@@ -133,6 +156,8 @@ impl HistoryView {
                                             .size(Theme::SIZE_CAPTION)
                                             .color(Theme::TEXT_SECONDARY),
                                         );
+                                        ui.add_space(8.0);
+                                        ui.add(SignatureBadge::new(run.signature_status));
                                         if run.uploaded_at.is_some() {
                                             ui.add_space(8.0);
                                             ui.label(
@@ -140,6 +165,9 @@ impl HistoryView {
                                                     .size(Theme::SIZE_CAPTION)
                                                     .color(Theme::SUCCESS),
                                             );
+                                        } else if let Some(pending) = pending_uploads.iter().find(|p| p.run.id == run.id) {
+                                            ui.add_space(8.0);
+                                            Self::show_pending_upload(ui, pending);
                                         }
                                     });
 
@@ -215,8 +243,10 @@ impl HistoryView {
 
                                     ui.add_space(4.0);
 
-                                    // Upload button (only if not already uploaded)
-                                    if run.uploaded_at.is_none() {
+                                    let is_queued = pending_uploads.iter().any(|p| p.run.id == run.id);
+
+                                    // Upload button (only if not already uploaded or queued)
+                                    if run.uploaded_at.is_none() && !is_queued {
                                         let upload_btn = egui::Button::new(
                                             RichText::new("Upload")
                                                 .size(Theme::SIZE_CAPTION)
@@ -230,7 +260,7 @@ impl HistoryView {
                                         }
 
                                         ui.add_space(4.0);
-                                    } else {
+                                    } else if run.uploaded_at.is_some() {
                                         // Show remove upload button
                                         let remove_btn = egui::Button::new(
                                             RichText::new("Remove Upload")
@@ -275,16 +305,31 @@ impl HistoryView {
 
                 ui.add_space(12.0);
 
-                // Back button
-                let back_btn = egui::Button::new(
-                    RichText::new("Back to Home").size(Theme::SIZE_BODY),
-                )
-                .min_size(egui::vec2(100.0, 32.0))
-                .rounding(Theme::CARD_ROUNDING);
+                ui.horizontal(|ui| {
+                    // Back button
+                    let back_btn = egui::Button::new(
+                        RichText::new("Back to Home").size(Theme::SIZE_BODY),
+                    )
+                    .min_size(egui::vec2(100.0, 32.0))
+                    .rounding(Theme::CARD_ROUNDING);
 
-                if ui.add(back_btn).clicked() {
-                    action = HistoryAction::Back;
-                }
+                    if ui.add(back_btn).clicked() {
+                        action = HistoryAction::Back;
+                    }
+
+                    ui.add_space(6.0);
+
+                    // Trends button (needs at least one run to plot)
+                    let trends_btn = egui::Button::new(
+                        RichText::new("View Trends").size(Theme::SIZE_BODY),
+                    )
+                    .min_size(egui::vec2(100.0, 32.0))
+                    .rounding(Theme::CARD_ROUNDING);
+
+                    if ui.add_enabled(!runs.is_empty(), trends_btn).clicked() {
+                        action = HistoryAction::Trends;
+                    }
+                });
 
                 ui.add_space(12.0);
             });
@@ -292,4 +337,27 @@ impl HistoryView {
 
         action
     }
+
+    /// Queue state of a run that is waiting to be uploaded
+    fn show_pending_upload(ui: &mut Ui, pending: &PendingUpload) {
+        let text = if pending.attempts == 0 {
+            "Upload queued".to_string()
+        } else {
+            let wait = (pending.next_attempt_at - Utc::now()).num_seconds().max(0) as f64;
+            format!(
+                "Upload failed {}x, retry in {}",
+                pending.attempts,
+                EtaEstimator::format_remaining(wait)
+            )
+        };
+
+        let label = ui.label(
+            RichText::new(text)
+                .size(Theme::SIZE_CAPTION)
+                .color(Theme::WARNING),
+        );
+        if let Some(ref err) = pending.last_error {
+            label.on_hover_text(err);
+        }
+    }
 }
@@ -9,6 +9,7 @@ pub enum HomeAction {
     None,
     Run,
     History,
+    Community,
     /// Run a single test by index (debug feature only)
     #[cfg(feature = "debug-logging")]
     RunSingleTest(usize),
@@ -311,7 +312,19 @@ impl HomeView {
                                 ui.label(RichText::new("CPU:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
                                 ui.label(RichText::new(&system_info.cpu.name).size(Theme::SIZE_CAPTION));
                                 ui.label(RichText::new("Cores:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
-                                ui.label(RichText::new(format!("{}/{}", system_info.cpu.cores, system_info.cpu.threads)).size(Theme::SIZE_CAPTION));
+                                // Hybrid CPUs show their split, e.g. "14/20 (6P+8E)"
+                                let split = if system_info.cpu.is_hybrid() {
+                                    let groups: Vec<String> = system_info
+                                        .cpu
+                                        .core_groups
+                                        .iter()
+                                        .map(|g| format!("{}{}", g.cores, &g.kind.label()[..1]))
+                                        .collect();
+                                    format!(" ({})", groups.join("+"))
+                                } else {
+                                    String::new()
+                                };
+                                ui.label(RichText::new(format!("{}/{}{}", system_info.cpu.cores, system_info.cpu.threads, split)).size(Theme::SIZE_CAPTION));
                             });
                     });
                     ui.horizontal(|ui| {
@@ -321,16 +334,56 @@ impl HomeView {
                             .spacing([8.0, 2.0])
                             .show(ui, |ui| {
                                 ui.label(RichText::new("RAM:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
-                                ui.label(RichText::new(format!("{:.0}GB", system_info.memory.total_gb())).size(Theme::SIZE_CAPTION));
+                                let memory = &system_info.memory;
+                                let memory_type = match (&memory.memory_type, memory.speed_mhz) {
+                                    (Some(kind), Some(speed)) => format!(" {}-{}", kind, speed),
+                                    (Some(kind), None) => format!(" {}", kind),
+                                    _ => String::new(),
+                                };
+                                let channels = match memory.channels {
+                                    Some(1) => " (single channel)".to_string(),
+                                    Some(n) => format!(" ({} channels)", n),
+                                    None => String::new(),
+                                };
+                                ui.label(RichText::new(format!("{:.0}GB{}{}", memory.total_gb(), memory_type, channels)).size(Theme::SIZE_CAPTION));
                                 ui.label(RichText::new("OS:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
                                 ui.label(RichText::new(format!("{} {}", system_info.os.name, system_info.os.version)).size(Theme::SIZE_CAPTION));
                                 if !system_info.storage.is_empty() {
                                     let storage = &system_info.storage[0];
                                     ui.label(RichText::new("Disk:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
-                                    ui.label(RichText::new(format!("{} {:.0}GB", storage.device_type.label(), storage.capacity_gb())).size(Theme::SIZE_CAPTION));
+                                    let file_system = storage.file_system.as_deref().map(|fs| format!(" ({})", fs)).unwrap_or_default();
+                                    ui.label(RichText::new(format!("{} {:.0}GB{}", storage.device_type.label(), storage.capacity_gb(), file_system)).size(Theme::SIZE_CAPTION));
                                 }
                             });
                     });
+                    if let Some(gpu) = &system_info.gpu {
+                        ui.horizontal(|ui| {
+                            ui.add_space(48.0); // Align with content above
+                            ui.label(RichText::new("GPU:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+                            let vram = gpu.vram_gb().map(|gb| format!(" {:.0}GB", gb)).unwrap_or_default();
+                            ui.label(RichText::new(format!("{}{}", gpu.name, vram)).size(Theme::SIZE_CAPTION));
+                        });
+                    }
+                    let virtualization = &system_info.virtualization;
+                    if virtualization.is_virtualized() {
+                        ui.horizontal(|ui| {
+                            ui.add_space(48.0); // Align with content above
+                            ui.label(RichText::new("Env:").size(Theme::SIZE_CAPTION).color(Theme::TEXT_SECONDARY));
+                            let mut parts: Vec<String> = virtualization
+                                .hypervisor
+                                .iter()
+                                .chain(virtualization.container.iter())
+                                .cloned()
+                                .collect();
+                            if let Some(quota) = virtualization.cpu_quota {
+                                parts.push(format!("{:.1} CPU quota", quota));
+                            }
+                            if let Some(limit) = virtualization.memory_limit_bytes {
+                                parts.push(format!("{:.1}GB memory limit", limit as f64 / (1024.0 * 1024.0 * 1024.0)));
+                            }
+                            ui.label(RichText::new(parts.join(", ")).size(Theme::SIZE_CAPTION));
+                        });
+                    }
                 });
 
             // Buttons
@@ -359,6 +412,16 @@ impl HomeView {
                     action = HomeAction::History;
                 }
 
+                let community_button = egui::Button::new(
+                    RichText::new("Community").size(Theme::SIZE_CAPTION),
+                )
+                .min_size(egui::vec2(70.0, 28.0))
+                .rounding(4.0);
+
+                if ui.add(community_button).clicked() {
+                    action = HomeAction::Community;
+                }
+
                 ui.add_space(20.0);
 
                 // Safe mode toggle button
@@ -1,11 +1,17 @@
+mod community;
+mod comparison;
 mod history;
 mod home;
 mod precheck;
 mod results;
 mod running;
+mod trends;
 
+pub use community::{CommunityAction, CommunityFilters, CommunityView};
+pub use comparison::ComparisonView;
 pub use history::{HistoryAction, HistoryView};
 pub use home::{HomeAction, HomeView};
 pub use precheck::{PreCheckAction, PreCheckView};
 pub use results::{ResultsAction, ResultsView};
 pub use running::RunningView;
+pub use trends::TrendsView;
@@ -2,7 +2,11 @@
 
 use egui::{Align, Layout, RichText, Ui};
 
-use crate::core::system_check::{SystemCheckResult, WarningSeverity};
+use crate::core::system_check::{
+    SystemCheckResult, WarningSeverity, BYTES_PER_GB, CPU_PRESSURE_WARNING, CPU_USAGE_CRITICAL, CPU_USAGE_WARNING,
+    DISK_BUSY_CRITICAL, DISK_BUSY_WARNING, HOT_TEMP_C, IO_PRESSURE_WARNING, LOW_DISK_SPACE_GB, LOW_MEMORY_CRITICAL_GB,
+    LOW_MEMORY_WARNING_GB, MEMORY_PRESSURE_WARNING, SWAP_PAGES_CRITICAL, SWAP_PAGES_WARNING, WARM_TEMP_C,
+};
 use crate::ui::Theme;
 
 /// Actions from the pre-check view
@@ -16,6 +20,10 @@ pub enum PreCheckAction {
     Recheck,
     /// User wants to proceed with benchmark (even with warnings)
     Proceed,
+    /// User wants the benchmark to start once the system has been idle for the quiet period
+    WaitForQuiet,
+    /// User wants to switch to Safe Mode's smaller tests, which fit in the free space
+    ProceedInSafeMode,
 }
 
 /// Pre-check view that shows system readiness status
@@ -25,12 +33,13 @@ impl PreCheckView {
     /// Show the pre-check view
     /// Returns the action requested by the user
     /// If check_result is None, shows a loading state
-    pub fn show(ui: &mut Ui, check_result: Option<&SystemCheckResult>) -> PreCheckAction {
+    /// `quiet_period_secs` is the idle time "Start When Idle" waits for, editable here
+    pub fn show(ui: &mut Ui, check_result: Option<&SystemCheckResult>, quiet_period_secs: &mut u32) -> PreCheckAction {
         let mut action = PreCheckAction::None;
 
         // Calculate needed height based on content
         let needed_height = if let Some(result) = check_result {
-            let base_height = 320.0; // Title + status card + buttons + padding
+            let base_height = 400.0; // Title + status card + buttons + padding
             let processes_height = if !result.high_cpu_processes.is_empty() {
                 100.0 + (result.high_cpu_processes.len() as f32 * 24.0)
             } else {
@@ -146,9 +155,9 @@ impl PreCheckView {
                                         .size(Theme::SIZE_CAPTION)
                                         .color(Theme::TEXT_SECONDARY),
                                 );
-                                let cpu_color = if check_result.cpu_usage_percent > 50.0 {
+                                let cpu_color = if check_result.cpu_usage_percent > CPU_USAGE_CRITICAL {
                                     Theme::ERROR
-                                } else if check_result.cpu_usage_percent > 20.0 {
+                                } else if check_result.cpu_usage_percent > CPU_USAGE_WARNING {
                                     Theme::WARNING
                                 } else {
                                     Theme::SUCCESS
@@ -166,9 +175,9 @@ impl PreCheckView {
                                         .size(Theme::SIZE_CAPTION)
                                         .color(Theme::TEXT_SECONDARY),
                                 );
-                                let mem_color = if check_result.available_memory_gb < 2.0 {
+                                let mem_color = if check_result.available_memory_gb < LOW_MEMORY_CRITICAL_GB {
                                     Theme::ERROR
-                                } else if check_result.available_memory_gb < 4.0 {
+                                } else if check_result.available_memory_gb < LOW_MEMORY_WARNING_GB {
                                     Theme::WARNING
                                 } else {
                                     Theme::SUCCESS
@@ -180,6 +189,118 @@ impl PreCheckView {
                                 );
                                 ui.end_row();
 
+                                // Swap
+                                if check_result.swap_total_gb > 0.0 {
+                                    ui.label(
+                                        RichText::new("Swap Used:")
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                    let swap_color = match check_result.activity.swap_pages_per_sec {
+                                        Some(pages) if pages >= SWAP_PAGES_CRITICAL => Theme::ERROR,
+                                        Some(pages) if pages >= SWAP_PAGES_WARNING => Theme::WARNING,
+                                        _ => Theme::SUCCESS,
+                                    };
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "{:.1} / {:.1} GB",
+                                            check_result.swap_used_gb, check_result.swap_total_gb
+                                        ))
+                                        .size(Theme::SIZE_CAPTION)
+                                        .color(swap_color),
+                                    );
+                                    ui.end_row();
+                                }
+
+                                // Disk Activity
+                                if let Some(busy) = check_result.activity.disk_busy_percent {
+                                    ui.label(
+                                        RichText::new("Disk Activity:")
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                    let disk_color = if busy >= DISK_BUSY_CRITICAL {
+                                        Theme::ERROR
+                                    } else if busy >= DISK_BUSY_WARNING {
+                                        Theme::WARNING
+                                    } else {
+                                        Theme::SUCCESS
+                                    };
+                                    ui.label(
+                                        RichText::new(format!("{:.0}% busy", busy))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(disk_color),
+                                    );
+                                    ui.end_row();
+                                }
+
+                                // Pressure
+                                if let Some(pressure) = check_result.pressure {
+                                    ui.label(
+                                        RichText::new("Pressure:")
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                    let stalls: Vec<String> = [("CPU", pressure.cpu), ("I/O", pressure.io), ("Mem", pressure.memory)]
+                                        .iter()
+                                        .filter_map(|(name, stall)| stall.map(|s| format!("{} {:.0}%", name, s)))
+                                        .collect();
+                                    let stalled = pressure.cpu.is_some_and(|s| s >= CPU_PRESSURE_WARNING)
+                                        || pressure.io.is_some_and(|s| s >= IO_PRESSURE_WARNING)
+                                        || pressure.memory.is_some_and(|s| s >= MEMORY_PRESSURE_WARNING);
+                                    ui.label(
+                                        RichText::new(stalls.join("  "))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(if stalled { Theme::WARNING } else { Theme::SUCCESS }),
+                                    );
+                                    ui.end_row();
+                                }
+
+                                // Free Space
+                                if let Some(free_gb) = check_result.test_volume_free_gb {
+                                    ui.label(
+                                        RichText::new("Free Space:")
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                    let needed_gb = check_result.scratch.selected_bytes as f64 / BYTES_PER_GB;
+                                    let space_color = if !check_result.scratch_fits() {
+                                        Theme::ERROR
+                                    } else if free_gb - needed_gb < LOW_DISK_SPACE_GB {
+                                        Theme::WARNING
+                                    } else {
+                                        Theme::SUCCESS
+                                    };
+                                    ui.label(
+                                        RichText::new(format!("{:.1} GB ({:.1} GB needed)", free_gb, needed_gb))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(space_color),
+                                    );
+                                    ui.end_row();
+                                }
+
+                                // CPU Temperature
+                                if let Some(temp) = check_result.cpu_temperature_c {
+                                    ui.label(
+                                        RichText::new("CPU Temperature:")
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(Theme::TEXT_SECONDARY),
+                                    );
+                                    let temp_color = if temp >= HOT_TEMP_C {
+                                        Theme::ERROR
+                                    } else if temp >= WARM_TEMP_C {
+                                        Theme::WARNING
+                                    } else {
+                                        Theme::SUCCESS
+                                    };
+                                    ui.label(
+                                        RichText::new(format!("{:.0}°C", temp))
+                                            .size(Theme::SIZE_CAPTION)
+                                            .color(temp_color),
+                                    );
+                                    ui.end_row();
+                                }
+
                                 // Power State
                                 ui.label(
                                     RichText::new("Power State:")
@@ -367,6 +488,31 @@ impl PreCheckView {
 
                     ui.add_space(8.0);
 
+                    // Wait-for-quiet button, with the quiet period it waits for
+                    let wait_btn = egui::Button::new(
+                        RichText::new("Start When Idle").size(Theme::SIZE_BODY),
+                    )
+                    .min_size(egui::vec2(110.0, 32.0))
+                    .rounding(Theme::CARD_ROUNDING);
+
+                    // Without room for the test files the run would fail part way
+                    let has_room = check_result.scratch_fits();
+
+                    if ui
+                        .add_enabled(has_room, wait_btn)
+                        .on_hover_text("Keep watching CPU, disk and pressure, and start once the system has been quiet this long")
+                        .clicked()
+                    {
+                        action = PreCheckAction::WaitForQuiet;
+                    }
+                    ui.add(
+                        egui::DragValue::new(quiet_period_secs)
+                            .range(5..=600)
+                            .suffix(" s"),
+                    );
+
+                    ui.add_space(8.0);
+
                     // Proceed button
                     let proceed_text = if check_result.ready_to_benchmark {
                         "Start Benchmark"
@@ -388,9 +534,32 @@ impl PreCheckView {
                     .fill(proceed_color)
                     .rounding(Theme::CARD_ROUNDING);
 
-                    if ui.add(proceed_btn).clicked() {
+                    if ui.add_enabled(has_room, proceed_btn).clicked() {
                         action = PreCheckAction::Proceed;
                     }
+
+                    // Offer the smaller tests when only they fit
+                    let scratch = check_result.scratch;
+                    if !has_room && check_result.lite_scratch_fits() && scratch.lite_bytes < scratch.selected_bytes {
+                        ui.add_space(8.0);
+
+                        let safe_mode_btn = egui::Button::new(
+                            RichText::new("Use Safe Mode")
+                                .size(Theme::SIZE_BODY)
+                                .color(egui::Color32::WHITE),
+                        )
+                        .min_size(egui::vec2(120.0, 32.0))
+                        .fill(Theme::WARNING)
+                        .rounding(Theme::CARD_ROUNDING);
+
+                        if ui
+                            .add(safe_mode_btn)
+                            .on_hover_text("Run the Lite tests, whose smaller files fit in the free space")
+                            .clicked()
+                        {
+                            action = PreCheckAction::ProceedInSafeMode;
+                        }
+                    }
                 });
 
                 ui.add_space(16.0);
@@ -1,8 +1,13 @@
-use egui::{Align, Layout, RichText, Ui};
-
-use crate::core::{RecommendationCategory, RecommendationPriority, RecommendationsReport};
-use crate::models::BenchmarkRun;
-use crate::ui::widgets::{CategorySummaryCard, MachineInfoCard};
+use egui::{Align, Color32, Layout, RichText, Ui};
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints, VLine};
+
+use crate::benchmarks::Category;
+use crate::cloud::{estimate_percentile_rank, CommunityStatistics, PercentileRank, TestStatistics};
+use crate::core::{
+    RecommendationCategory, RecommendationPriority, RecommendationsReport, RunScore,
+};
+use crate::models::{BenchmarkRun, Percentiles, SystemInfo, TestResult};
+use crate::ui::widgets::{CategorySummaryCard, MachineInfoCard, SignatureBadge};
 use crate::ui::Theme;
 
 /// Actions from the results view for online features
@@ -13,17 +18,29 @@ pub enum ResultsAction {
     Upload,
 }
 
-/// Results View - displays raw benchmark values without scores
+/// Results View - displays raw benchmark values alongside reference scores
 /// Layout:
-/// - Machine info header with timestamp
-/// - Category summary cards
+/// - Machine info header with timestamp and overall score
+/// - Category summary cards with category scores
 /// - Expandable detail sections with raw values
+/// - Per-test distribution drill-downs (histogram, CDF, percentile ladder)
 /// - Export/Compare buttons
 pub struct ResultsView;
 
+/// Number of histogram bins for per-test sample distributions
+const HISTOGRAM_BINS: usize = 30;
+
+/// Color for community distribution overlays
+const COMMUNITY_COLOR: Color32 = Color32::from_rgb(245, 158, 11);
+
 impl ResultsView {
     /// Returns back_clicked
-    pub fn show(ui: &mut Ui, run: &BenchmarkRun) -> bool {
+    pub fn show(
+        ui: &mut Ui,
+        run: &BenchmarkRun,
+        score: &RunScore,
+        community_stats: Option<&CommunityStatistics>,
+    ) -> bool {
         let mut back_clicked = false;
 
         egui::ScrollArea::vertical().show(ui, |ui| {
@@ -50,6 +67,9 @@ impl ResultsView {
                     &run.timestamp.format("%Y-%m-%d %H:%M").to_string(),
                     total_tests,
                 ));
+                ui.add(SignatureBadge::new(run.signature_status));
+
+                Self::show_overall_score(ui, score);
 
                 ui.add_space(16.0);
 
@@ -66,7 +86,7 @@ impl ResultsView {
                     ui.add_space(8.0);
 
                     if !run.results.project_operations.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.project_operations);
+                        let summary = Self::get_category_summary(score, Category::ProjectOperations, &run.results.project_operations);
                         ui.add(CategorySummaryCard::new(
                             "Project Operations",
                             run.results.project_operations.len(),
@@ -76,7 +96,7 @@ impl ResultsView {
                     }
 
                     if !run.results.build_performance.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.build_performance);
+                        let summary = Self::get_category_summary(score, Category::BuildPerformance, &run.results.build_performance);
                         ui.add(CategorySummaryCard::new(
                             "Build Performance",
                             run.results.build_performance.len(),
@@ -86,7 +106,7 @@ impl ResultsView {
                     }
 
                     if !run.results.responsiveness.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.responsiveness);
+                        let summary = Self::get_category_summary(score, Category::Responsiveness, &run.results.responsiveness);
                         ui.add(CategorySummaryCard::new(
                             "Responsiveness",
                             run.results.responsiveness.len(),
@@ -106,9 +126,9 @@ impl ResultsView {
                 );
                 ui.add_space(8.0);
 
-                Self::show_category_details(ui, "Project Operations", &run.results.project_operations);
-                Self::show_category_details(ui, "Build Performance", &run.results.build_performance);
-                Self::show_category_details(ui, "Responsiveness", &run.results.responsiveness);
+                Self::show_category_details(ui, "Project Operations", &run.results.project_operations, score, community_stats, &run.system_info);
+                Self::show_category_details(ui, "Build Performance", &run.results.build_performance, score, community_stats, &run.system_info);
+                Self::show_category_details(ui, "Responsiveness", &run.results.responsiveness, score, community_stats, &run.system_info);
 
                 ui.add_space(16.0);
 
@@ -134,7 +154,9 @@ impl ResultsView {
     pub fn show_with_save(
         ui: &mut Ui,
         run: &BenchmarkRun,
+        score: &RunScore,
         recommendations: Option<&RecommendationsReport>,
+        community_stats: Option<&CommunityStatistics>,
     ) -> ResultsAction {
         let mut action = ResultsAction::None;
 
@@ -174,6 +196,9 @@ impl ResultsView {
                     &run.timestamp.format("%Y-%m-%d %H:%M").to_string(),
                     total_tests,
                 ));
+                ui.add(SignatureBadge::new(run.signature_status));
+
+                Self::show_overall_score(ui, score);
 
                 ui.add_space(16.0);
 
@@ -190,7 +215,7 @@ impl ResultsView {
                     ui.add_space(8.0);
 
                     if !run.results.project_operations.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.project_operations);
+                        let summary = Self::get_category_summary(score, Category::ProjectOperations, &run.results.project_operations);
                         ui.add(CategorySummaryCard::new(
                             "Project Operations",
                             run.results.project_operations.len(),
@@ -200,7 +225,7 @@ impl ResultsView {
                     }
 
                     if !run.results.build_performance.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.build_performance);
+                        let summary = Self::get_category_summary(score, Category::BuildPerformance, &run.results.build_performance);
                         ui.add(CategorySummaryCard::new(
                             "Build Performance",
                             run.results.build_performance.len(),
@@ -210,7 +235,7 @@ impl ResultsView {
                     }
 
                     if !run.results.responsiveness.is_empty() {
-                        let summary = Self::get_category_summary(&run.results.responsiveness);
+                        let summary = Self::get_category_summary(score, Category::Responsiveness, &run.results.responsiveness);
                         ui.add(CategorySummaryCard::new(
                             "Responsiveness",
                             run.results.responsiveness.len(),
@@ -230,9 +255,9 @@ impl ResultsView {
                 );
                 ui.add_space(8.0);
 
-                Self::show_category_details(ui, "Project Operations", &run.results.project_operations);
-                Self::show_category_details(ui, "Build Performance", &run.results.build_performance);
-                Self::show_category_details(ui, "Responsiveness", &run.results.responsiveness);
+                Self::show_category_details(ui, "Project Operations", &run.results.project_operations, score, community_stats, &run.system_info);
+                Self::show_category_details(ui, "Build Performance", &run.results.build_performance, score, community_stats, &run.system_info);
+                Self::show_category_details(ui, "Responsiveness", &run.results.responsiveness, score, community_stats, &run.system_info);
 
                 ui.add_space(16.0);
 
@@ -300,15 +325,50 @@ impl ResultsView {
         action
     }
 
-    fn get_category_summary(results: &[crate::models::TestResult]) -> String {
+    fn get_category_summary(score: &RunScore, category: Category, results: &[TestResult]) -> String {
         if results.is_empty() {
             return "No tests".to_string();
         }
-        // Just show "completed" status
-        "completed".to_string()
+        match score.categories.iter().find(|c| c.category == category) {
+            Some(c) if c.unscored > 0 => format!("Score {:.0} ({} unscored)", c.score, c.unscored),
+            Some(c) => format!("Score {:.0}", c.score),
+            None => "completed".to_string(),
+        }
+    }
+
+    /// Overall score relative to the reference machine, with the reference version
+    fn show_overall_score(ui: &mut Ui, score: &RunScore) {
+        let Some(overall) = score.overall else {
+            return;
+        };
+
+        ui.add_space(8.0);
+        ui.label(
+            RichText::new(format!("Overall Score {:.0}", overall))
+                .size(Theme::SIZE_SECTION)
+                .strong()
+                .color(Theme::TEXT_PRIMARY),
+        );
+        ui.label(
+            RichText::new(format!(
+                "Reference machine = {:.0} (scoring v{})",
+                crate::core::scoring::REFERENCE_SCORE,
+                score.reference_version
+            ))
+            .size(Theme::SIZE_CAPTION)
+            .color(Theme::TEXT_SECONDARY),
+        )
+        .on_hover_text(&score.reference_name);
     }
 
-    fn show_category_details(ui: &mut Ui, category_name: &str, results: &[crate::models::TestResult]) {
+    fn show_category_details(
+        ui: &mut Ui,
+        category_name: &str,
+        results: &[TestResult],
+        score: &RunScore,
+        community_stats: Option<&CommunityStatistics>,
+        system_info: &SystemInfo,
+    ) {
         if results.is_empty() {
             return;
         }
@@ -333,7 +393,7 @@ impl ResultsView {
                     ui.add_space(4.0);
 
                     egui::Grid::new(format!("results_grid_{}", category_name))
-                        .num_columns(4)
+                        .num_columns(7)
                         .spacing([16.0, 4.0])
                         .striped(true)
                         .show(ui, |ui| {
@@ -362,6 +422,27 @@ impl ResultsView {
                                     .strong()
                                     .color(Theme::TEXT_SECONDARY),
                             );
+                            ui.label(
+                                RichText::new("Score")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .strong()
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
+                            ui.label(
+                                RichText::new("Community")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .strong()
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
+                            let similar_header = ui.label(
+                                RichText::new("Similar")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .strong()
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
+                            if let Some(stats) = community_stats.filter(|s| s.class.matches(system_info)) {
+                                similar_header.on_hover_text(format!("Machines like this one: {}", stats.class.label()));
+                            }
                             ui.end_row();
 
                             for result in results {
@@ -396,15 +477,275 @@ impl ResultsView {
                                         .size(Theme::SIZE_CAPTION)
                                         .color(Theme::TEXT_SECONDARY),
                                 );
+
+                                // Score relative to the reference machine
+                                let score_str = score
+                                    .test_score(&result.test_id)
+                                    .map(|s| format!("{:.0}", s))
+                                    .unwrap_or_else(|| "-".to_string());
+                                ui.label(
+                                    RichText::new(score_str)
+                                        .size(Theme::SIZE_CAPTION)
+                                        .color(Theme::TEXT_PRIMARY),
+                                );
+
+                                // Percentile ranks estimated from community statistics,
+                                // overall and among machines of the same hardware class
+                                let rank = community_stats
+                                    .and_then(|stats| stats.for_test(&result.test_id))
+                                    .and_then(|stats| estimate_percentile_rank(result, stats));
+                                Self::show_rank(ui, rank, "community results");
+                                let similar_rank = community_stats
+                                    .and_then(|stats| stats.similar_for_test(&result.test_id, system_info))
+                                    .and_then(|stats| estimate_percentile_rank(result, stats));
+                                Self::show_rank(ui, similar_rank, "results from similar machines");
                                 ui.end_row();
                             }
                         });
+
+                    // Per-test distribution drill-downs
+                    for result in results {
+                        let stats = community_stats.and_then(|stats| stats.for_test(&result.test_id));
+
+                        if result.details.samples.is_empty()
+                            && result.details.percentiles.is_none()
+                            && stats.is_none()
+                        {
+                            continue;
+                        }
+
+                        egui::CollapsingHeader::new(
+                            RichText::new(format!("{} - Distribution", result.name))
+                                .size(Theme::SIZE_CAPTION)
+                                .color(Theme::ACCENT),
+                        )
+                        .id_salt(format!("distribution_{}", result.test_id))
+                        .default_open(false)
+                        .show(ui, |ui| {
+                            Self::show_distribution(ui, result, stats);
+                        });
+                    }
                 });
             });
 
         ui.add_space(4.0);
     }
 
+    fn show_rank(ui: &mut Ui, rank: Option<PercentileRank>, population: &str) {
+        match rank {
+            Some(rank) => {
+                ui.label(
+                    RichText::new(format!("P{:.0}", rank.percentile_rank))
+                        .size(Theme::SIZE_CAPTION)
+                        .color(Theme::TEXT_PRIMARY),
+                )
+                .on_hover_text(format!(
+                    "Better than about {:.0}% of {} {}",
+                    rank.percentile_rank, rank.total_count, population
+                ));
+            }
+            None => {
+                ui.label(
+                    RichText::new("-")
+                        .size(Theme::SIZE_CAPTION)
+                        .color(Theme::TEXT_SECONDARY),
+                );
+            }
+        }
+    }
+
+    fn show_distribution(ui: &mut Ui, result: &TestResult, stats: Option<&TestStatistics>) {
+        let samples = &result.details.samples;
+        let unit = Self::sample_unit(result);
+
+        if !samples.is_empty() || stats.is_some() {
+            let show_cdf = samples.len() > 1;
+            let plot_width = if show_cdf { 285.0 } else { 580.0 };
+
+            ui.horizontal(|ui| {
+                Self::show_histogram(ui, result, stats, plot_width);
+                if show_cdf {
+                    Self::show_cdf(ui, result, plot_width);
+                }
+            });
+
+            if stats.is_some() {
+                ui.label(
+                    RichText::new(format!(
+                        "Community bars show how other machines' results ({}) are distributed; the line marks yours",
+                        result.unit
+                    ))
+                    .size(Theme::SIZE_CAPTION)
+                    .color(Theme::TEXT_SECONDARY),
+                );
+            }
+        }
+
+        if let Some(percentiles) = &result.details.percentiles {
+            ui.add_space(4.0);
+            Self::show_percentile_ladder(ui, result, percentiles, unit);
+        }
+
+        ui.add_space(4.0);
+    }
+
+    fn show_histogram(ui: &mut Ui, result: &TestResult, stats: Option<&TestStatistics>, width: f32) {
+        let samples = &result.details.samples;
+
+        Plot::new(format!("histogram_{}", result.test_id))
+            .width(width)
+            .height(160.0)
+            .legend(Legend::default())
+            .y_axis_label("% of total")
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                if !samples.is_empty() {
+                    let bars: Vec<Bar> = Self::histogram(samples, HISTOGRAM_BINS)
+                        .into_iter()
+                        .map(|(start, bin_width, fraction)| {
+                            Bar::new(start + bin_width / 2.0, fraction * 100.0).width(bin_width)
+                        })
+                        .collect();
+                    plot_ui.bar_chart(BarChart::new(bars).color(Theme::ACCENT).name("Samples"));
+                }
+
+                // Community overlay: share of machines per bucket
+                if let Some(stats) = stats {
+                    let total: i64 = stats.histogram_buckets.iter().map(|b| b.count).sum();
+                    if total > 0 {
+                        let bars: Vec<Bar> = stats
+                            .histogram_buckets
+                            .iter()
+                            .map(|b| {
+                                Bar::new(
+                                    (b.bucket_start + b.bucket_end) / 2.0,
+                                    b.count as f64 / total as f64 * 100.0,
+                                )
+                                .width(b.bucket_end - b.bucket_start)
+                            })
+                            .collect();
+                        plot_ui.bar_chart(
+                            BarChart::new(bars)
+                                .color(COMMUNITY_COLOR.gamma_multiply(0.6))
+                                .name(format!("Community ({} runs)", stats.sample_count)),
+                        );
+                    }
+
+                    plot_ui.vline(
+                        VLine::new(result.value)
+                            .color(Theme::SUCCESS)
+                            .width(2.0)
+                            .name("Your result"),
+                    );
+                }
+            });
+    }
+
+    fn show_cdf(ui: &mut Ui, result: &TestResult, width: f32) {
+        let mut sorted = result.details.samples.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let count = sorted.len() as f64;
+        let points: Vec<[f64; 2]> = sorted
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| [*value, (idx + 1) as f64 / count * 100.0])
+            .collect();
+
+        Plot::new(format!("cdf_{}", result.test_id))
+            .width(width)
+            .height(160.0)
+            .y_axis_label("Percentile")
+            .include_y(0.0)
+            .include_y(100.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new(PlotPoints::new(points))
+                        .color(Theme::ACCENT)
+                        .width(2.0)
+                        .name("CDF"),
+                );
+            });
+    }
+
+    fn show_percentile_ladder(ui: &mut Ui, result: &TestResult, percentiles: &Percentiles, unit: &str) {
+        let rungs = [
+            ("P50", percentiles.p50),
+            ("P75", percentiles.p75),
+            ("P90", percentiles.p90),
+            ("P95", percentiles.p95),
+            ("P99", percentiles.p99),
+            ("P99.9", percentiles.p999),
+        ];
+        let top = rungs.iter().map(|(_, v)| *v).fold(0.0_f64, f64::max);
+
+        egui::Grid::new(format!("percentile_ladder_{}", result.test_id))
+            .num_columns(3)
+            .spacing([12.0, 2.0])
+            .show(ui, |ui| {
+                for (label, value) in rungs {
+                    ui.label(
+                        RichText::new(label)
+                            .size(Theme::SIZE_CAPTION)
+                            .strong()
+                            .color(Theme::TEXT_SECONDARY),
+                    );
+                    ui.label(
+                        RichText::new(format!("{} {}", Self::format_value(value), unit))
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::TEXT_PRIMARY),
+                    );
+                    let fraction = if top > 0.0 { (value / top) as f32 } else { 0.0 };
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .desired_width(360.0)
+                            .desired_height(8.0)
+                            .fill(Theme::ACCENT),
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Bin samples into (bin_start, bin_width, fraction_of_samples).
+    /// The range is capped at the 99.5th percentile so a few outliers don't
+    /// squash the rest of the distribution; anything above lands in the last bin.
+    fn histogram(samples: &[f64], bins: usize) -> Vec<(f64, f64, f64)> {
+        if samples.is_empty() || bins == 0 {
+            return Vec::new();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let low = sorted[0];
+        let high = sorted[((sorted.len() - 1) as f64 * 0.995).round() as usize];
+        let bin_width = if high > low { (high - low) / bins as f64 } else { 1.0 };
+
+        let mut counts = vec![0usize; bins];
+        for value in &sorted {
+            let idx = ((value - low) / bin_width) as usize;
+            counts[idx.min(bins - 1)] += 1;
+        }
+
+        let total = sorted.len() as f64;
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, count)| (low + idx as f64 * bin_width, bin_width, count as f64 / total))
+            .collect()
+    }
+
+    /// Unit of the detail values, without qualifiers like "(P99)"
+    fn sample_unit(result: &TestResult) -> &str {
+        result.unit.split(" (").next().unwrap_or(&result.unit)
+    }
+
     fn format_value(value: f64) -> String {
         if value >= 10000.0 {
             format!("{:.0}", value)
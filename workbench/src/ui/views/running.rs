@@ -1,24 +1,31 @@
 use egui::{Align, Layout, RichText, Ui};
+use egui_plot::{Legend, Line, Plot, PlotPoints};
 
+use crate::benchmarks::LiveSample;
+use crate::core::EtaEstimator;
 use crate::ui::widgets::ProgressBar;
 use crate::ui::Theme;
 
 /// Running View matching 05-ui-design.md spec:
-/// - Overall progress bar
+/// - Overall progress bar with estimated time remaining
 /// - Category list with status
 /// - Current test name + progress
+/// - Live chart of samples reported by the current test
 /// - Cancel button
 pub struct RunningView;
 
 impl RunningView {
     /// Returns true if cancel button was clicked
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         ui: &mut Ui,
         overall_progress: f32,
+        eta_secs: Option<f64>,
         current_test_progress: f32,
         current_test: &str,
         current_message: &str,
         completed_tests: &[String],
+        live_samples: &[LiveSample],
     ) -> bool {
         let mut cancel_clicked = false;
 
@@ -65,6 +72,18 @@ impl RunningView {
 
                         // Large progress bar
                         ui.add(ProgressBar::new(overall_progress).height(12.0).width(420.0));
+
+                        if let Some(eta_secs) = eta_secs {
+                            ui.add_space(4.0);
+                            ui.label(
+                                RichText::new(format!(
+                                    "About {} remaining",
+                                    EtaEstimator::format_remaining(eta_secs)
+                                ))
+                                .size(Theme::SIZE_CAPTION)
+                                .color(Theme::TEXT_SECONDARY),
+                            );
+                        }
                     });
 
                 ui.add_space(8.0);
@@ -124,6 +143,12 @@ impl RunningView {
 
                 ui.add_space(8.0);
 
+                // Live Chart Card
+                if !live_samples.is_empty() {
+                    Self::show_live_chart(ui, live_samples);
+                    ui.add_space(8.0);
+                }
+
                 // Completed Tests Card (scrollable)
                 if !completed_tests.is_empty() {
                     egui::Frame::none()
@@ -200,4 +225,68 @@ impl RunningView {
 
         cancel_clicked
     }
+
+    fn show_live_chart(ui: &mut Ui, samples: &[LiveSample]) {
+        // Group samples by series, keeping first-seen order
+        let mut series: Vec<(&str, Vec<[f64; 2]>)> = Vec::new();
+        for sample in samples {
+            let point = [sample.elapsed_secs, sample.value];
+            match series.iter_mut().find(|(name, _)| *name == sample.series) {
+                Some((_, points)) => points.push(point),
+                None => series.push((sample.series, vec![point])),
+            }
+        }
+
+        let unit = samples[0].unit;
+
+        egui::Frame::none()
+            .fill(Theme::BG_CARD)
+            .stroke(egui::Stroke::new(1.0, Theme::BORDER))
+            .rounding(Theme::CARD_ROUNDING)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.set_min_width(420.0);
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new("Live")
+                            .size(Theme::SIZE_BODY)
+                            .color(Theme::TEXT_SECONDARY),
+                    );
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        if let Some(last) = samples.last() {
+                            ui.label(
+                                RichText::new(format!("{:.2} {}", last.value, last.unit))
+                                    .size(Theme::SIZE_BODY)
+                                    .strong()
+                                    .color(Theme::ACCENT),
+                            );
+                        }
+                    });
+                });
+
+                ui.add_space(4.0);
+
+                Plot::new("live_samples_plot")
+                    .width(420.0)
+                    .height(160.0)
+                    .legend(Legend::default())
+                    .x_axis_label("s")
+                    .y_axis_label(unit)
+                    .include_y(0.0)
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .show(ui, |plot_ui| {
+                        for (name, points) in series {
+                            plot_ui.line(
+                                Line::new(PlotPoints::new(points))
+                                    .color(Theme::ACCENT)
+                                    .width(1.5)
+                                    .name(name),
+                            );
+                        }
+                    });
+            });
+    }
 }
@@ -0,0 +1,292 @@
+//! Performance trends view - plots each test's value over time across saved runs.
+
+use chrono::DateTime;
+use egui::{Align, Color32, Layout, RichText, Ui};
+use egui_plot::{Legend, Line, MarkerShape, Plot, PlotPoints, Points, Polygon};
+
+use crate::core::{TestTrend, TrendAnalyzer};
+use crate::models::BenchmarkRun;
+use crate::ui::Theme;
+
+/// Series colors, assigned to machines in order
+const SERIES_COLORS: [Color32; 6] = [
+    Theme::ACCENT,
+    Color32::from_rgb(16, 185, 129),  // green
+    Color32::from_rgb(245, 158, 11),  // amber
+    Color32::from_rgb(139, 92, 246),  // violet
+    Color32::from_rgb(236, 72, 153),  // pink
+    Color32::from_rgb(14, 165, 233),  // sky
+];
+
+/// Trends View
+/// Layout:
+/// - Test selector and band toggle
+/// - Line chart with one series per machine, optional min-max bands
+/// - Markers for runs with notes or tags, listed below the chart
+pub struct TrendsView;
+
+impl TrendsView {
+    /// Returns back_clicked
+    pub fn show(
+        ui: &mut Ui,
+        runs: &[BenchmarkRun],
+        selected_test: &mut Option<String>,
+        show_bands: &mut bool,
+    ) -> bool {
+        let mut back_clicked = false;
+
+        let tests = TrendAnalyzer::available_tests(runs);
+
+        // Default to the first test, and drop a selection that no longer exists
+        if !selected_test
+            .as_ref()
+            .is_some_and(|id| tests.iter().any(|(test_id, _)| test_id == id))
+        {
+            *selected_test = tests.first().map(|(id, _)| id.clone());
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.with_layout(Layout::top_down(Align::Center), |ui| {
+                ui.add_space(12.0);
+
+                // Title
+                ui.label(
+                    RichText::new("Performance Trends")
+                        .size(Theme::SIZE_SECTION)
+                        .strong()
+                        .color(Theme::ACCENT),
+                );
+                ui.label(
+                    RichText::new(format!("{} saved runs", runs.len()))
+                        .size(Theme::SIZE_CAPTION)
+                        .color(Theme::TEXT_SECONDARY),
+                );
+
+                ui.add_space(8.0);
+
+                let trend = selected_test
+                    .as_deref()
+                    .and_then(|id| TrendAnalyzer::build(runs, id));
+
+                egui::Frame::none()
+                    .fill(Theme::BG_CARD)
+                    .stroke(egui::Stroke::new(1.0, Theme::BORDER))
+                    .rounding(Theme::CARD_ROUNDING)
+                    .inner_margin(12.0)
+                    .show(ui, |ui| {
+                        ui.set_min_width(650.0);
+
+                        let Some(trend) = trend else {
+                            ui.with_layout(Layout::top_down(Align::Center), |ui| {
+                                ui.label(
+                                    RichText::new("No benchmark history yet")
+                                        .size(Theme::SIZE_CARD)
+                                        .color(Theme::TEXT_SECONDARY),
+                                );
+                                ui.label(
+                                    RichText::new("Run a benchmark to start tracking trends")
+                                        .size(Theme::SIZE_CAPTION)
+                                        .color(Theme::TEXT_SECONDARY),
+                                );
+                            });
+                            return;
+                        };
+
+                        // Test selector
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                RichText::new("Test:")
+                                    .size(Theme::SIZE_CAPTION)
+                                    .color(Theme::TEXT_SECONDARY),
+                            );
+                            egui::ComboBox::from_id_salt("trend_test_select")
+                                .width(280.0)
+                                .selected_text(&trend.name)
+                                .show_ui(ui, |ui| {
+                                    for (test_id, name) in &tests {
+                                        ui.selectable_value(
+                                            selected_test,
+                                            Some(test_id.clone()),
+                                            name,
+                                        );
+                                    }
+                                });
+
+                            ui.add_space(12.0);
+                            ui.checkbox(show_bands, "Min-max bands");
+                        });
+
+                        ui.add_space(8.0);
+
+                        Self::show_chart(ui, &trend, *show_bands);
+
+                        ui.add_space(8.0);
+
+                        Self::show_annotations(ui, &trend);
+                    });
+
+                ui.add_space(12.0);
+
+                // Back button
+                let back_btn = egui::Button::new(
+                    RichText::new("Back to History").size(Theme::SIZE_BODY),
+                )
+                .min_size(egui::vec2(100.0, 32.0))
+                .rounding(Theme::CARD_ROUNDING);
+
+                if ui.add(back_btn).clicked() {
+                    back_clicked = true;
+                }
+
+                ui.add_space(12.0);
+            });
+        });
+
+        back_clicked
+    }
+
+    fn show_chart(ui: &mut Ui, trend: &TestTrend, show_bands: bool) {
+        let unit = trend.unit.clone();
+
+        Plot::new(format!("trend_plot_{}", trend.test_id))
+            .height(320.0)
+            .legend(Legend::default())
+            .y_axis_label(trend.unit.as_str())
+            .x_axis_formatter(|mark, _range| Self::format_date(mark.value, "%Y-%m-%d"))
+            .label_formatter(move |name, point| {
+                let date = Self::format_date(point.x, "%Y-%m-%d %H:%M");
+                if name.is_empty() {
+                    format!("{}\n{:.3} {}", date, point.y, unit)
+                } else {
+                    format!("{}\n{}\n{:.3} {}", name, date, point.y, unit)
+                }
+            })
+            .show(ui, |plot_ui| {
+                for (idx, series) in trend.series.iter().enumerate() {
+                    let color = SERIES_COLORS[idx % SERIES_COLORS.len()];
+
+                    let points: Vec<[f64; 2]> = series
+                        .points
+                        .iter()
+                        .map(|p| [p.timestamp.timestamp() as f64, p.value])
+                        .collect();
+
+                    // Min-max band: upper edge forward, lower edge back
+                    if show_bands && series.points.len() > 1 && series.points.iter().any(|p| p.has_range()) {
+                        let mut band: Vec<[f64; 2]> = series
+                            .points
+                            .iter()
+                            .map(|p| [p.timestamp.timestamp() as f64, p.max.max(p.value)])
+                            .collect();
+                        band.extend(
+                            series
+                                .points
+                                .iter()
+                                .rev()
+                                .map(|p| [p.timestamp.timestamp() as f64, p.min.min(p.value)]),
+                        );
+                        plot_ui.polygon(
+                            Polygon::new(PlotPoints::new(band))
+                                .fill_color(color.gamma_multiply(0.15))
+                                .stroke(egui::Stroke::NONE)
+                                .allow_hover(false),
+                        );
+                    }
+
+                    plot_ui.line(
+                        Line::new(PlotPoints::new(points.clone()))
+                            .color(color)
+                            .width(2.0)
+                            .name(&series.machine_name),
+                    );
+                    plot_ui.points(
+                        Points::new(PlotPoints::new(points))
+                            .color(color)
+                            .radius(3.0)
+                            .filled(true)
+                            .name(&series.machine_name),
+                    );
+
+                    // Annotated runs (notes or tags)
+                    let annotated: Vec<[f64; 2]> = series
+                        .points
+                        .iter()
+                        .filter(|p| p.annotation.is_some())
+                        .map(|p| [p.timestamp.timestamp() as f64, p.value])
+                        .collect();
+                    if !annotated.is_empty() {
+                        plot_ui.points(
+                            Points::new(PlotPoints::new(annotated))
+                                .shape(MarkerShape::Diamond)
+                                .color(Theme::WARNING)
+                                .radius(7.0)
+                                .filled(false)
+                                .name("Notes / tags"),
+                        );
+                    }
+                }
+            });
+    }
+
+    fn show_annotations(ui: &mut Ui, trend: &TestTrend) {
+        let mut annotated: Vec<_> = trend
+            .series
+            .iter()
+            .flat_map(|s| {
+                s.points
+                    .iter()
+                    .filter_map(move |p| p.annotation.as_ref().map(|a| (&s.machine_name, p, a)))
+            })
+            .collect();
+
+        if annotated.is_empty() {
+            return;
+        }
+
+        annotated.sort_by_key(|(_, p, _)| std::cmp::Reverse(p.timestamp));
+
+        ui.label(
+            RichText::new("Annotated Runs")
+                .size(Theme::SIZE_BODY)
+                .strong()
+                .color(Theme::TEXT_PRIMARY),
+        );
+        ui.add_space(4.0);
+
+        egui::Grid::new(format!("trend_annotations_{}", trend.test_id))
+            .num_columns(4)
+            .spacing([16.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for (machine, point, annotation) in annotated {
+                    ui.label(
+                        RichText::new(point.timestamp.format("%Y-%m-%d %H:%M").to_string())
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::TEXT_SECONDARY),
+                    );
+                    ui.label(
+                        RichText::new(machine)
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::TEXT_PRIMARY),
+                    );
+                    ui.label(
+                        RichText::new(format!("{:.3} {}", point.value, trend.unit))
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::ACCENT),
+                    );
+                    ui.label(
+                        RichText::new(annotation)
+                            .size(Theme::SIZE_CAPTION)
+                            .color(Theme::TEXT_PRIMARY),
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn format_date(secs: f64, format: &str) -> String {
+        DateTime::from_timestamp(secs as i64, 0)
+            .map(|dt| dt.format(format).to_string())
+            .unwrap_or_default()
+    }
+}
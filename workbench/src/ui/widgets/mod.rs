@@ -1,5 +1,7 @@
 mod progress_bar;
 mod score_card;
+mod signature_badge;
 
 pub use progress_bar::ProgressBar;
 pub use score_card::{CategorySummaryCard, MachineInfoCard, TestResultRow};
+pub use signature_badge::SignatureBadge;
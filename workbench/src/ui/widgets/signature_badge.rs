@@ -0,0 +1,37 @@
+use egui::{Response, RichText, Ui, Widget};
+
+use crate::models::SignatureStatus;
+use crate::ui::Theme;
+
+/// Small label telling whether a run's results are signed and intact
+pub struct SignatureBadge {
+    status: SignatureStatus,
+}
+
+impl SignatureBadge {
+    pub fn new(status: SignatureStatus) -> Self {
+        Self { status }
+    }
+}
+
+impl Widget for SignatureBadge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (color, hover) = match self.status {
+            SignatureStatus::Valid => (
+                Theme::SUCCESS,
+                "The results and system info are unchanged since the run was signed",
+            ),
+            SignatureStatus::Unsigned => (
+                Theme::TEXT_SECONDARY,
+                "This run carries no signature, so edits can't be detected",
+            ),
+            SignatureStatus::Invalid => (
+                Theme::ERROR,
+                "The results or system info were changed after the run was signed",
+            ),
+        };
+
+        ui.label(RichText::new(self.status.label()).size(Theme::SIZE_CAPTION).color(color))
+            .on_hover_text(hover)
+    }
+}
@@ -0,0 +1,119 @@
+-- Deletion tokens for community uploads
+--
+-- Every insert into benchmark_runs gets a random token. It is returned once, in
+-- the `Prefer: return=representation` response to the upload, and only its
+-- SHA-256 is kept, in a table clients can't read. Deleting a run requires the
+-- token in the `x-delete-token` request header, the same contract as the
+-- self-hosted server (src/server/api.rs).
+
+create extension if not exists pgcrypto with schema extensions;
+
+-- Plaintext column, only ever filled in the insert's RETURNING row
+alter table public.benchmark_runs add column if not exists delete_token text;
+
+create table if not exists public.benchmark_run_delete_tokens (
+    run_id uuid primary key references public.benchmark_runs (id) on delete cascade,
+    token_hash text not null
+);
+
+-- No policies: nothing but the functions below can read or write the hashes
+alter table public.benchmark_run_delete_tokens enable row level security;
+revoke all on public.benchmark_run_delete_tokens from anon, authenticated;
+
+create or replace function public.issue_delete_token()
+returns trigger
+language plpgsql
+set search_path = public, extensions
+as $$
+begin
+    -- Whatever the client sent is replaced
+    new.delete_token := encode(gen_random_bytes(32), 'hex');
+    return new;
+end;
+$$;
+
+-- RETURNING already holds the token by the time this runs, so clearing the
+-- stored plaintext doesn't affect the upload response
+create or replace function public.store_delete_token()
+returns trigger
+language plpgsql
+security definer
+set search_path = public, extensions
+as $$
+begin
+    insert into public.benchmark_run_delete_tokens (run_id, token_hash)
+    values (new.id, encode(digest(new.delete_token, 'sha256'), 'hex'));
+
+    update public.benchmark_runs set delete_token = null where id = new.id;
+    return null;
+end;
+$$;
+
+drop trigger if exists benchmark_runs_issue_delete_token on public.benchmark_runs;
+create trigger benchmark_runs_issue_delete_token
+    before insert on public.benchmark_runs
+    for each row execute function public.issue_delete_token();
+
+drop trigger if exists benchmark_runs_store_delete_token on public.benchmark_runs;
+create trigger benchmark_runs_store_delete_token
+    after insert on public.benchmark_runs
+    for each row execute function public.store_delete_token();
+
+-- Whether the request's x-delete-token header owns a run
+create or replace function public.delete_token_matches(target uuid)
+returns boolean
+language sql
+stable
+security definer
+set search_path = public, extensions
+as $$
+    select exists (
+        select 1
+        from public.benchmark_run_delete_tokens t
+        where t.run_id = target
+          and t.token_hash = encode(
+              digest(coalesce(current_setting('request.headers', true)::json ->> 'x-delete-token', ''), 'sha256'),
+              'hex'
+          )
+    );
+$$;
+
+revoke all on function public.delete_token_matches(uuid) from public;
+grant execute on function public.delete_token_matches(uuid) to anon, authenticated;
+
+-- Policies are OR'ed, so any older DELETE policy would let the token be skipped.
+-- Admin removals go through the dashboard or the service role, which bypass RLS.
+do $$
+declare
+    policy record;
+begin
+    if exists (
+        select 1 from pg_policies
+        where schemaname = 'public' and tablename = 'benchmark_runs' and cmd = 'ALL'
+    ) then
+        raise exception 'benchmark_runs has a FOR ALL policy; split it per command before applying this migration';
+    end if;
+
+    for policy in
+        select policyname from pg_policies
+        where schemaname = 'public' and tablename = 'benchmark_runs' and cmd = 'DELETE'
+    loop
+        execute format('drop policy %I on public.benchmark_runs', policy.policyname);
+    end loop;
+
+    -- Without RLS anyone could delete; keep reads and uploads open when turning it on
+    if not (select relrowsecurity from pg_class where oid = 'public.benchmark_runs'::regclass) then
+        alter table public.benchmark_runs enable row level security;
+        create policy "Anyone can read runs" on public.benchmark_runs
+            for select to anon, authenticated using (true);
+        create policy "Anyone can upload runs" on public.benchmark_runs
+            for insert to anon, authenticated with check (true);
+    end if;
+end;
+$$;
+
+create policy "Uploaders delete with their token"
+    on public.benchmark_runs
+    for delete
+    to anon, authenticated
+    using (public.delete_token_matches(id));
@@ -0,0 +1,138 @@
+-- Community statistics sliced by hardware class
+--
+-- get_test_statistics takes the fields of the app's HardwareClass as optional
+-- named arguments (PostgREST passes the JSON body's keys), so `{}` still asks
+-- for the whole community. Bands and vendor names follow HardwareClass::of and
+-- the numbers follow src/cloud/statistics.rs, which the self-hosted server uses.
+
+-- The argument-less version would make a `{}` call ambiguous
+drop function if exists public.get_test_statistics();
+
+create or replace function public.get_test_statistics(
+    cpu_vendor text default null,
+    core_band text default null,
+    memory_band text default null,
+    storage_type text default null,
+    os_name text default null
+)
+returns table (
+    test_id text,
+    test_name text,
+    unit text,
+    sample_count bigint,
+    min_value double precision,
+    max_value double precision,
+    mean_value double precision,
+    std_dev double precision,
+    p10 double precision,
+    p25 double precision,
+    p50 double precision,
+    p75 double precision,
+    p90 double precision,
+    histogram_buckets jsonb
+)
+language sql
+stable
+as $$
+    with runs as (
+        select r.results::jsonb as results
+        from public.benchmark_runs r
+        where (
+            get_test_statistics.cpu_vendor is null
+            or case
+                when lower(r.system_info::jsonb #>> '{cpu,vendor}') like '%intel%' then 'Intel'
+                when lower(r.system_info::jsonb #>> '{cpu,vendor}') like '%amd%' then 'AMD'
+                when lower(r.system_info::jsonb #>> '{cpu,vendor}') like '%apple%' then 'Apple'
+                else trim(r.system_info::jsonb #>> '{cpu,vendor}')
+            end = get_test_statistics.cpu_vendor
+        )
+        and (
+            get_test_statistics.core_band is null
+            or case
+                when r.cpu_cores <= 4 then '1-4'
+                when r.cpu_cores <= 8 then '5-8'
+                when r.cpu_cores <= 16 then '9-16'
+                else '17+'
+            end = get_test_statistics.core_band
+        )
+        and (
+            get_test_statistics.memory_band is null
+            or case
+                when r.memory_gb < 12 then '8'
+                when r.memory_gb < 24 then '16'
+                when r.memory_gb < 48 then '32'
+                else '64+'
+            end = get_test_statistics.memory_band
+        )
+        and (get_test_statistics.storage_type is null or r.storage_type = get_test_statistics.storage_type)
+        and (get_test_statistics.os_name is null or r.os_name = get_test_statistics.os_name)
+    ),
+    samples as (
+        select
+            t ->> 'test_id' as test_id,
+            t ->> 'name' as test_name,
+            t ->> 'unit' as unit,
+            (t ->> 'value')::double precision as value
+        from runs,
+            jsonb_array_elements(
+                coalesce(results -> 'project_operations', '[]'::jsonb)
+                || coalesce(results -> 'build_performance', '[]'::jsonb)
+                || coalesce(results -> 'responsiveness', '[]'::jsonb)
+            ) as t
+        -- Non-finite values are stored as null
+        where jsonb_typeof(t -> 'value') = 'number'
+    ),
+    stats as (
+        select
+            s.test_id,
+            min(s.test_name) as test_name,
+            min(s.unit) as unit,
+            count(*) as n,
+            min(s.value) as lo,
+            max(s.value) as hi,
+            avg(s.value) as mean,
+            stddev_pop(s.value) as sd,
+            array_agg(s.value order by s.value) as sorted
+        from samples s
+        group by s.test_id
+    )
+    select
+        st.test_id,
+        st.test_name,
+        st.unit,
+        st.n,
+        st.lo,
+        st.hi,
+        st.mean,
+        coalesce(st.sd, 0),
+        -- Nearest rank, as in statistics.rs
+        st.sorted[round(0.10 * (st.n - 1))::int + 1],
+        st.sorted[round(0.25 * (st.n - 1))::int + 1],
+        st.sorted[round(0.50 * (st.n - 1))::int + 1],
+        st.sorted[round(0.75 * (st.n - 1))::int + 1],
+        st.sorted[round(0.90 * (st.n - 1))::int + 1],
+        case
+            when st.hi <= st.lo then
+                jsonb_build_array(jsonb_build_object('bucket_start', st.lo, 'bucket_end', st.hi, 'count', st.n))
+            else (
+                -- 20 equal-width buckets, the largest value in the last one
+                select jsonb_agg(
+                    jsonb_build_object(
+                        'bucket_start', st.lo + i * (st.hi - st.lo) / 20,
+                        'bucket_end', st.lo + (i + 1) * (st.hi - st.lo) / 20,
+                        'count', (
+                            select count(*)
+                            from unnest(st.sorted) as v
+                            where least(floor((v - st.lo) / ((st.hi - st.lo) / 20))::int, 19) = i
+                        )
+                    )
+                    order by i
+                )
+                from generate_series(0, 19) as i
+            )
+        end
+    from stats st
+    order by st.test_id;
+$$;
+
+grant execute on function public.get_test_statistics(text, text, text, text, text) to anon, authenticated;